@@ -0,0 +1,17 @@
+-- Create task_dependencies table (blocking_task_id blocks blocked_task_id)
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    blocking_task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    blocked_task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(blocking_task_id, blocked_task_id),
+    CHECK (blocking_task_id <> blocked_task_id)
+);
+
+-- Allow projects to refuse completing tasks that are still blocked
+ALTER TABLE projects ADD COLUMN IF NOT EXISTS enforce_dependencies BOOLEAN NOT NULL DEFAULT false;
+
+-- Create indexes for dependencies
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_blocking ON task_dependencies(blocking_task_id);
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_blocked ON task_dependencies(blocked_task_id);
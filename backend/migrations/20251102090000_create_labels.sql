@@ -0,0 +1,28 @@
+-- Create labels table (organization-wide when project_id is NULL)
+CREATE TABLE IF NOT EXISTS labels (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    project_id UUID REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    color VARCHAR(7) NOT NULL DEFAULT '#6B7280',
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create task_labels table (many-to-many relationship)
+CREATE TABLE IF NOT EXISTS task_labels (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    label_id UUID NOT NULL REFERENCES labels(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, label_id)
+);
+
+-- Label names are unique within their scope
+CREATE UNIQUE INDEX IF NOT EXISTS idx_labels_org_name ON labels(organization_id, LOWER(name)) WHERE project_id IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_labels_project_name ON labels(project_id, LOWER(name)) WHERE project_id IS NOT NULL;
+
+-- Create indexes for labels
+CREATE INDEX IF NOT EXISTS idx_labels_organization_id ON labels(organization_id);
+CREATE INDEX IF NOT EXISTS idx_labels_project_id ON labels(project_id);
+CREATE INDEX IF NOT EXISTS idx_task_labels_label_id ON task_labels(label_id);
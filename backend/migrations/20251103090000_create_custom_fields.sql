@@ -0,0 +1,29 @@
+-- Create custom_fields table (per-project typed task metadata)
+CREATE TABLE IF NOT EXISTS custom_fields (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    field_type VARCHAR(20) NOT NULL CHECK (field_type IN ('text', 'number', 'date', 'single_select', 'multi_select', 'user', 'url')),
+    options TEXT[] NOT NULL DEFAULT '{}',
+    is_required BOOLEAN NOT NULL DEFAULT false,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(project_id, name)
+);
+
+-- Create task_custom_field_values table (one value per task and field)
+CREATE TABLE IF NOT EXISTS task_custom_field_values (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    field_id UUID NOT NULL REFERENCES custom_fields(id) ON DELETE CASCADE,
+    value JSONB NOT NULL,
+    updated_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, field_id)
+);
+
+-- Create indexes for custom fields
+CREATE INDEX IF NOT EXISTS idx_custom_fields_project_id ON custom_fields(project_id);
+CREATE INDEX IF NOT EXISTS idx_task_custom_field_values_field_id ON task_custom_field_values(field_id);
+CREATE INDEX IF NOT EXISTS idx_task_custom_field_values_value ON task_custom_field_values USING GIN (value);
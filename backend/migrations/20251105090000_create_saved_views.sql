@@ -0,0 +1,19 @@
+-- Create saved_views table (named task filters per user and project)
+CREATE TABLE IF NOT EXISTS saved_views (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    filter TEXT,
+    sort VARCHAR(100),
+    sort_order VARCHAR(4) NOT NULL DEFAULT 'asc' CHECK (sort_order IN ('asc', 'desc')),
+    group_by VARCHAR(20) CHECK (group_by IN ('status', 'priority', 'assignee', 'label', 'due_date')),
+    layout VARCHAR(20) NOT NULL DEFAULT 'board' CHECK (layout IN ('board', 'list', 'calendar')),
+    is_shared BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create indexes
+CREATE INDEX IF NOT EXISTS idx_saved_views_project_id ON saved_views(project_id);
+CREATE INDEX IF NOT EXISTS idx_saved_views_owner_id ON saved_views(owner_id);
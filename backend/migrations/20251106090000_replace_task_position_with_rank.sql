@@ -0,0 +1,20 @@
+-- Replace integer task positions with lexicographic rank keys
+ALTER TABLE tasks ADD COLUMN IF NOT EXISTS rank TEXT COLLATE "C";
+
+-- Keep the existing order: fixed-width numbers sort correctly as text, and the
+-- trailing digit keeps keys from ending in '0'
+UPDATE tasks t
+SET rank = lpad(ordered.n::text, 10, '0') || 'V'
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY project_id ORDER BY status, position, created_at, id) AS n
+    FROM tasks
+) ordered
+WHERE t.id = ordered.id AND t.rank IS NULL;
+
+ALTER TABLE tasks ALTER COLUMN rank SET NOT NULL;
+
+DROP INDEX IF EXISTS idx_tasks_position;
+ALTER TABLE tasks DROP COLUMN IF EXISTS position;
+
+-- Ranks are unique per project so concurrent moves cannot produce ties
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_project_rank ON tasks(project_id, rank);
@@ -0,0 +1,13 @@
+-- Create task_history table (one row per changed field)
+CREATE TABLE IF NOT EXISTS task_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    field VARCHAR(50) NOT NULL,
+    old_value JSONB,
+    new_value JSONB,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create index for task history
+CREATE INDEX IF NOT EXISTS idx_task_history_task_id ON task_history(task_id, created_at);
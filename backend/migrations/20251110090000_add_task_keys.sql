@@ -0,0 +1,52 @@
+-- Short project keys (e.g. WEB) and per-project task numbers, shown as WEB-123
+ALTER TABLE projects
+    ADD COLUMN key VARCHAR(10),
+    ADD COLUMN next_task_number INTEGER NOT NULL DEFAULT 1;
+
+-- Derive keys from the first letters of the name, numbering duplicates within an organization
+UPDATE projects p
+SET key = k.key
+FROM (
+    SELECT id,
+           CASE WHEN n = 1 THEN base ELSE base || n END AS key
+    FROM (
+        SELECT id, base,
+               ROW_NUMBER() OVER (PARTITION BY organization_id, base ORDER BY created_at, id) AS n
+        FROM (
+            SELECT id, organization_id, created_at,
+                   COALESCE(NULLIF(LEFT(regexp_replace(UPPER(name), '[^A-Z]', '', 'g'), 3), ''), 'PRJ') AS base
+            FROM projects
+        ) b
+    ) numbered
+) k
+WHERE p.id = k.id;
+
+ALTER TABLE projects ALTER COLUMN key SET NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_projects_organization_key ON projects(organization_id, key);
+
+ALTER TABLE tasks ADD COLUMN number INTEGER;
+
+UPDATE tasks t
+SET number = n.number
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY project_id ORDER BY created_at, id) AS number
+    FROM tasks
+) n
+WHERE t.id = n.id;
+
+UPDATE projects p
+SET next_task_number = COALESCE((SELECT MAX(number) FROM tasks WHERE project_id = p.id), 0) + 1;
+
+ALTER TABLE tasks ALTER COLUMN number SET NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tasks_project_number ON tasks(project_id, number);
+
+-- Old keys of tasks that moved to another project keep resolving to the task
+CREATE TABLE IF NOT EXISTS task_key_redirects (
+    project_id UUID NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (project_id, number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_key_redirects_task_id ON task_key_redirects(task_id);
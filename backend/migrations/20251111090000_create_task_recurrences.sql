@@ -0,0 +1,18 @@
+-- A recurring series follows its newest occurrence; the next one is created when
+-- that occurrence is completed or its due date passes
+CREATE TABLE IF NOT EXISTS task_recurrences (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL UNIQUE REFERENCES tasks(id) ON DELETE CASCADE,
+    rule TEXT NOT NULL,
+    timezone VARCHAR(64) NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL,
+    occurrence_index INTEGER NOT NULL DEFAULT 0,
+    current_due_at TIMESTAMPTZ NOT NULL,
+    next_due_at TIMESTAMPTZ,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_recurrences_current_due_at
+    ON task_recurrences(current_due_at) WHERE next_due_at IS NOT NULL;
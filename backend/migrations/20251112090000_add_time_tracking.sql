@@ -0,0 +1,25 @@
+-- Estimates are kept in seconds, like logged time
+ALTER TABLE tasks
+    ADD COLUMN IF NOT EXISTS original_estimate_seconds INTEGER CHECK (original_estimate_seconds >= 0),
+    ADD COLUMN IF NOT EXISTS remaining_estimate_seconds INTEGER CHECK (remaining_estimate_seconds >= 0);
+
+-- A log without an end is a running timer; its duration is set when it stops
+CREATE TABLE IF NOT EXISTS time_logs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    description TEXT,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    duration_seconds INTEGER CHECK (duration_seconds >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((ended_at IS NULL) = (duration_seconds IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_time_logs_task_id ON time_logs(task_id);
+CREATE INDEX IF NOT EXISTS idx_time_logs_user_started_at ON time_logs(user_id, started_at);
+
+-- Each user has at most one running timer
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_logs_running
+    ON time_logs(user_id) WHERE ended_at IS NULL;
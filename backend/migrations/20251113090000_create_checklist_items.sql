@@ -0,0 +1,16 @@
+-- Ordered checklist items inside a task; ranks are the same keys tasks use
+CREATE TABLE IF NOT EXISTS checklist_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    content TEXT NOT NULL,
+    checked BOOLEAN NOT NULL DEFAULT FALSE,
+    checked_at TIMESTAMPTZ,
+    checked_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    assigned_to UUID REFERENCES users(id) ON DELETE SET NULL,
+    rank TEXT COLLATE "C" NOT NULL,
+    created_by UUID NOT NULL REFERENCES users(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_checklist_items_task_rank ON checklist_items(task_id, rank);
@@ -0,0 +1,31 @@
+-- Everyone assigned to a task; tasks.assigned_to stays as the primary assignee
+CREATE TABLE IF NOT EXISTS task_assignees (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, user_id)
+);
+
+-- Users who follow the changes of a task
+CREATE TABLE IF NOT EXISTS task_watchers (
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (task_id, user_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_assignees_user_id ON task_assignees(user_id);
+CREATE INDEX IF NOT EXISTS idx_task_watchers_user_id ON task_watchers(user_id);
+
+INSERT INTO task_assignees (task_id, user_id)
+SELECT id, assigned_to FROM tasks WHERE assigned_to IS NOT NULL
+ON CONFLICT DO NOTHING;
+
+-- Creators, assignees and commenters watch their existing tasks
+INSERT INTO task_watchers (task_id, user_id)
+SELECT id, created_by FROM tasks
+UNION
+SELECT id, assigned_to FROM tasks WHERE assigned_to IS NOT NULL
+UNION
+SELECT task_id, user_id FROM task_comments
+ON CONFLICT DO NOTHING;
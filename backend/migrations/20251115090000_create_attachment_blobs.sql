@@ -0,0 +1,46 @@
+-- Attachment content stored once per SHA-256 digest and shared by every
+-- attachment with the same bytes
+CREATE TABLE IF NOT EXISTS attachment_blobs (
+    sha256 CHAR(64) PRIMARY KEY,
+    size BIGINT NOT NULL,
+    -- Attachments pointing at the blob, kept by the trigger below
+    ref_count INTEGER NOT NULL DEFAULT 0,
+    -- Set once the content is in storage
+    stored BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Claimed by an upload or last referenced; garbage collection leaves
+    -- unreferenced blobs alone for a grace period after this
+    last_used_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachment_blobs_unreferenced
+    ON attachment_blobs(last_used_at) WHERE ref_count = 0;
+
+-- Attachments uploaded before deduplication keep their own file and no blob
+ALTER TABLE task_attachments
+    ADD COLUMN IF NOT EXISTS blob_sha256 CHAR(64) REFERENCES attachment_blobs(sha256);
+
+CREATE INDEX IF NOT EXISTS idx_task_attachments_blob_sha256 ON task_attachments(blob_sha256);
+
+-- Overrides the configured per-organization storage quota, in bytes
+ALTER TABLE organizations ADD COLUMN IF NOT EXISTS storage_quota_bytes BIGINT;
+
+-- Count references on insert and delete, including cascades from purged tasks
+CREATE OR REPLACE FUNCTION update_attachment_blob_refs()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'INSERT' AND NEW.blob_sha256 IS NOT NULL THEN
+        UPDATE attachment_blobs
+        SET ref_count = ref_count + 1, last_used_at = NOW()
+        WHERE sha256 = NEW.blob_sha256;
+    ELSIF TG_OP = 'DELETE' AND OLD.blob_sha256 IS NOT NULL THEN
+        UPDATE attachment_blobs
+        SET ref_count = ref_count - 1, last_used_at = NOW()
+        WHERE sha256 = OLD.blob_sha256;
+    END IF;
+    RETURN NULL;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER update_attachment_blob_refs AFTER INSERT OR DELETE ON task_attachments
+    FOR EACH ROW EXECUTE FUNCTION update_attachment_blob_refs();
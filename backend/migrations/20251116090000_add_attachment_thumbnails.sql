@@ -0,0 +1,18 @@
+-- Thumbnails of image blobs: NULL for content without one, otherwise
+-- 'pending' until the background job renders it as 'ready' or 'failed'
+ALTER TABLE attachment_blobs
+    ADD COLUMN IF NOT EXISTS thumbnail_status VARCHAR(20),
+    ADD COLUMN IF NOT EXISTS thumbnail_mime_type VARCHAR(100);
+
+CREATE INDEX IF NOT EXISTS idx_attachment_blobs_thumbnail_pending
+    ON attachment_blobs(created_at) WHERE thumbnail_status = 'pending';
+
+-- Images uploaded before thumbnails existed
+UPDATE attachment_blobs b
+SET thumbnail_status = 'pending'
+WHERE b.stored
+  AND EXISTS (
+      SELECT 1 FROM task_attachments a
+      WHERE a.blob_sha256 = b.sha256
+        AND a.mime_type IN ('image/png', 'image/jpeg', 'image/gif', 'image/webp')
+  );
@@ -0,0 +1,13 @@
+-- Malware scanning of attachment blobs: 'pending' until the background job
+-- has scanned the content, then 'clean' or 'infected'. Content already
+-- stored is scanned afresh.
+ALTER TABLE attachment_blobs
+    ADD COLUMN IF NOT EXISTS scan_status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (scan_status IN ('pending', 'clean', 'infected')),
+    -- What the scanner found in infected content
+    ADD COLUMN IF NOT EXISTS scan_signature VARCHAR(255),
+    -- Last scan attempt, successful or not
+    ADD COLUMN IF NOT EXISTS scanned_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_attachment_blobs_scan_pending
+    ON attachment_blobs(scanned_at NULLS FIRST, created_at) WHERE scan_status = 'pending';
@@ -0,0 +1,26 @@
+-- Attachments from before deduplication are moved onto blobs so they can be
+-- scanned, which sets blob_sha256 on existing rows: count those references too
+CREATE OR REPLACE FUNCTION update_attachment_blob_refs()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('DELETE', 'UPDATE') AND OLD.blob_sha256 IS NOT NULL THEN
+        UPDATE attachment_blobs
+        SET ref_count = ref_count - 1, last_used_at = NOW()
+        WHERE sha256 = OLD.blob_sha256;
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') AND NEW.blob_sha256 IS NOT NULL THEN
+        UPDATE attachment_blobs
+        SET ref_count = ref_count + 1, last_used_at = NOW()
+        WHERE sha256 = NEW.blob_sha256;
+    END IF;
+    RETURN NULL;
+END;
+$$ language 'plpgsql';
+
+DROP TRIGGER IF EXISTS update_attachment_blob_refs ON task_attachments;
+CREATE TRIGGER update_attachment_blob_refs
+    AFTER INSERT OR DELETE OR UPDATE OF blob_sha256 ON task_attachments
+    FOR EACH ROW EXECUTE FUNCTION update_attachment_blob_refs();
+
+CREATE INDEX IF NOT EXISTS idx_task_attachments_without_blob
+    ON task_attachments(created_at) WHERE blob_sha256 IS NULL;
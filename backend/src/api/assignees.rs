@@ -0,0 +1,133 @@
+use sqlx::{PgConnection, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    api::{history::record_changes, watchers::add_watchers},
+    services::task_history::diff_assignees,
+    utils::AppError,
+};
+
+/// Assignees of the given tasks, primary assignee first
+pub(crate) async fn assignees_for_tasks(
+    db: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Uuid>>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT ta.task_id, ta.user_id
+        FROM task_assignees ta
+        INNER JOIN tasks t ON t.id = ta.task_id
+        WHERE ta.task_id = ANY($1)
+        ORDER BY ta.task_id, ta.user_id IS DISTINCT FROM t.assigned_to, ta.created_at, ta.user_id
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch assignees".to_string())
+    })?;
+
+    let mut assignees: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for row in rows {
+        assignees.entry(row.task_id).or_default().push(row.user_id);
+    }
+    Ok(assignees)
+}
+
+/// Reject assignees that are not members of the organization
+pub(crate) async fn ensure_assignable(
+    conn: &mut PgConnection,
+    organization_id: Uuid,
+    user_ids: &[Uuid],
+) -> Result<(), AppError> {
+    if user_ids.is_empty() {
+        return Ok(());
+    }
+
+    let members = sqlx::query_scalar!(
+        "SELECT user_id FROM organization_members WHERE organization_id = $1 AND user_id = ANY($2)",
+        organization_id,
+        user_ids
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+
+    match user_ids.iter().find(|id| !members.contains(id)) {
+        Some(id) => Err(AppError::Validation(format!(
+            "Assignee {} is not a member of this organization",
+            id
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Add assignees to a task, who also start watching it
+pub(crate) async fn insert_assignees(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    user_ids: &[Uuid],
+) -> Result<(), AppError> {
+    sqlx::query!(
+        r#"
+        INSERT INTO task_assignees (task_id, user_id)
+        SELECT $1, UNNEST($2::uuid[])
+        ON CONFLICT DO NOTHING
+        "#,
+        task_id,
+        user_ids
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to add assignees".to_string())
+    })?;
+
+    add_watchers(&mut *conn, task_id, user_ids).await
+}
+
+/// Replace a task's assignees; the caller has already set `tasks.assigned_to`
+/// to the first of them. The change goes to the task history.
+pub(crate) async fn set_task_assignees(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    user_ids: &[Uuid],
+    actor_id: Uuid,
+) -> Result<(), AppError> {
+    let old = sqlx::query_scalar!(
+        "SELECT user_id FROM task_assignees WHERE task_id = $1 ORDER BY created_at, user_id",
+        task_id
+    )
+    .fetch_all(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch assignees".to_string())
+    })?;
+
+    let Some(change) = diff_assignees(&old, user_ids) else {
+        return Ok(());
+    };
+
+    sqlx::query!(
+        "DELETE FROM task_assignees WHERE task_id = $1 AND NOT (user_id = ANY($2))",
+        task_id,
+        user_ids
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update assignees".to_string())
+    })?;
+
+    insert_assignees(&mut *conn, task_id, user_ids).await?;
+    record_changes(&mut *conn, task_id, actor_id, vec![change]).await
+}
@@ -0,0 +1,714 @@
+use axum::{
+    body::Body,
+    extract::{multipart::{Field, MultipartError}, Multipart, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sqlx::PgExecutor;
+use std::{collections::HashSet, io::SeekFrom};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        AttachmentLinkResponse, AttachmentResponse, AuthUser, TaskAttachment, UploadAttachmentsQuery,
+    },
+    services::{
+        attachments::{
+            check_signature, content_disposition, is_inline, resolve_mime_type, sanitize_filename,
+            MAX_FILES_PER_UPLOAD,
+        },
+        blobs::{added_bytes, blob_key, check_quota, effective_quota, thumbnail_key},
+        images::{has_thumbnail, strip_metadata},
+        scanner,
+        storage::ResponseHeaders,
+    },
+    utils::AppError,
+};
+
+/// Leading bytes kept to check a file's signature
+const SIGNATURE_BYTES: usize = 16;
+
+/// Request body limit for uploads: a full batch of maximum-size files plus
+/// room for the multipart framing
+pub fn upload_body_limit(max_file_size: usize) -> usize {
+    max_file_size
+        .saturating_mul(MAX_FILES_PER_UPLOAD)
+        .saturating_add(64 * 1024)
+}
+
+/// A file put in storage but not yet recorded
+struct StoredFile {
+    filename: String,
+    sha256: String,
+    file_size: i64,
+    mime_type: String,
+    scan_status: String,
+}
+
+fn io_error(context: &str, e: std::io::Error) -> AppError {
+    tracing::error!("{}: {}", context, e);
+    AppError::Internal(context.to_string())
+}
+
+fn multipart_error(e: MultipartError) -> AppError {
+    if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        AppError::PayloadTooLarge("The upload is too large".to_string())
+    } else {
+        AppError::BadRequest(format!("Invalid multipart body: {}", e.body_text()))
+    }
+}
+
+/// A field spooled to an anonymous temporary file, rewound
+struct Spooled {
+    file: tokio::fs::File,
+    size: u64,
+    sha256: String,
+}
+
+/// Spool one multipart field to disk, hashing it and enforcing the size
+/// limit and the signature of its type
+async fn spool_field(
+    field: &mut Field<'_>,
+    mime_type: &str,
+    max_file_size: usize,
+) -> Result<Spooled, AppError> {
+    let spool = tempfile::tempfile().map_err(|e| io_error("Failed to create upload spool file", e))?;
+    let mut file = tokio::fs::File::from_std(spool);
+
+    let mut size = 0usize;
+    let mut hasher = Sha256::new();
+    let mut head = Vec::with_capacity(SIGNATURE_BYTES);
+    while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+        size += chunk.len();
+        if size > max_file_size {
+            return Err(AppError::PayloadTooLarge(format!(
+                "Files are limited to {} bytes",
+                max_file_size
+            )));
+        }
+        let missing = SIGNATURE_BYTES - head.len();
+        head.extend_from_slice(&chunk[..chunk.len().min(missing)]);
+        hasher.update(&chunk);
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| io_error("Failed to spool upload", e))?;
+    }
+
+    if size == 0 {
+        return Err(AppError::Validation("File is empty".to_string()));
+    }
+    check_signature(mime_type, &head)?;
+    file.flush()
+        .await
+        .map_err(|e| io_error("Failed to spool upload", e))?;
+    file.seek(SeekFrom::Start(0))
+        .await
+        .map_err(|e| io_error("Failed to spool upload", e))?;
+
+    Ok(Spooled { file, size: size as u64, sha256: hex::encode(hasher.finalize()) })
+}
+
+/// Re-spool a file without its image metadata, if it carries any
+async fn strip_spooled(mut spooled: Spooled, mime_type: &str) -> Result<Spooled, AppError> {
+    let mut data = Vec::with_capacity(spooled.size as usize);
+    spooled
+        .file
+        .read_to_end(&mut data)
+        .await
+        .map_err(|e| io_error("Failed to read spooled upload", e))?;
+
+    let stripped = strip_metadata(mime_type, &data)
+        .map_err(|e| AppError::Validation(format!("Could not remove image metadata: {}", e)))?;
+    let Some(stripped) = stripped else {
+        spooled
+            .file
+            .seek(SeekFrom::Start(0))
+            .await
+            .map_err(|e| io_error("Failed to spool upload", e))?;
+        return Ok(spooled);
+    };
+
+    let spool = tempfile::tempfile().map_err(|e| io_error("Failed to create upload spool file", e))?;
+    let mut file = tokio::fs::File::from_std(spool);
+    file.write_all(&stripped)
+        .await
+        .map_err(|e| io_error("Failed to spool upload", e))?;
+    file.flush()
+        .await
+        .map_err(|e| io_error("Failed to spool upload", e))?;
+    file.seek(SeekFrom::Start(0))
+        .await
+        .map_err(|e| io_error("Failed to spool upload", e))?;
+
+    Ok(Spooled {
+        file,
+        size: stripped.len() as u64,
+        sha256: hex::encode(Sha256::digest(&stripped)),
+    })
+}
+
+/// Make sure the blob for a spooled file is in storage. The blob row is
+/// claimed first, which keeps garbage collection off it for its grace period
+/// even if the upload fails before referencing it; content already stored
+/// under the same digest is not uploaded again. Returns the blob's scan status.
+async fn store_blob(
+    state: &AppState,
+    spooled: Spooled,
+    mime_type: &str,
+) -> Result<String, AppError> {
+    let Spooled { file, size, sha256 } = spooled;
+    let claim = sqlx::query!(
+        r#"
+        INSERT INTO attachment_blobs (sha256, size)
+        VALUES ($1, $2)
+        ON CONFLICT (sha256) DO UPDATE SET last_used_at = NOW()
+        RETURNING stored, scan_status
+        "#,
+        sha256,
+        size as i64
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to claim attachment blob".to_string())
+    })?;
+
+    if claim.stored {
+        return Ok(claim.scan_status);
+    }
+
+    state
+        .storage
+        .put(&blob_key(&sha256), Box::pin(ReaderStream::new(file)), size, mime_type)
+        .await?;
+
+    let thumbnail_status = has_thumbnail(mime_type).then_some("pending");
+    sqlx::query!(
+        "UPDATE attachment_blobs SET stored = TRUE, thumbnail_status = $2 WHERE sha256 = $1",
+        sha256,
+        thumbnail_status
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update attachment blob".to_string())
+    })?;
+
+    Ok(claim.scan_status)
+}
+
+/// Put the blob of every `file` field of the upload in storage
+async fn receive_files(
+    state: &AppState,
+    multipart: &mut Multipart,
+    strip: bool,
+) -> Result<Vec<StoredFile>, AppError> {
+    // Content larger than the scanner takes would never become downloadable
+    let max_file_size = match scanner::size_limit(&state.config.scanner) {
+        Some(limit) => state
+            .config
+            .storage
+            .max_file_size
+            .min(usize::try_from(limit).unwrap_or(usize::MAX)),
+        None => state.config.storage.max_file_size,
+    };
+    let mut stored = Vec::new();
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        if stored.len() == MAX_FILES_PER_UPLOAD {
+            return Err(AppError::Validation(format!(
+                "An upload holds at most {} files",
+                MAX_FILES_PER_UPLOAD
+            )));
+        }
+
+        let filename = sanitize_filename(field.file_name());
+        let mime_type = resolve_mime_type(field.content_type(), &filename)?;
+        let mut spooled = spool_field(&mut field, &mime_type, max_file_size).await?;
+        if strip {
+            spooled = strip_spooled(spooled, &mime_type).await?;
+        }
+        let sha256 = spooled.sha256.clone();
+        let file_size = spooled.size as i64;
+
+        let scan_status = store_blob(state, spooled, &mime_type).await?;
+        stored.push(StoredFile { filename, sha256, file_size, mime_type, scan_status });
+    }
+    Ok(stored)
+}
+
+/// Bytes of attachment storage an organization uses. Each blob counts once
+/// however many of its tasks share it; trashed tasks still count.
+pub(crate) async fn storage_used<'e>(
+    executor: impl PgExecutor<'e>,
+    org_id: Uuid,
+) -> Result<i64, AppError> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT COALESCE(SUM(u.file_size), 0)::BIGINT AS "used!"
+        FROM (
+            SELECT DISTINCT COALESCE(a.blob_sha256, a.id::TEXT) AS content, a.file_size
+            FROM task_attachments a
+            INNER JOIN tasks t ON a.task_id = t.id
+            INNER JOIN projects p ON t.project_id = p.id
+            WHERE p.organization_id = $1
+        ) u
+        "#,
+        org_id
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to compute storage usage".to_string())
+    })
+}
+
+/// Upload one or more files, each sent as a multipart field named `file`
+pub async fn upload_attachments(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<UploadAttachmentsQuery>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, Json<Vec<AttachmentResponse>>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let org_id = sqlx::query_scalar!(
+        r#"
+        SELECT p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::Authorization("You don't have access to this task".to_string()))?;
+
+    // Blobs stored for a failed upload are left to garbage collection
+    let stored = receive_files(&state, &mut multipart, query.strip_metadata).await?;
+    if stored.is_empty() {
+        return Err(AppError::Validation(
+            "Send the files as multipart fields named file".to_string(),
+        ));
+    }
+
+    let attachments = record_files(&state, org_id, task_id, user_id, &stored).await?;
+
+    tracing::info!("{} attachment(s) added to task {}", attachments.len(), task_id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(
+            attachments
+                .into_iter()
+                .zip(&stored)
+                .map(|(attachment, file)| AttachmentResponse {
+                    scan_status: file.scan_status.clone(),
+                    ..attachment.into()
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// Record the uploaded files against the task, within the organization's quota
+async fn record_files(
+    state: &AppState,
+    org_id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+    stored: &[StoredFile],
+) -> Result<Vec<TaskAttachment>, AppError> {
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    // Uploads to one organization take turns so they can't overshoot the quota together
+    let quota_override = sqlx::query_scalar!(
+        "SELECT storage_quota_bytes FROM organizations WHERE id = $1 FOR NO KEY UPDATE",
+        org_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to lock organization".to_string())
+    })?;
+
+    let digests: Vec<String> = stored.iter().map(|f| f.sha256.clone()).collect();
+    let held: HashSet<String> = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT a.blob_sha256 AS "blob_sha256!"
+        FROM task_attachments a
+        INNER JOIN tasks t ON a.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        WHERE p.organization_id = $1 AND a.blob_sha256 = ANY($2)
+        "#,
+        org_id,
+        &digests
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check stored files".to_string())
+    })?
+    .into_iter()
+    .collect();
+
+    let used = storage_used(&mut *tx, org_id).await?;
+    let added = added_bytes(stored.iter().map(|f| (f.sha256.as_str(), f.file_size)), &held);
+    check_quota(used, added, effective_quota(quota_override, state.config.storage.org_quota))?;
+
+    let mut attachments = Vec::with_capacity(stored.len());
+    for file in stored {
+        let attachment = sqlx::query_as!(
+            TaskAttachment,
+            r#"
+            INSERT INTO task_attachments (task_id, user_id, filename, file_path, file_size, mime_type, blob_sha256)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, task_id, user_id, filename, file_path, file_size, mime_type, created_at
+            "#,
+            task_id,
+            user_id,
+            file.filename,
+            blob_key(&file.sha256),
+            file.file_size,
+            file.mime_type,
+            file.sha256
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to record attachment".to_string())
+        })?;
+        attachments.push(attachment);
+    }
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit attachments".to_string())
+    })?;
+
+    Ok(attachments)
+}
+
+pub async fn get_task_attachments(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<AttachmentResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT a.id, a.task_id, a.user_id, a.filename, a.file_size, a.mime_type, a.created_at,
+               CASE WHEN b.scan_status = 'clean' THEN b.thumbnail_status END AS "thumbnail_status?",
+               COALESCE(b.scan_status, 'pending') AS "scan_status!"
+        FROM task_attachments a
+        LEFT JOIN attachment_blobs b ON a.blob_sha256 = b.sha256
+        WHERE a.task_id = $1
+        ORDER BY a.created_at, a.id
+        "#,
+        task_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch attachments".to_string())
+    })?;
+
+    let attachments = rows
+        .into_iter()
+        .map(|r| AttachmentResponse {
+            id: r.id,
+            task_id: r.task_id,
+            user_id: r.user_id,
+            filename: r.filename,
+            file_size: r.file_size,
+            mime_type: r.mime_type,
+            thumbnail_url: (r.thumbnail_status.as_deref() == Some("ready"))
+                .then(|| format!("/api/attachments/{}/thumbnail", r.id)),
+            scan_status: r.scan_status,
+            created_at: r.created_at,
+        })
+        .collect();
+
+    Ok(Json(attachments))
+}
+
+/// An attachment on a live task of one of the user's organizations, once its
+/// content has been scanned and found clean. Files from before deduplication
+/// stay pending until the scan job has moved them onto a blob and scanned it.
+async fn fetch_attachment(
+    state: &AppState,
+    attachment_id: Uuid,
+    user_id: Uuid,
+) -> Result<TaskAttachment, AppError> {
+    let row = sqlx::query!(
+        r#"
+        SELECT a.id, a.task_id, a.user_id, a.filename, a.file_path, a.file_size, a.mime_type, a.created_at,
+               COALESCE(b.scan_status, 'pending') AS "scan_status!"
+        FROM task_attachments a
+        LEFT JOIN attachment_blobs b ON a.blob_sha256 = b.sha256
+        INNER JOIN tasks t ON a.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE a.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        attachment_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch attachment".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Attachment not found".to_string()))?;
+
+    match row.scan_status.as_str() {
+        "clean" => {}
+        "infected" => {
+            return Err(AppError::Conflict(
+                "Attachment was found to contain malware and has been quarantined".to_string(),
+            ))
+        }
+        _ => {
+            return Err(AppError::Conflict(
+                "Attachment is still being scanned for malware".to_string(),
+            ))
+        }
+    }
+
+    Ok(TaskAttachment {
+        id: row.id,
+        task_id: row.task_id,
+        user_id: row.user_id,
+        filename: row.filename,
+        file_path: row.file_path,
+        file_size: row.file_size,
+        mime_type: row.mime_type,
+        created_at: row.created_at,
+    })
+}
+
+/// Stream an attachment's content to a member of its organization
+pub async fn download_attachment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let attachment = fetch_attachment(&state, attachment_id, user_id).await?;
+
+    let object = state.storage.get(&attachment.file_path).await.map_err(|e| {
+        if let AppError::NotFound(_) = e {
+            tracing::error!("Attachment {} has no stored file", attachment.id);
+            AppError::NotFound("Attachment content is missing".to_string())
+        } else {
+            e
+        }
+    })?;
+
+    let disposition = content_disposition(&attachment.filename, is_inline(&attachment.mime_type));
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.mime_type),
+            (header::CONTENT_LENGTH, object.size.to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (header::CACHE_CONTROL, "private, no-cache".to_string()),
+        ],
+        Body::from_stream(object.body),
+    )
+        .into_response())
+}
+
+/// Stream the thumbnail of an image attachment
+pub async fn get_attachment_thumbnail(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let thumbnail = sqlx::query!(
+        r#"
+        SELECT b.sha256, b.thumbnail_mime_type AS "mime_type!"
+        FROM task_attachments a
+        INNER JOIN attachment_blobs b ON a.blob_sha256 = b.sha256
+        INNER JOIN tasks t ON a.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE a.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+          AND b.thumbnail_status = 'ready' AND b.scan_status = 'clean'
+        "#,
+        attachment_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch thumbnail".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Thumbnail not found".to_string()))?;
+
+    let object = state.storage.get(&thumbnail_key(&thumbnail.sha256)).await?;
+
+    // Content-addressed, so a thumbnail never changes
+    Ok((
+        [
+            (header::CONTENT_TYPE, thumbnail.mime_type),
+            (header::CONTENT_LENGTH, object.size.to_string()),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+            (header::CACHE_CONTROL, "private, max-age=86400, immutable".to_string()),
+        ],
+        Body::from_stream(object.body),
+    )
+        .into_response())
+}
+
+/// A short-lived direct link to an attachment when the storage backend can
+/// presign one, or else the API download path
+pub async fn get_attachment_link(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<Json<AttachmentLinkResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let attachment = fetch_attachment(&state, attachment_id, user_id).await?;
+
+    // Deduplicated blobs carry the content type of whichever upload stored
+    // them first, and the bucket can't send nosniff, so links always serve
+    // this attachment's type as a download
+    let disposition = content_disposition(&attachment.filename, false);
+    let headers = ResponseHeaders {
+        content_type: &attachment.mime_type,
+        content_disposition: &disposition,
+    };
+    let expires_in = std::time::Duration::from_secs(state.config.storage.presign_expiry);
+    let link = match state.storage.presign(&attachment.file_path, expires_in, &headers) {
+        Some(url) => AttachmentLinkResponse {
+            url,
+            expires_at: Some(Utc::now() + chrono::Duration::seconds(expires_in.as_secs() as i64)),
+        },
+        None => AttachmentLinkResponse {
+            url: format!("/api/attachments/{}/download", attachment.id),
+            expires_at: None,
+        },
+    };
+
+    Ok(Json(link))
+}
+
+/// Delete an attachment. Uploaders may delete their own files; organization
+/// owners and admins may delete any.
+pub async fn delete_attachment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(attachment_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let access = sqlx::query!(
+        r#"
+        SELECT a.user_id, a.file_path, a.blob_sha256, om.role
+        FROM task_attachments a
+        INNER JOIN tasks t ON a.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE a.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        attachment_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check attachment access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Attachment not found".to_string()))?;
+
+    if access.user_id != user_id && access.role != "owner" && access.role != "admin" {
+        return Err(AppError::Authorization(
+            "Only the uploader or an organization admin can delete this attachment".to_string(),
+        ));
+    }
+
+    sqlx::query!("DELETE FROM task_attachments WHERE id = $1", attachment_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete attachment".to_string())
+        })?;
+
+    // Shared blobs are garbage collected once unreferenced. Files from before
+    // deduplication belong to this attachment alone; the row is gone, so one
+    // left behind is only wasted space.
+    if access.blob_sha256.is_none() {
+        if let Err(e) = state.storage.delete(&access.file_path).await {
+            tracing::warn!("Failed to remove attachment file {}: {}", access.file_path, e);
+        }
+    }
+
+    tracing::info!("Attachment deleted: {}", attachment_id);
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,315 @@
+use axum::{extract::State, response::Json, Extension};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    api::{
+        assignees::{ensure_assignable, set_task_assignees},
+        history::record_task_changes,
+        recurrence::advance_on_completion,
+        tasks::{ensure_can_complete, lock_task},
+        transfer::{leave_project, take_project_slot},
+    },
+    models::{AuthUser, Task, BulkTaskChanges, BulkTaskRequest, BulkTaskResponse, BulkTaskResult},
+    utils::AppError,
+};
+
+const MAX_BULK_TASKS: usize = 200;
+
+fn validate_request(payload: &BulkTaskRequest) -> Result<Vec<Uuid>, AppError> {
+    let mut task_ids = Vec::with_capacity(payload.task_ids.len());
+    for id in &payload.task_ids {
+        if !task_ids.contains(id) {
+            task_ids.push(*id);
+        }
+    }
+    if task_ids.is_empty() || task_ids.len() > MAX_BULK_TASKS {
+        return Err(AppError::Validation(format!(
+            "task_ids must contain between 1 and {} tasks",
+            MAX_BULK_TASKS
+        )));
+    }
+
+    let changes = &payload.changes;
+    let has_updates = changes.status.is_some()
+        || changes.priority.is_some()
+        || changes.assigned_to.is_set()
+        || !changes.add_label_ids.is_empty()
+        || !changes.remove_label_ids.is_empty()
+        || changes.project_id.is_some();
+
+    if changes.delete && has_updates {
+        return Err(AppError::Validation(
+            "delete cannot be combined with other changes".to_string(),
+        ));
+    }
+    if !changes.delete && !has_updates {
+        return Err(AppError::Validation("No changes given".to_string()));
+    }
+
+    Ok(task_ids)
+}
+
+/// Database failures abort the whole request; anything else is reported per task
+fn is_fatal(error: &AppError) -> bool {
+    matches!(error, AppError::DatabaseError(_) | AppError::Database(_))
+}
+
+fn item_error(error: AppError) -> String {
+    match error {
+        AppError::Validation(msg)
+        | AppError::Authorization(msg)
+        | AppError::NotFound(msg)
+        | AppError::Conflict(msg)
+        | AppError::BadRequest(msg) => msg,
+        other => other.to_string(),
+    }
+}
+
+/// Authorize and apply the changes to one task inside the bulk transaction
+async fn apply_to_task(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    user_id: Uuid,
+    changes: &BulkTaskChanges,
+) -> Result<(), AppError> {
+    let task = sqlx::query!(
+        r#"
+        SELECT t.id, t.project_id, t.status, p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        FOR UPDATE OF t
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task not found".to_string()))?;
+
+    if changes.delete {
+        sqlx::query!(
+            r#"
+            UPDATE tasks
+            SET deleted_at = NOW(), deleted_by = $2, version = version + 1, updated_at = NOW()
+            WHERE id = $1
+            "#,
+            task.id,
+            user_id
+        )
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to delete task".to_string())
+            })?;
+        return Ok(());
+    }
+
+    let project_id = changes.project_id.unwrap_or(task.project_id);
+    let moving = project_id != task.project_id;
+
+    if moving {
+        let target = sqlx::query!(
+            "SELECT id FROM projects WHERE id = $1 AND organization_id = $2",
+            project_id,
+            task.organization_id
+        )
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check project access".to_string())
+        })?;
+
+        if target.is_none() {
+            return Err(AppError::NotFound(
+                "Target project not found in this organization".to_string(),
+            ));
+        }
+    }
+
+    // A bulk assignment replaces all assignees with the one user
+    let assignees: Option<Vec<Uuid>> = changes
+        .assigned_to
+        .is_set()
+        .then(|| changes.assigned_to.value().copied().into_iter().collect());
+    if let Some(ref ids) = assignees {
+        ensure_assignable(&mut *conn, task.organization_id, ids).await?;
+    }
+
+    if changes.status.as_deref() == Some("done") {
+        ensure_can_complete(&mut *conn, task.id, project_id).await?;
+    }
+
+    // Labels must be organization-wide or belong to the (new) project
+    if !changes.add_label_ids.is_empty() {
+        let usable = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM labels
+            WHERE id = ANY($1) AND organization_id = $2
+              AND (project_id IS NULL OR project_id = $3)
+            "#,
+            &changes.add_label_ids,
+            task.organization_id,
+            project_id
+        )
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check labels".to_string())
+        })?;
+
+        let mut requested = changes.add_label_ids.clone();
+        requested.sort();
+        requested.dedup();
+        if usable != requested.len() as i64 {
+            return Err(AppError::NotFound("Label not found".to_string()));
+        }
+    }
+
+    let old = lock_task(&mut *conn, task.id).await?;
+
+    // A moved task goes to the end of its column in the new project and takes
+    // that project's next number
+    let (rank, number) = if moving {
+        let (rank, number) = take_project_slot(&mut *conn, project_id).await?;
+        (Some(rank), Some(number))
+    } else {
+        (None, None)
+    };
+
+    let updated = sqlx::query_as!(
+        Task,
+        r#"
+        UPDATE tasks
+        SET
+            project_id = $2,
+            status = COALESCE($3, status),
+            priority = COALESCE($4, priority),
+            assigned_to = CASE WHEN $5 THEN $6 ELSE assigned_to END,
+            rank = COALESCE($7, rank),
+            number = COALESCE($8, number),
+            completed_at = CASE
+                WHEN COALESCE($3, status) <> 'done' THEN NULL
+                WHEN status = 'done' THEN completed_at
+                ELSE NOW()
+            END,
+            version = version + 1,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        "#,
+        task.id,
+        project_id,
+        changes.status,
+        changes.priority,
+        changes.assigned_to.is_set(),
+        changes.assigned_to.value().copied(),
+        rank,
+        number
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update task".to_string())
+    })?;
+
+    record_task_changes(&mut *conn, &old, &updated, user_id).await?;
+    if let Some(ids) = assignees {
+        set_task_assignees(&mut *conn, task.id, &ids, user_id).await?;
+    }
+    advance_on_completion(&mut *conn, &old, &updated).await?;
+
+    if moving {
+        leave_project(&mut *conn, &old).await?;
+    }
+
+    if !changes.remove_label_ids.is_empty() {
+        sqlx::query!(
+            "DELETE FROM task_labels WHERE task_id = $1 AND label_id = ANY($2)",
+            task.id,
+            &changes.remove_label_ids
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to remove labels".to_string())
+        })?;
+    }
+
+    if !changes.add_label_ids.is_empty() {
+        sqlx::query!(
+            r#"
+            INSERT INTO task_labels (task_id, label_id)
+            SELECT $1, UNNEST($2::uuid[])
+            ON CONFLICT DO NOTHING
+            "#,
+            task.id,
+            &changes.add_label_ids
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to add labels".to_string())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Apply one set of changes to many tasks. Each task is authorized on its own;
+/// if any task fails, nothing is changed and the report says which ones failed.
+pub async fn bulk_update_tasks(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Json(payload): Json<BulkTaskRequest>,
+) -> Result<Json<BulkTaskResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let task_ids = validate_request(&payload)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let mut results = Vec::with_capacity(task_ids.len());
+    for task_id in task_ids {
+        let result = match apply_to_task(&mut tx, task_id, user_id, &payload.changes).await {
+            Ok(()) => BulkTaskResult { task_id, ok: true, error: None },
+            Err(e) if is_fatal(&e) => return Err(e),
+            Err(e) => BulkTaskResult { task_id, ok: false, error: Some(item_error(e)) },
+        };
+        results.push(result);
+    }
+
+    let applied = results.iter().all(|r| r.ok);
+    if applied {
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to commit bulk update".to_string())
+        })?;
+        tracing::info!("Bulk update applied to {} tasks", results.len());
+    } else {
+        tx.rollback().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to roll back bulk update".to_string())
+        })?;
+    }
+
+    Ok(Json(BulkTaskResponse { applied, results }))
+}
@@ -0,0 +1,506 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use sqlx::{PgConnection, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        AuthUser, ChecklistItem, ChecklistItemResponse, ChecklistProgress,
+        CreateChecklistItemRequest, MoveChecklistItemRequest, UpdateChecklistItemRequest,
+    },
+    services::rank,
+    utils::AppError,
+};
+
+const MAX_CHECKLIST_ITEMS: i64 = 200;
+const MAX_CONTENT_CHARS: usize = 1000;
+
+/// Checked and total item counts of the given tasks that have a checklist
+pub(crate) async fn checklist_progress_for_tasks(
+    db: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, ChecklistProgress>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT task_id, COUNT(*) FILTER (WHERE checked) AS "checked!", COUNT(*) AS "total!"
+        FROM checklist_items
+        WHERE task_id = ANY($1)
+        GROUP BY task_id
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch checklist progress".to_string())
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| (r.task_id, ChecklistProgress { checked: r.checked, total: r.total }))
+        .collect())
+}
+
+/// Give `to_task_id` an unchecked copy of the checklist of `from_task_id`
+pub(crate) async fn copy_checklist(
+    conn: &mut PgConnection,
+    from_task_id: Uuid,
+    to_task_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query!(
+        r#"
+        INSERT INTO checklist_items (task_id, content, assigned_to, rank, created_by)
+        SELECT $2, content, assigned_to, rank, created_by
+        FROM checklist_items
+        WHERE task_id = $1
+        "#,
+        from_task_id,
+        to_task_id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to copy checklist".to_string())
+    })?;
+
+    Ok(())
+}
+
+fn validate_content(content: &str) -> Result<String, AppError> {
+    let content = content.trim();
+    if content.is_empty() || content.chars().count() > MAX_CONTENT_CHARS {
+        return Err(AppError::Validation(format!(
+            "content must be between 1 and {} characters",
+            MAX_CONTENT_CHARS
+        )));
+    }
+    Ok(content.to_string())
+}
+
+/// Check access to a live task and lock it so its checklist ranks stay
+/// consistent until commit. Returns the task's organization.
+async fn lock_task_checklist(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    user_id: Uuid,
+) -> Result<Uuid, AppError> {
+    let access = sqlx::query!(
+        r#"
+        SELECT p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        FOR NO KEY UPDATE OF t
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    match access {
+        Some(access) => Ok(access.organization_id),
+        None => Err(AppError::Authorization("You don't have access to this task".to_string())),
+    }
+}
+
+async fn fetch_item(conn: &mut PgConnection, item_id: Uuid) -> Result<ChecklistItem, AppError> {
+    sqlx::query_as!(
+        ChecklistItem,
+        r#"
+        SELECT id, task_id, content, checked, checked_at, checked_by, assigned_to, rank, created_by, created_at, updated_at
+        FROM checklist_items
+        WHERE id = $1
+        "#,
+        item_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch checklist item".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Checklist item not found".to_string()))
+}
+
+async fn ensure_member(
+    conn: &mut PgConnection,
+    organization_id: Uuid,
+    assignee: Uuid,
+) -> Result<(), AppError> {
+    let member = sqlx::query!(
+        "SELECT id FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        organization_id,
+        assignee
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+
+    if member.is_none() {
+        return Err(AppError::Validation(
+            "Assignee is not a member of this organization".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rank of a neighbor for a move; it must be on the same checklist
+async fn neighbor_rank(
+    conn: &mut PgConnection,
+    neighbor_id: Uuid,
+    task_id: Uuid,
+) -> Result<String, AppError> {
+    let neighbor = sqlx::query_scalar!(
+        "SELECT rank FROM checklist_items WHERE id = $1 AND task_id = $2",
+        neighbor_id,
+        task_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch neighbor item".to_string())
+    })?;
+
+    neighbor.ok_or_else(|| {
+        AppError::Validation("Neighbor items must be on the same checklist".to_string())
+    })
+}
+
+pub async fn get_checklist(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<ChecklistItemResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    let items = sqlx::query_as!(
+        ChecklistItem,
+        r#"
+        SELECT id, task_id, content, checked, checked_at, checked_by, assigned_to, rank, created_by, created_at, updated_at
+        FROM checklist_items
+        WHERE task_id = $1
+        ORDER BY rank
+        "#,
+        task_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch checklist".to_string())
+    })?;
+
+    Ok(Json(items.into_iter().map(Into::into).collect()))
+}
+
+/// Add an item to the end of a task's checklist
+pub async fn create_checklist_item(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<CreateChecklistItemRequest>,
+) -> Result<(StatusCode, Json<ChecklistItemResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let content = validate_content(&payload.content)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let organization_id = lock_task_checklist(&mut tx, task_id, user_id).await?;
+    if let Some(assignee) = payload.assigned_to {
+        ensure_member(&mut tx, organization_id, assignee).await?;
+    }
+
+    let existing = sqlx::query!(
+        r#"SELECT COUNT(*) AS "count!", MAX(rank) AS last_rank FROM checklist_items WHERE task_id = $1"#,
+        task_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch checklist".to_string())
+    })?;
+
+    if existing.count >= MAX_CHECKLIST_ITEMS {
+        return Err(AppError::Validation(format!(
+            "A checklist holds at most {} items",
+            MAX_CHECKLIST_ITEMS
+        )));
+    }
+
+    let item = sqlx::query_as!(
+        ChecklistItem,
+        r#"
+        INSERT INTO checklist_items (task_id, content, assigned_to, rank, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, task_id, content, checked, checked_at, checked_by, assigned_to, rank, created_by, created_at, updated_at
+        "#,
+        task_id,
+        content,
+        payload.assigned_to,
+        rank::between(existing.last_rank.as_deref(), None)?,
+        user_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create checklist item".to_string())
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit checklist item".to_string())
+    })?;
+
+    Ok((StatusCode::CREATED, Json(item.into())))
+}
+
+/// Edit, check/uncheck or (re)assign an item
+pub async fn update_checklist_item(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<UpdateChecklistItemRequest>,
+) -> Result<Json<ChecklistItemResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let content = payload
+        .content
+        .required("content")?
+        .map(|c| validate_content(&c))
+        .transpose()?;
+    let checked = payload.checked.required("checked")?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let item = fetch_item(&mut tx, item_id).await?;
+    let organization_id = lock_task_checklist(&mut tx, item.task_id, user_id).await?;
+    if let Some(&assignee) = payload.assigned_to.value() {
+        ensure_member(&mut tx, organization_id, assignee).await?;
+    }
+
+    // `checked_at` and `checked_by` follow the checked state
+    let item = sqlx::query_as!(
+        ChecklistItem,
+        r#"
+        UPDATE checklist_items
+        SET
+            content = COALESCE($2, content),
+            checked = COALESCE($3, checked),
+            checked_at = CASE
+                WHEN NOT COALESCE($3, checked) THEN NULL
+                WHEN checked THEN checked_at
+                ELSE NOW()
+            END,
+            checked_by = CASE
+                WHEN NOT COALESCE($3, checked) THEN NULL
+                WHEN checked THEN checked_by
+                ELSE $6
+            END,
+            assigned_to = CASE WHEN $4 THEN $5 ELSE assigned_to END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, task_id, content, checked, checked_at, checked_by, assigned_to, rank, created_by, created_at, updated_at
+        "#,
+        item_id,
+        content,
+        checked,
+        payload.assigned_to.is_set(),
+        payload.assigned_to.into_option(),
+        user_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update checklist item".to_string())
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit checklist item".to_string())
+    })?;
+
+    Ok(Json(item.into()))
+}
+
+/// Move an item between the given neighbors of its checklist
+pub async fn move_checklist_item(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(item_id): Path<Uuid>,
+    Json(payload): Json<MoveChecklistItemRequest>,
+) -> Result<Json<ChecklistItemResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    if payload.before_id == Some(item_id) || payload.after_id == Some(item_id) {
+        return Err(AppError::Validation("An item cannot be its own neighbor".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let item = fetch_item(&mut tx, item_id).await?;
+    lock_task_checklist(&mut tx, item.task_id, user_id).await?;
+
+    let before = match payload.before_id {
+        Some(id) => Some(neighbor_rank(&mut tx, id, item.task_id).await?),
+        None => None,
+    };
+    let after = match payload.after_id {
+        Some(id) => Some(neighbor_rank(&mut tx, id, item.task_id).await?),
+        None => None,
+    };
+
+    // Fill in a missing neighbor from the checklist itself
+    let (before, after) = match (before, after) {
+        (Some(before), None) => {
+            let next = sqlx::query_scalar!(
+                "SELECT MIN(rank) FROM checklist_items WHERE task_id = $1 AND id <> $2 AND rank > $3",
+                item.task_id,
+                item_id,
+                before
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to fetch neighbor item".to_string())
+            })?;
+            (Some(before), next)
+        }
+        (None, after) => {
+            let previous = sqlx::query_scalar!(
+                r#"
+                SELECT MAX(rank) FROM checklist_items
+                WHERE task_id = $1 AND id <> $2 AND ($3::text IS NULL OR rank < $3)
+                "#,
+                item.task_id,
+                item_id,
+                after
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to fetch neighbor item".to_string())
+            })?;
+            (previous, after)
+        }
+        neighbors => neighbors,
+    };
+
+    let new_rank = rank::between(before.as_deref(), after.as_deref())?;
+
+    let item = sqlx::query_as!(
+        ChecklistItem,
+        r#"
+        UPDATE checklist_items
+        SET rank = $2, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, task_id, content, checked, checked_at, checked_by, assigned_to, rank, created_by, created_at, updated_at
+        "#,
+        item_id,
+        new_rank
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to move checklist item".to_string())
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit checklist move".to_string())
+    })?;
+
+    Ok(Json(item.into()))
+}
+
+pub async fn delete_checklist_item(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(item_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let item = fetch_item(&mut tx, item_id).await?;
+    lock_task_checklist(&mut tx, item.task_id, user_id).await?;
+
+    sqlx::query!("DELETE FROM checklist_items WHERE id = $1", item_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete checklist item".to_string())
+        })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit checklist item".to_string())
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,557 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        AuthUser, CreateCustomFieldRequest, CustomField, CustomFieldResponse,
+        CustomFieldValueResponse, SetCustomFieldValueRequest, UpdateCustomFieldRequest,
+    },
+    services::custom_fields::{normalize_value, validate_options, CustomFieldType},
+    utils::AppError,
+};
+
+/// Whether the user administers the project, as an org owner/admin or a
+/// project admin. Fails when the user has no access to the project at all.
+pub(crate) async fn is_project_admin(
+    db: &sqlx::PgPool,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> Result<bool, AppError> {
+    let access = sqlx::query!(
+        r#"
+        SELECT om.role AS org_role, pm.role AS "project_role?"
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        LEFT JOIN project_members pm ON pm.project_id = p.id AND pm.user_id = om.user_id
+        WHERE p.id = $1 AND om.user_id = $2
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?;
+
+    match access {
+        Some(a) => Ok(a.org_role == "owner"
+            || a.org_role == "admin"
+            || a.project_role.as_deref() == Some("admin")),
+        None => Err(AppError::Authorization(
+            "You don't have access to this project".to_string(),
+        )),
+    }
+}
+
+/// Require the user to administer the project: an organization owner/admin or a project admin
+async fn check_project_admin(
+    db: &sqlx::PgPool,
+    project_id: Uuid,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    if is_project_admin(db, project_id, user_id).await? {
+        Ok(())
+    } else {
+        Err(AppError::Authorization(
+            "Only project admins can manage custom fields".to_string(),
+        ))
+    }
+}
+
+fn parse_field_type(field_type: &str) -> Result<CustomFieldType, AppError> {
+    CustomFieldType::parse(field_type)
+        .ok_or_else(|| AppError::Internal(format!("Unknown custom field type: {}", field_type)))
+}
+
+/// Fetch a custom field that belongs to `project_id`
+pub(crate) async fn fetch_project_field(
+    db: &sqlx::PgPool,
+    project_id: Uuid,
+    field_id: Uuid,
+) -> Result<CustomField, AppError> {
+    sqlx::query_as!(
+        CustomField,
+        r#"
+        SELECT id, project_id, name, field_type, options, is_required, position, created_by, created_at, updated_at
+        FROM custom_fields
+        WHERE id = $1 AND project_id = $2
+        "#,
+        field_id,
+        project_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch custom field".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Custom field not found".to_string()))
+}
+
+/// Validate a value for `field` and return its canonical form, checking that
+/// user values refer to members of the project's organization
+async fn validate_field_value(
+    db: &sqlx::PgPool,
+    field: &CustomField,
+    value: &Value,
+) -> Result<Value, AppError> {
+    let field_type = parse_field_type(&field.field_type)?;
+    let value = normalize_value(field_type, &field.options, value)?;
+
+    if field_type == CustomFieldType::User {
+        let user_id = value
+            .as_str()
+            .and_then(|s| Uuid::parse_str(s).ok())
+            .ok_or_else(|| AppError::Validation("Invalid user ID".to_string()))?;
+
+        let member = sqlx::query!(
+            r#"
+            SELECT om.id
+            FROM projects p
+            INNER JOIN organization_members om ON p.organization_id = om.organization_id
+            WHERE p.id = $1 AND om.user_id = $2
+            "#,
+            field.project_id,
+            user_id
+        )
+        .fetch_optional(db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check organization membership".to_string())
+        })?;
+
+        if member.is_none() {
+            return Err(AppError::Validation(format!(
+                "User {} is not a member of this organization",
+                user_id
+            )));
+        }
+    }
+
+    Ok(value)
+}
+
+/// Validate the custom field values supplied when creating a task, making sure
+/// every required field of the project is present
+pub(crate) async fn prepare_initial_values(
+    db: &sqlx::PgPool,
+    project_id: Uuid,
+    values: Option<&HashMap<Uuid, Value>>,
+) -> Result<Vec<(Uuid, Value)>, AppError> {
+    let fields = sqlx::query_as!(
+        CustomField,
+        r#"
+        SELECT id, project_id, name, field_type, options, is_required, position, created_by, created_at, updated_at
+        FROM custom_fields
+        WHERE project_id = $1
+        "#,
+        project_id
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch custom fields".to_string())
+    })?;
+
+    let empty = HashMap::new();
+    let values = values.unwrap_or(&empty);
+
+    if let Some(unknown) = values.keys().find(|id| !fields.iter().any(|f| f.id == **id)) {
+        return Err(AppError::Validation(format!("Unknown custom field: {}", unknown)));
+    }
+
+    let mut prepared = Vec::new();
+    for field in &fields {
+        match values.get(&field.id) {
+            Some(value) if !value.is_null() => {
+                prepared.push((field.id, validate_field_value(db, field, value).await?));
+            }
+            _ if field.is_required => {
+                return Err(AppError::Validation(format!(
+                    "Custom field '{}' is required",
+                    field.name
+                )));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(prepared)
+}
+
+/// Load the custom field values set on each of `task_ids`
+pub(crate) async fn custom_field_values_for_tasks(
+    db: &sqlx::PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<CustomFieldValueResponse>>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT v.task_id, f.id AS field_id, f.name, f.field_type, v.value
+        FROM task_custom_field_values v
+        INNER JOIN custom_fields f ON v.field_id = f.id
+        WHERE v.task_id = ANY($1)
+        ORDER BY f.position ASC, f.name ASC
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch custom field values".to_string())
+    })?;
+
+    let mut values: HashMap<Uuid, Vec<CustomFieldValueResponse>> = HashMap::new();
+    for row in rows {
+        values.entry(row.task_id).or_default().push(CustomFieldValueResponse {
+            field_id: row.field_id,
+            name: row.name,
+            field_type: row.field_type,
+            value: row.value,
+        });
+    }
+
+    Ok(values)
+}
+
+pub async fn create_custom_field(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(payload): Json<CreateCustomFieldRequest>,
+) -> Result<(StatusCode, Json<CustomFieldResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_project_admin(&state.db, project_id, user_id).await?;
+
+    let name = payload.name.trim();
+    if name.is_empty() || name.len() > 100 {
+        return Err(AppError::Validation(
+            "Field name must be between 1 and 100 characters".to_string(),
+        ));
+    }
+
+    let field_type = CustomFieldType::parse(&payload.field_type).ok_or_else(|| {
+        AppError::Validation(format!("Unknown field type: {}", payload.field_type))
+    })?;
+    let options = payload.options.unwrap_or_default();
+    validate_options(field_type, &options)?;
+
+    // Check if name is unique within the project
+    let existing = sqlx::query!(
+        "SELECT id FROM custom_fields WHERE project_id = $1 AND name = $2",
+        project_id,
+        name
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check custom field name".to_string())
+    })?;
+
+    if existing.is_some() {
+        return Err(AppError::Conflict(
+            "A custom field with this name already exists in this project".to_string(),
+        ));
+    }
+
+    let field = sqlx::query_as!(
+        CustomField,
+        r#"
+        INSERT INTO custom_fields (project_id, name, field_type, options, is_required, position, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, project_id, name, field_type, options, is_required, position, created_by, created_at, updated_at
+        "#,
+        project_id,
+        name,
+        field_type.as_str(),
+        &options,
+        payload.is_required.unwrap_or(false),
+        payload.position.unwrap_or(0),
+        user_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create custom field".to_string())
+    })?;
+
+    tracing::info!("Custom field created: {} in project {}", field.name, project_id);
+
+    Ok((StatusCode::CREATED, Json(field.into())))
+}
+
+pub async fn get_project_custom_fields(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<CustomFieldResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let fields = sqlx::query_as!(
+        CustomField,
+        r#"
+        SELECT f.id, f.project_id, f.name, f.field_type, f.options, f.is_required, f.position, f.created_by, f.created_at, f.updated_at
+        FROM custom_fields f
+        INNER JOIN projects p ON f.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE f.project_id = $1 AND om.user_id = $2
+        ORDER BY f.position ASC, f.name ASC
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch custom fields".to_string())
+    })?;
+
+    Ok(Json(fields.into_iter().map(|f| f.into()).collect()))
+}
+
+pub async fn update_custom_field(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(field_id): Path<Uuid>,
+    Json(payload): Json<UpdateCustomFieldRequest>,
+) -> Result<Json<CustomFieldResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let project_id = sqlx::query_scalar!("SELECT project_id FROM custom_fields WHERE id = $1", field_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch custom field".to_string())
+        })?
+        .ok_or_else(|| AppError::NotFound("Custom field not found".to_string()))?;
+
+    check_project_admin(&state.db, project_id, user_id).await?;
+    let field = fetch_project_field(&state.db, project_id, field_id).await?;
+
+    if let Some(ref name) = payload.name {
+        if name.trim().is_empty() || name.trim().len() > 100 {
+            return Err(AppError::Validation(
+                "Field name must be between 1 and 100 characters".to_string(),
+            ));
+        }
+    }
+    let field_type = parse_field_type(&field.field_type)?;
+    if let Some(ref options) = payload.options {
+        // Including an empty list, which would otherwise prune every stored value
+        if !field_type.has_options() {
+            return Err(AppError::Validation(format!(
+                "Fields of type {} do not take options",
+                field_type.as_str()
+            )));
+        }
+        validate_options(field_type, options)?;
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let field = sqlx::query_as!(
+        CustomField,
+        r#"
+        UPDATE custom_fields
+        SET
+            name = COALESCE($2, name),
+            options = COALESCE($3, options),
+            is_required = COALESCE($4, is_required),
+            position = COALESCE($5, position),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, name, field_type, options, is_required, position, created_by, created_at, updated_at
+        "#,
+        field_id,
+        payload.name.as_deref().map(str::trim),
+        payload.options.as_deref(),
+        payload.is_required,
+        payload.position
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update custom field".to_string())
+    })?;
+
+    // Drop selections of options that no longer exist
+    if payload.options.is_some() {
+        sqlx::query!(
+            r#"
+            DELETE FROM task_custom_field_values
+            WHERE field_id = $1
+              AND jsonb_typeof(value) = 'string'
+              AND NOT (value #>> '{}') = ANY($2)
+            "#,
+            field_id,
+            &field.options
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to update custom field values".to_string())
+        })?;
+
+        sqlx::query!(
+            r#"
+            UPDATE task_custom_field_values
+            SET value = COALESCE(
+                (SELECT jsonb_agg(e) FROM jsonb_array_elements_text(value) e WHERE e = ANY($2)),
+                '[]'::jsonb
+            )
+            WHERE field_id = $1 AND jsonb_typeof(value) = 'array'
+            "#,
+            field_id,
+            &field.options
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to update custom field values".to_string())
+        })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit custom field".to_string())
+    })?;
+
+    tracing::info!("Custom field updated: {}", field.id);
+
+    Ok(Json(field.into()))
+}
+
+pub async fn delete_custom_field(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(field_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let project_id = sqlx::query_scalar!("SELECT project_id FROM custom_fields WHERE id = $1", field_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch custom field".to_string())
+        })?
+        .ok_or_else(|| AppError::NotFound("Custom field not found".to_string()))?;
+
+    check_project_admin(&state.db, project_id, user_id).await?;
+
+    sqlx::query!("DELETE FROM custom_fields WHERE id = $1", field_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete custom field".to_string())
+        })?;
+
+    tracing::info!("Custom field deleted: {}", field_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn set_task_custom_field_value(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, field_id)): Path<(Uuid, Uuid)>,
+    Json(payload): Json<SetCustomFieldValueRequest>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let task = sqlx::query!(
+        r#"
+        SELECT t.project_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::Authorization("You don't have access to this task".to_string()))?;
+
+    let field = fetch_project_field(&state.db, task.project_id, field_id).await?;
+
+    if payload.value.is_null() {
+        if field.is_required {
+            return Err(AppError::Validation(format!(
+                "Custom field '{}' is required",
+                field.name
+            )));
+        }
+
+        sqlx::query!(
+            "DELETE FROM task_custom_field_values WHERE task_id = $1 AND field_id = $2",
+            task_id,
+            field_id
+        )
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to clear custom field value".to_string())
+        })?;
+
+        return Ok(StatusCode::NO_CONTENT);
+    }
+
+    let value = validate_field_value(&state.db, &field, &payload.value).await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_custom_field_values (task_id, field_id, value, updated_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (task_id, field_id)
+        DO UPDATE SET value = EXCLUDED.value, updated_by = EXCLUDED.updated_by, updated_at = NOW()
+        "#,
+        task_id,
+        field_id,
+        value,
+        user_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to set custom field value".to_string())
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,396 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        AuthUser, CreateDependencyRequest, DependencyGraphNode, DependencyGraphResponse,
+        DependencyRelation, DependencyResponse, LinkedTaskResponse, TaskDependenciesResponse,
+        TaskDependency,
+    },
+    services::dependencies::creates_cycle,
+    utils::AppError,
+};
+
+/// Return the subset of `task_ids` that have at least one unfinished blocking task
+pub(crate) async fn blocked_task_ids(
+    db: &sqlx::PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashSet<Uuid>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT DISTINCT d.blocked_task_id
+        FROM task_dependencies d
+        INNER JOIN tasks b ON d.blocking_task_id = b.id
+        WHERE d.blocked_task_id = ANY($1) AND b.status <> 'done' AND b.deleted_at IS NULL
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check blocked tasks".to_string())
+    })?;
+
+    Ok(rows.into_iter().map(|r| r.blocked_task_id).collect())
+}
+
+pub async fn create_dependency(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<CreateDependencyRequest>,
+) -> Result<(StatusCode, Json<DependencyResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access to the task and resolve its organization
+    let task = sqlx::query!(
+        r#"
+        SELECT p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::Authorization("You don't have access to this task".to_string()))?;
+
+    // The linked task may live in another project, but must share the organization
+    let other = sqlx::query!(
+        r#"
+        SELECT p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        WHERE t.id = $1 AND t.deleted_at IS NULL
+        "#,
+        payload.task_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch linked task".to_string())
+    })?;
+
+    match other {
+        Some(o) if o.organization_id == task.organization_id => {}
+        _ => return Err(AppError::NotFound("Linked task not found".to_string())),
+    }
+
+    let (blocking_task_id, blocked_task_id) = match payload.relation {
+        DependencyRelation::Blocks => (task_id, payload.task_id),
+        DependencyRelation::BlockedBy => (payload.task_id, task_id),
+    };
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    // Serialize dependency changes per organization so concurrent inserts
+    // cannot each pass the cycle check and together form a cycle
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1::text, 0))")
+        .bind(task.organization_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to lock dependency graph".to_string())
+        })?;
+
+    let edges = sqlx::query!(
+        r#"
+        SELECT d.blocking_task_id, d.blocked_task_id
+        FROM task_dependencies d
+        INNER JOIN tasks t ON d.blocking_task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        WHERE p.organization_id = $1
+        "#,
+        task.organization_id
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch dependencies".to_string())
+    })?;
+
+    let edges: Vec<(Uuid, Uuid)> = edges
+        .into_iter()
+        .map(|e| (e.blocking_task_id, e.blocked_task_id))
+        .collect();
+
+    if edges.contains(&(blocking_task_id, blocked_task_id)) {
+        return Err(AppError::Conflict("This dependency already exists".to_string()));
+    }
+
+    if creates_cycle(&edges, blocking_task_id, blocked_task_id) {
+        return Err(AppError::Validation(
+            "This dependency would create a cycle".to_string(),
+        ));
+    }
+
+    let dependency = sqlx::query_as!(
+        TaskDependency,
+        r#"
+        INSERT INTO task_dependencies (blocking_task_id, blocked_task_id, created_by)
+        VALUES ($1, $2, $3)
+        RETURNING id, blocking_task_id, blocked_task_id, created_by, created_at
+        "#,
+        blocking_task_id,
+        blocked_task_id,
+        user_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create dependency".to_string())
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit dependency".to_string())
+    })?;
+
+    tracing::info!("Dependency created: {} blocks {}", blocking_task_id, blocked_task_id);
+
+    Ok((StatusCode::CREATED, Json(dependency.into())))
+}
+
+pub async fn get_task_dependencies(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<TaskDependenciesResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    let blocked_by = sqlx::query_as!(
+        LinkedTaskResponse,
+        r#"
+        SELECT d.id AS dependency_id, t.id AS task_id, t.project_id, t.title, t.status
+        FROM task_dependencies d
+        INNER JOIN tasks t ON d.blocking_task_id = t.id
+        WHERE d.blocked_task_id = $1 AND t.deleted_at IS NULL
+        ORDER BY d.created_at ASC
+        "#,
+        task_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch dependencies".to_string())
+    })?;
+
+    let blocks = sqlx::query_as!(
+        LinkedTaskResponse,
+        r#"
+        SELECT d.id AS dependency_id, t.id AS task_id, t.project_id, t.title, t.status
+        FROM task_dependencies d
+        INNER JOIN tasks t ON d.blocked_task_id = t.id
+        WHERE d.blocking_task_id = $1 AND t.deleted_at IS NULL
+        ORDER BY d.created_at ASC
+        "#,
+        task_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch dependencies".to_string())
+    })?;
+
+    Ok(Json(TaskDependenciesResponse { blocked_by, blocks }))
+}
+
+pub async fn delete_dependency(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, dependency_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM task_dependencies
+        WHERE id = $1 AND (blocking_task_id = $2 OR blocked_task_id = $2)
+        "#,
+        dependency_id,
+        task_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to delete dependency".to_string())
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::NotFound("Dependency not found".to_string()));
+    }
+
+    tracing::info!("Dependency deleted: {}", dependency_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_project_dependency_graph(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<DependencyGraphResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check if user has access to the project
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE p.id = $1 AND om.user_id = $2
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization(
+            "You don't have access to this project".to_string(),
+        ));
+    }
+
+    // Every edge touching the project, including links to tasks in other projects
+    let edges = sqlx::query_as!(
+        TaskDependency,
+        r#"
+        SELECT d.id, d.blocking_task_id, d.blocked_task_id, d.created_by, d.created_at
+        FROM task_dependencies d
+        INNER JOIN tasks a ON d.blocking_task_id = a.id
+        INNER JOIN tasks b ON d.blocked_task_id = b.id
+        WHERE (a.project_id = $1 OR b.project_id = $1)
+          AND a.deleted_at IS NULL AND b.deleted_at IS NULL
+        ORDER BY d.created_at ASC
+        "#,
+        project_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch dependencies".to_string())
+    })?;
+
+    let linked_ids: Vec<Uuid> = edges
+        .iter()
+        .flat_map(|e| [e.blocking_task_id, e.blocked_task_id])
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    let tasks = sqlx::query!(
+        r#"
+        SELECT id, project_id, title, status
+        FROM tasks
+        WHERE deleted_at IS NULL AND (project_id = $1 OR id = ANY($2))
+        ORDER BY rank ASC, created_at ASC
+        "#,
+        project_id,
+        &linked_ids
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch tasks".to_string())
+    })?;
+
+    let task_ids: Vec<Uuid> = tasks.iter().map(|t| t.id).collect();
+    let blocked = blocked_task_ids(&state.db, &task_ids).await?;
+
+    let nodes = tasks
+        .into_iter()
+        .map(|t| DependencyGraphNode {
+            is_blocked: blocked.contains(&t.id),
+            id: t.id,
+            project_id: t.project_id,
+            title: t.title,
+            status: t.status,
+        })
+        .collect();
+
+    Ok(Json(DependencyGraphResponse {
+        nodes,
+        edges: edges.into_iter().map(|e| e.into()).collect(),
+    }))
+}
@@ -0,0 +1,151 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+    Extension,
+};
+use sqlx::{FromRow, PgConnection, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{AuthUser, PaginatedResponse, Task, TaskHistoryEntry, TaskHistoryQuery},
+    services::{
+        pagination::{page_size, Cursor, SortDirection, SortKey},
+        task_history::{diff_tasks, FieldChange},
+    },
+    utils::AppError,
+};
+
+/// Record every tracked field that differs between `old` and `new`
+pub(crate) async fn record_task_changes(
+    conn: &mut PgConnection,
+    old: &Task,
+    new: &Task,
+    actor_id: Uuid,
+) -> Result<(), AppError> {
+    record_changes(conn, new.id, actor_id, diff_tasks(old, new)).await
+}
+
+pub(crate) async fn record_changes(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    actor_id: Uuid,
+    changes: Vec<FieldChange>,
+) -> Result<(), AppError> {
+    if changes.is_empty() {
+        return Ok(());
+    }
+
+    let fields: Vec<String> = changes.iter().map(|c| c.field.to_string()).collect();
+    let old_values: Vec<Option<serde_json::Value>> =
+        changes.iter().map(|c| c.old_value.clone()).collect();
+    let new_values: Vec<Option<serde_json::Value>> =
+        changes.into_iter().map(|c| c.new_value).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_history (task_id, actor_id, field, old_value, new_value)
+        SELECT $1, $2, field, old_value, new_value
+        FROM UNNEST($3::varchar[], $4::jsonb[], $5::jsonb[]) AS c(field, old_value, new_value)
+        "#,
+        task_id,
+        actor_id,
+        &fields,
+        &old_values as &[Option<serde_json::Value>],
+        &new_values as &[Option<serde_json::Value>]
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to record task history".to_string())
+    })?;
+
+    Ok(())
+}
+
+pub async fn get_task_history(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Query(query): Query<TaskHistoryQuery>,
+) -> Result<Json<PaginatedResponse<TaskHistoryEntry>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    let direction = SortDirection::parse(query.order.as_deref())?;
+    let limit = page_size(query.limit)?;
+    let sort_key = SortKey::new("created_at", "h.created_at", "timestamptz");
+    let cursor = Cursor::decode_for(query.cursor.as_deref(), &sort_key)?;
+
+    let mut builder = QueryBuilder::<Postgres>::new(
+        "SELECT h.id, h.task_id, h.actor_id, h.field, h.old_value, h.new_value, h.created_at",
+    );
+    sort_key.push_select(&mut builder);
+    builder
+        .push(" FROM task_history h WHERE h.task_id = ")
+        .push_bind(task_id);
+
+    if let Some(field) = query.field {
+        builder.push(" AND h.field = ").push_bind(field);
+    }
+    if let Some(ref cursor) = cursor {
+        sort_key.push_after(&mut builder, "h.id", direction, cursor);
+    }
+    sort_key.push_order_by(&mut builder, "h.id", direction);
+    builder.push(" LIMIT ").push_bind(limit + 1);
+
+    let mut rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch task history".to_string())
+        })?;
+
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(last) if has_more => Some(
+            Cursor {
+                sort: sort_key.name.clone(),
+                value: last.try_get("sort_key")?,
+                id: last.try_get("id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    let items = rows
+        .iter()
+        .map(TaskHistoryEntry::from_row)
+        .collect::<Result<Vec<TaskHistoryEntry>, _>>()?;
+
+    Ok(Json(PaginatedResponse { items, next_cursor }))
+}
@@ -0,0 +1,530 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{AuthUser, CreateLabelRequest, Label, LabelResponse, UpdateLabelRequest},
+    utils::AppError,
+};
+
+const DEFAULT_LABEL_COLOR: &str = "#6B7280";
+
+/// Validate a `#RRGGBB` color string
+fn validate_color(color: &str) -> Result<(), AppError> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+
+    if !valid {
+        return Err(AppError::Validation(
+            "Color must be a hex value like #3B82F6".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Validate a label name, already trimmed
+fn validate_name(name: &str) -> Result<(), AppError> {
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(AppError::Validation(
+            "Label name must be between 1 and 100 characters".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Load the labels attached to each of `task_ids`
+pub(crate) async fn labels_for_tasks(
+    db: &sqlx::PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<LabelResponse>>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT tl.task_id, l.id, l.organization_id, l.project_id, l.name, l.color
+        FROM task_labels tl
+        INNER JOIN labels l ON tl.label_id = l.id
+        WHERE tl.task_id = ANY($1)
+        ORDER BY l.name ASC
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch task labels".to_string())
+    })?;
+
+    let mut labels: HashMap<Uuid, Vec<LabelResponse>> = HashMap::new();
+    for row in rows {
+        labels.entry(row.task_id).or_default().push(LabelResponse {
+            id: row.id,
+            organization_id: row.organization_id,
+            project_id: row.project_id,
+            name: row.name,
+            color: row.color,
+        });
+    }
+
+    Ok(labels)
+}
+
+/// Check that no other label in the same scope already uses `name`
+async fn ensure_unique_name(
+    db: &sqlx::PgPool,
+    org_id: Uuid,
+    project_id: Option<Uuid>,
+    name: &str,
+    exclude_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let existing = sqlx::query!(
+        r#"
+        SELECT id
+        FROM labels
+        WHERE organization_id = $1
+          AND project_id IS NOT DISTINCT FROM $2
+          AND LOWER(name) = LOWER($3)
+          AND ($4::uuid IS NULL OR id <> $4)
+        "#,
+        org_id,
+        project_id,
+        name,
+        exclude_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check label name".to_string())
+    })?;
+
+    if existing.is_some() {
+        return Err(AppError::Conflict(
+            "A label with this name already exists".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub async fn create_organization_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(org_id): Path<Uuid>,
+    Json(payload): Json<CreateLabelRequest>,
+) -> Result<(StatusCode, Json<LabelResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Organization-wide labels are managed by owners and admins
+    let membership = sqlx::query!(
+        "SELECT role FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        org_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+
+    match membership {
+        Some(m) if m.role == "owner" || m.role == "admin" => {}
+        Some(_) => {
+            return Err(AppError::Authorization(
+                "Only organization owners and admins can manage organization labels".to_string(),
+            ))
+        }
+        None => {
+            return Err(AppError::Authorization(
+                "You are not a member of this organization".to_string(),
+            ))
+        }
+    }
+
+    let color = payload.color.unwrap_or_else(|| DEFAULT_LABEL_COLOR.to_string());
+    let name = payload.name.trim();
+    validate_name(name)?;
+    validate_color(&color)?;
+    ensure_unique_name(&state.db, org_id, None, name, None).await?;
+
+    let label = sqlx::query_as!(
+        Label,
+        r#"
+        INSERT INTO labels (organization_id, name, color, created_by)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, organization_id, project_id, name, color, created_by, created_at, updated_at
+        "#,
+        org_id,
+        name,
+        color,
+        user_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create label".to_string())
+    })?;
+
+    tracing::info!("Label created: {} in org {}", label.name, org_id);
+
+    Ok((StatusCode::CREATED, Json(label.into())))
+}
+
+pub async fn get_organization_labels(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(org_id): Path<Uuid>,
+) -> Result<Json<Vec<LabelResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check if user is a member of the organization
+    let membership = sqlx::query!(
+        "SELECT id FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        org_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+
+    if membership.is_none() {
+        return Err(AppError::Authorization(
+            "You are not a member of this organization".to_string(),
+        ));
+    }
+
+    let labels = sqlx::query_as!(
+        Label,
+        r#"
+        SELECT id, organization_id, project_id, name, color, created_by, created_at, updated_at
+        FROM labels
+        WHERE organization_id = $1 AND project_id IS NULL
+        ORDER BY name ASC
+        "#,
+        org_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch labels".to_string())
+    })?;
+
+    Ok(Json(labels.into_iter().map(|l| l.into()).collect()))
+}
+
+pub async fn create_project_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(payload): Json<CreateLabelRequest>,
+) -> Result<(StatusCode, Json<LabelResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check if user has access to the project's organization
+    let project = sqlx::query!(
+        r#"
+        SELECT p.organization_id
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE p.id = $1 AND om.user_id = $2
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?
+    .ok_or_else(|| AppError::Authorization("You don't have access to this project".to_string()))?;
+
+    let color = payload.color.unwrap_or_else(|| DEFAULT_LABEL_COLOR.to_string());
+    let name = payload.name.trim();
+    validate_name(name)?;
+    validate_color(&color)?;
+    ensure_unique_name(&state.db, project.organization_id, Some(project_id), name, None)
+        .await?;
+
+    let label = sqlx::query_as!(
+        Label,
+        r#"
+        INSERT INTO labels (organization_id, project_id, name, color, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, organization_id, project_id, name, color, created_by, created_at, updated_at
+        "#,
+        project.organization_id,
+        project_id,
+        name,
+        color,
+        user_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create label".to_string())
+    })?;
+
+    tracing::info!("Label created: {} in project {}", label.name, project_id);
+
+    Ok((StatusCode::CREATED, Json(label.into())))
+}
+
+/// List labels usable in a project: organization-wide labels plus the project's own
+pub async fn get_project_labels(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<LabelResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let labels = sqlx::query_as!(
+        Label,
+        r#"
+        SELECT l.id, l.organization_id, l.project_id, l.name, l.color, l.created_by, l.created_at, l.updated_at
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        INNER JOIN labels l ON l.organization_id = p.organization_id
+        WHERE p.id = $1 AND om.user_id = $2
+          AND (l.project_id IS NULL OR l.project_id = p.id)
+        ORDER BY l.name ASC
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch labels".to_string())
+    })?;
+
+    Ok(Json(labels.into_iter().map(|l| l.into()).collect()))
+}
+
+/// Resolve a label the user may manage, enforcing owner/admin for organization labels
+async fn fetch_manageable_label(
+    db: &sqlx::PgPool,
+    label_id: Uuid,
+    user_id: Uuid,
+) -> Result<Label, AppError> {
+    let record = sqlx::query!(
+        r#"
+        SELECT l.id, l.organization_id, l.project_id, l.name, l.color, l.created_by, l.created_at, l.updated_at, om.role
+        FROM labels l
+        INNER JOIN organization_members om ON l.organization_id = om.organization_id
+        WHERE l.id = $1 AND om.user_id = $2
+        "#,
+        label_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch label".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Label not found".to_string()))?;
+
+    if record.project_id.is_none() && record.role != "owner" && record.role != "admin" {
+        return Err(AppError::Authorization(
+            "Only organization owners and admins can manage organization labels".to_string(),
+        ));
+    }
+
+    Ok(Label {
+        id: record.id,
+        organization_id: record.organization_id,
+        project_id: record.project_id,
+        name: record.name,
+        color: record.color,
+        created_by: record.created_by,
+        created_at: record.created_at,
+        updated_at: record.updated_at,
+    })
+}
+
+pub async fn update_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(label_id): Path<Uuid>,
+    Json(payload): Json<UpdateLabelRequest>,
+) -> Result<Json<LabelResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let label = fetch_manageable_label(&state.db, label_id, user_id).await?;
+
+    let name = payload.name.as_deref().map(str::trim);
+    if let Some(name) = name {
+        validate_name(name)?;
+        ensure_unique_name(&state.db, label.organization_id, label.project_id, name, Some(label.id))
+            .await?;
+    }
+    if let Some(ref color) = payload.color {
+        validate_color(color)?;
+    }
+
+    let label = sqlx::query_as!(
+        Label,
+        r#"
+        UPDATE labels
+        SET
+            name = COALESCE($2, name),
+            color = COALESCE($3, color),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, organization_id, project_id, name, color, created_by, created_at, updated_at
+        "#,
+        label_id,
+        name,
+        payload.color
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update label".to_string())
+    })?;
+
+    tracing::info!("Label updated: {}", label.id);
+
+    Ok(Json(label.into()))
+}
+
+pub async fn delete_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(label_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    fetch_manageable_label(&state.db, label_id, user_id).await?;
+
+    sqlx::query!("DELETE FROM labels WHERE id = $1", label_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete label".to_string())
+        })?;
+
+    tracing::info!("Label deleted: {}", label_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn add_task_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, label_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // The label must be organization-wide or belong to the task's own project
+    let allowed = sqlx::query!(
+        r#"
+        SELECT l.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        INNER JOIN labels l ON l.organization_id = p.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL AND l.id = $3
+          AND (l.project_id IS NULL OR l.project_id = p.id)
+        "#,
+        task_id,
+        user_id,
+        label_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if allowed.is_none() {
+        return Err(AppError::NotFound("Task or label not found".to_string()));
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_labels (task_id, label_id)
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        task_id,
+        label_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to add label".to_string())
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn remove_task_label(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path((task_id, label_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+
+    sqlx::query!(
+        "DELETE FROM task_labels WHERE task_id = $1 AND label_id = $2",
+        task_id,
+        label_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to remove label".to_string())
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,18 +1,66 @@
 // API routes module
+pub mod assignees;
+pub mod attachments;
 pub mod auth;
+pub mod bulk;
+pub mod checklists;
+pub mod custom_fields;
+pub mod dependencies;
+pub mod history;
+pub mod labels;
 pub mod organizations;
 pub mod projects;
+pub mod recurrence;
+pub mod search;
 pub mod tasks;
+pub mod time_tracking;
+pub mod transfer;
+pub mod trash;
+pub mod views;
+pub mod watchers;
 
+pub use attachments::{
+    delete_attachment, download_attachment, get_attachment_link, get_attachment_thumbnail,
+    get_task_attachments, upload_attachments,
+};
 pub use auth::{login, me, register};
+pub use bulk::bulk_update_tasks;
+pub use checklists::{
+    create_checklist_item, delete_checklist_item, get_checklist, move_checklist_item,
+    update_checklist_item,
+};
+pub use custom_fields::{
+    create_custom_field, delete_custom_field, get_project_custom_fields,
+    set_task_custom_field_value, update_custom_field,
+};
+pub use dependencies::{
+    create_dependency, delete_dependency, get_project_dependency_graph, get_task_dependencies,
+};
 pub use organizations::{
     create_organization, get_my_organizations, get_organization, get_organization_members,
 };
+pub use history::get_task_history;
+pub use labels::{
+    add_task_label, create_organization_label, create_project_label, delete_label,
+    get_organization_labels, get_project_labels, remove_task_label, update_label,
+};
 pub use projects::{
     create_project, delete_project, get_organization_projects, get_project, update_project,
 };
+pub use recurrence::{delete_task_recurrence, get_task_recurrence, set_task_recurrence};
+pub use search::search_organization;
 pub use tasks::{
-    create_comment, create_task, delete_task, get_project_tasks, get_task, get_task_comments,
-    update_task,
+    create_comment, create_task, delete_comment, delete_task, get_my_tasks, get_project_tasks,
+    get_task, get_task_by_key, get_task_comments, move_task, update_task,
 };
-
+pub use time_tracking::{
+    create_time_log, delete_time_log, get_project_time_summary, get_running_timer,
+    get_task_time_logs, get_timesheet, start_timer, stop_timer,
+};
+pub use transfer::transfer_task;
+pub use trash::{get_project_trash, restore_comment, restore_task};
+pub use views::{
+    create_saved_view, delete_saved_view, get_project_saved_views, get_saved_view,
+    update_saved_view,
+};
+pub use watchers::{get_task_watchers, unwatch_task, watch_task};
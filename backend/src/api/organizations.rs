@@ -8,7 +8,9 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
+    api::attachments::storage_used,
     models::{AuthUser, MemberRole, Organization, OrganizationMember},
+    services::blobs::effective_quota,
     utils::AppError,
     AppState,
 };
@@ -30,6 +32,15 @@ pub struct OrganizationResponse {
     pub website: Option<String>,
     pub is_active: bool,
     pub role: Option<String>, // User's role in this org
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<StorageUsageResponse>,
+}
+
+/// Attachment storage of an organization; `quota_bytes` is absent when unlimited
+#[derive(Debug, Serialize)]
+pub struct StorageUsageResponse {
+    pub used_bytes: i64,
+    pub quota_bytes: Option<i64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -111,6 +122,7 @@ pub async fn create_organization(
         website: org.website,
         is_active: org.is_active,
         role: Some(MemberRole::Owner.as_str().to_string()),
+        storage: None,
     };
 
     Ok((StatusCode::CREATED, Json(response)))
@@ -161,6 +173,7 @@ pub async fn get_my_organizations(
             website: org.website,
             is_active: org.is_active,
             role: Some(org.role),
+            storage: None,
         })
         .collect();
 
@@ -199,6 +212,17 @@ pub async fn get_organization(
         .map_err(|e| AppError::DatabaseError(e.to_string()))?
         .ok_or_else(|| AppError::NotFound("Organization not found".to_string()))?;
 
+    let quota_override: Option<i64> =
+        sqlx::query_scalar("SELECT storage_quota_bytes FROM organizations WHERE id = $1")
+            .bind(org_id)
+            .fetch_one(&app_state.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+    let storage = StorageUsageResponse {
+        used_bytes: storage_used(&app_state.db, org_id).await?,
+        quota_bytes: effective_quota(quota_override, app_state.config.storage.org_quota),
+    };
+
     let response = OrganizationResponse {
         id: org.id,
         name: org.name,
@@ -208,6 +232,7 @@ pub async fn get_organization(
         website: org.website,
         is_active: org.is_active,
         role: Some(member.unwrap().role),
+        storage: Some(storage),
     };
 
     Ok(Json(response))
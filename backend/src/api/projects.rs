@@ -1,15 +1,20 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Json},
     Extension,
 };
+use sqlx::{FromRow, Postgres, QueryBuilder, Row};
 use uuid::Uuid;
 
 use crate::{
     AppState,
-    models::{AuthUser, CreateProjectRequest, Project, ProjectResponse, UpdateProjectRequest},
-    utils::AppError,
+    models::{AuthUser, CreateProjectRequest, PaginatedResponse, Project, ProjectListQuery, ProjectResponse, UpdateProjectRequest},
+    services::{
+        pagination::{page_size, Cursor, SortDirection, SortKey},
+        task_keys::{derive_project_key, first_free_key, validate_project_key},
+    },
+    utils::{escape_like, etag, if_match_versions, split_list, AppError},
 };
 
 pub async fn create_project(
@@ -59,22 +64,65 @@ pub async fn create_project(
         ));
     }
 
+    // An explicit key must be free; a derived one is numbered until it is
+    let key = match payload.key {
+        Some(key) => {
+            validate_project_key(&key)?;
+            let existing = sqlx::query!(
+                "SELECT id FROM projects WHERE organization_id = $1 AND key = $2",
+                org_id,
+                key
+            )
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to check project key".to_string())
+            })?;
+
+            if existing.is_some() {
+                return Err(AppError::Conflict(
+                    "A project with this key already exists in this organization".to_string(),
+                ));
+            }
+            key
+        }
+        None => {
+            let base = derive_project_key(&payload.name);
+            let taken = sqlx::query_scalar!(
+                "SELECT key FROM projects WHERE organization_id = $1 AND key LIKE $2 || '%'",
+                org_id,
+                base
+            )
+            .fetch_all(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to check project key".to_string())
+            })?;
+            first_free_key(&base, &taken)
+        }
+    };
+
     let status = payload.status.unwrap_or_else(|| "planning".to_string());
     let color = payload.color.unwrap_or_else(|| "#3B82F6".to_string());
+    let enforce_dependencies = payload.enforce_dependencies.unwrap_or(false);
 
     let project = sqlx::query_as!(
         Project,
         r#"
-        INSERT INTO projects (organization_id, name, slug, description, status, color, created_by)
-        VALUES ($1, $2, $3, $4, $5, $6, $7)
-        RETURNING id, organization_id, name, slug, description, status, color, created_by, created_at, updated_at
+        INSERT INTO projects (organization_id, name, slug, key, description, status, color, enforce_dependencies, created_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING id, organization_id, name, slug, key, description, status, color, enforce_dependencies, version, created_by, created_at, updated_at
         "#,
         org_id,
         payload.name,
         payload.slug,
+        key,
         payload.description,
         status,
         color,
+        enforce_dependencies,
         user_id
     )
     .fetch_one(&state.db)
@@ -93,7 +141,8 @@ pub async fn get_organization_projects(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(org_id): Path<Uuid>,
-) -> Result<Json<Vec<ProjectResponse>>, AppError> {
+    Query(query): Query<ProjectListQuery>,
+) -> Result<Json<PaginatedResponse<ProjectResponse>>, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
@@ -116,40 +165,87 @@ pub async fn get_organization_projects(
         ));
     }
 
-    let projects = sqlx::query_as!(
-        Project,
-        r#"
-        SELECT id, organization_id, name, slug, description, status, color, created_by, created_at, updated_at
-        FROM projects
-        WHERE organization_id = $1
-        ORDER BY created_at DESC
-        "#,
-        org_id
-    )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        AppError::DatabaseError("Failed to fetch projects".to_string())
-    })?;
+    let direction = SortDirection::parse(query.order.as_deref().or(Some("desc")))?;
+    let limit = page_size(query.limit)?;
+    let sort_key = match query.sort.as_deref().unwrap_or("created_at") {
+        "created_at" => SortKey::new("created_at", "p.created_at", "timestamptz"),
+        "updated_at" => SortKey::new("updated_at", "p.updated_at", "timestamptz"),
+        "name" => SortKey::new("name", "LOWER(p.name)", "text"),
+        other => return Err(AppError::Validation(format!("Invalid sort: {}", other))),
+    };
+    let cursor = Cursor::decode_for(query.cursor.as_deref(), &sort_key)?;
 
-    let responses: Vec<ProjectResponse> = projects.into_iter().map(|p| p.into()).collect();
+    let mut builder = QueryBuilder::<Postgres>::new(
+        "SELECT p.id, p.organization_id, p.name, p.slug, p.key, p.description, p.status, p.color, p.enforce_dependencies, p.version, p.created_by, p.created_at, p.updated_at",
+    );
+    sort_key.push_select(&mut builder);
+    builder
+        .push(" FROM projects p WHERE p.organization_id = ")
+        .push_bind(org_id);
 
-    Ok(Json(responses))
+    if let Some(statuses) = split_list(query.status.as_deref()) {
+        builder.push(" AND p.status = ANY(").push_bind(statuses).push(")");
+    }
+    if let Some(q) = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        let pattern = format!("%{}%", escape_like(q));
+        builder
+            .push(" AND (p.name ILIKE ")
+            .push_bind(pattern.clone())
+            .push(" OR p.description ILIKE ")
+            .push_bind(pattern)
+            .push(")");
+    }
+    if let Some(ref cursor) = cursor {
+        sort_key.push_after(&mut builder, "p.id", direction, cursor);
+    }
+    sort_key.push_order_by(&mut builder, "p.id", direction);
+    builder.push(" LIMIT ").push_bind(limit + 1);
+
+    let mut rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch projects".to_string())
+        })?;
+
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(last) if has_more => Some(
+            Cursor {
+                sort: sort_key.name.clone(),
+                value: last.try_get("sort_key")?,
+                id: last.try_get("id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    let items = rows
+        .iter()
+        .map(Project::from_row)
+        .map(|p| p.map(ProjectResponse::from))
+        .collect::<Result<Vec<ProjectResponse>, _>>()?;
+
+    Ok(Json(PaginatedResponse { items, next_cursor }))
 }
 
 pub async fn get_project(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(project_id): Path<Uuid>,
-) -> Result<Json<ProjectResponse>, AppError> {
+) -> Result<impl IntoResponse, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
     let project = sqlx::query_as!(
         Project,
         r#"
-        SELECT p.id, p.organization_id, p.name, p.slug, p.description, p.status, p.color, p.created_by, p.created_at, p.updated_at
+        SELECT p.id, p.organization_id, p.name, p.slug, p.key, p.description, p.status, p.color, p.enforce_dependencies, p.version, p.created_by, p.created_at, p.updated_at
         FROM projects p
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
         WHERE p.id = $1 AND om.user_id = $2
@@ -165,7 +261,7 @@ pub async fn get_project(
     })?;
 
     match project {
-        Some(p) => Ok(Json(p.into())),
+        Some(p) => Ok(([(header::ETAG, etag(p.version))], Json(ProjectResponse::from(p)))),
         None => Err(AppError::NotFound("Project not found".to_string())),
     }
 }
@@ -174,10 +270,12 @@ pub async fn update_project(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(project_id): Path<Uuid>,
+    headers: HeaderMap,
     Json(payload): Json<UpdateProjectRequest>,
-) -> Result<Json<ProjectResponse>, AppError> {
+) -> Result<impl IntoResponse, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+    let expected_versions = if_match_versions(&headers)?;
 
     // Check if user has access to the project's organization
     let access = sqlx::query!(
@@ -201,45 +299,59 @@ pub async fn update_project(
         return Err(AppError::Authorization("You don't have access to this project".to_string()));
     }
 
-    // Build dynamic update query
+    let name = payload.name.required("name")?;
+    let status = payload.status.required("status")?;
+    let enforce_dependencies = payload.enforce_dependencies.required("enforce_dependencies")?;
+
     let project = sqlx::query_as!(
         Project,
         r#"
         UPDATE projects
         SET 
             name = COALESCE($2, name),
-            description = COALESCE($3, description),
-            status = COALESCE($4, status),
-            color = COALESCE($5, color),
+            description = CASE WHEN $3 THEN $4 ELSE description END,
+            status = COALESCE($5, status),
+            color = CASE WHEN $6 THEN $7 ELSE color END,
+            enforce_dependencies = COALESCE($8, enforce_dependencies),
+            version = version + 1,
             updated_at = NOW()
-        WHERE id = $1
-        RETURNING id, organization_id, name, slug, description, status, color, created_by, created_at, updated_at
+        WHERE id = $1 AND ($9::int4[] IS NULL OR version = ANY($9))
+        RETURNING id, organization_id, name, slug, key, description, status, color, enforce_dependencies, version, created_by, created_at, updated_at
         "#,
         project_id,
-        payload.name,
-        payload.description,
-        payload.status,
-        payload.color
+        name,
+        payload.description.is_set(),
+        payload.description.into_option(),
+        status,
+        payload.color.is_set(),
+        payload.color.into_option(),
+        enforce_dependencies,
+        expected_versions.as_deref()
     )
-    .fetch_one(&state.db)
+    .fetch_optional(&state.db)
     .await
     .map_err(|e| {
         tracing::error!("Database error: {}", e);
         AppError::DatabaseError("Failed to update project".to_string())
+    })?
+    .ok_or_else(|| {
+        AppError::PreconditionFailed("Project has been changed since it was loaded".to_string())
     })?;
 
     tracing::info!("Project updated: {}", project.id);
 
-    Ok(Json(project.into()))
+    Ok(([(header::ETAG, etag(project.version))], Json(ProjectResponse::from(project))))
 }
 
 pub async fn delete_project(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(project_id): Path<Uuid>,
+    headers: HeaderMap,
 ) -> Result<StatusCode, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+    let expected_versions = if_match_versions(&headers)?;
 
     // Check if user is owner or admin of the organization
     let access = sqlx::query!(
@@ -261,13 +373,23 @@ pub async fn delete_project(
 
     match access {
         Some(record) if record.role == "owner" || record.role == "admin" => {
-            sqlx::query!("DELETE FROM projects WHERE id = $1", project_id)
-                .execute(&state.db)
-                .await
-                .map_err(|e| {
-                    tracing::error!("Database error: {}", e);
-                    AppError::DatabaseError("Failed to delete project".to_string())
-                })?;
+            let deleted = sqlx::query!(
+                "DELETE FROM projects WHERE id = $1 AND ($2::int4[] IS NULL OR version = ANY($2))",
+                project_id,
+                expected_versions.as_deref()
+            )
+            .execute(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to delete project".to_string())
+            })?;
+
+            if deleted.rows_affected() == 0 {
+                return Err(AppError::PreconditionFailed(
+                    "Project has been changed since it was loaded".to_string(),
+                ));
+            }
 
             tracing::info!("Project deleted: {}", project_id);
             Ok(StatusCode::NO_CONTENT)
@@ -0,0 +1,409 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use chrono::Utc;
+use sqlx::{PgConnection, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    api::{checklists::copy_checklist, tasks::lock_task, transfer::take_project_slot},
+    config::RecurrenceConfig,
+    models::{AuthUser, RecurrenceResponse, SetRecurrenceRequest, Task, TaskRecurrence},
+    services::recurrence::{parse_timezone, RecurrenceRule},
+    utils::AppError,
+};
+
+/// Recurrence rules of the given tasks that head a series
+pub(crate) async fn recurrence_rules_for_tasks(
+    db: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, String>, AppError> {
+    let rows = sqlx::query!(
+        "SELECT task_id, rule FROM task_recurrences WHERE task_id = ANY($1)",
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch recurrences".to_string())
+    })?;
+
+    Ok(rows.into_iter().map(|r| (r.task_id, r.rule)).collect())
+}
+
+/// Create the next occurrence of a locked series from its newest task and
+/// move the series on to it
+async fn create_next_occurrence(
+    conn: &mut PgConnection,
+    recurrence: &TaskRecurrence,
+) -> Result<Task, AppError> {
+    let due_date = recurrence
+        .next_due_at
+        .ok_or_else(|| AppError::Internal("Recurrence has ended".to_string()))?;
+    let rule = RecurrenceRule::parse(&recurrence.rule)?;
+    let tz = parse_timezone(&recurrence.timezone)?;
+
+    let template = lock_task(&mut *conn, recurrence.task_id).await?;
+    let (rank, number) = take_project_slot(&mut *conn, template.project_id).await?;
+
+    let task = sqlx::query_as!(
+        Task,
+        r#"
+        INSERT INTO tasks (project_id, title, description, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, rank, number)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $8, $9, $10)
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        "#,
+        template.project_id,
+        template.title,
+        template.description,
+        template.priority,
+        template.assigned_to,
+        recurrence.created_by,
+        due_date,
+        template.original_estimate_seconds,
+        rank,
+        number
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create occurrence".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_labels (task_id, label_id)
+        SELECT $1, label_id FROM task_labels WHERE task_id = $2
+        "#,
+        task.id,
+        template.id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to copy labels".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_custom_field_values (task_id, field_id, value, updated_by)
+        SELECT $1, field_id, value, updated_by FROM task_custom_field_values WHERE task_id = $2
+        "#,
+        task.id,
+        template.id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to copy custom field values".to_string())
+    })?;
+
+    copy_checklist(&mut *conn, template.id, task.id).await?;
+
+    // Assignees and watchers carry over to the next occurrence
+    sqlx::query!(
+        r#"
+        INSERT INTO task_assignees (task_id, user_id, created_at)
+        SELECT $1, user_id, created_at FROM task_assignees WHERE task_id = $2
+        "#,
+        task.id,
+        template.id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to copy assignees".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO task_watchers (task_id, user_id, created_at)
+        SELECT $1, user_id, created_at FROM task_watchers WHERE task_id = $2
+        "#,
+        task.id,
+        template.id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to copy watchers".to_string())
+    })?;
+
+    let index = recurrence.occurrence_index + 1;
+    let next_due_at = rule.occurrence(recurrence.starts_at, tz, index as u32 + 1);
+
+    sqlx::query!(
+        r#"
+        UPDATE task_recurrences
+        SET task_id = $2, occurrence_index = $3, current_due_at = $4, next_due_at = $5, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        recurrence.id,
+        task.id,
+        index,
+        due_date,
+        next_due_at
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to advance recurrence".to_string())
+    })?;
+
+    tracing::info!("Created occurrence {} of recurrence {}", index, recurrence.id);
+    Ok(task)
+}
+
+/// Completing the newest occurrence of a series creates the next one right away
+pub(crate) async fn advance_on_completion(
+    conn: &mut PgConnection,
+    old: &Task,
+    new: &Task,
+) -> Result<(), AppError> {
+    if old.status == "done" || new.status != "done" {
+        return Ok(());
+    }
+
+    let recurrence = sqlx::query_as!(
+        TaskRecurrence,
+        r#"
+        SELECT id, task_id, rule, timezone, starts_at, occurrence_index, current_due_at, next_due_at, created_by, created_at, updated_at
+        FROM task_recurrences
+        WHERE task_id = $1 AND next_due_at IS NOT NULL
+        FOR UPDATE
+        "#,
+        new.id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch recurrence".to_string())
+    })?;
+
+    if let Some(recurrence) = recurrence {
+        create_next_occurrence(conn, &recurrence).await?;
+    }
+    Ok(())
+}
+
+/// Create the next occurrence of every series whose newest occurrence is due.
+/// Each series advances at most once per run, so a series that fell behind
+/// catches up gradually instead of flooding its project. Series being advanced
+/// elsewhere are skipped and picked up on the next run.
+pub async fn run_due_recurrences(db: &PgPool) -> Result<usize, AppError> {
+    let started = Utc::now();
+    let mut created = 0;
+    loop {
+        let mut tx = db.begin().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to start transaction".to_string())
+        })?;
+
+        // Series whose newest occurrence is in the trash are paused
+        let recurrence = sqlx::query_as!(
+            TaskRecurrence,
+            r#"
+            SELECT r.id, r.task_id, r.rule, r.timezone, r.starts_at, r.occurrence_index, r.current_due_at, r.next_due_at, r.created_by, r.created_at, r.updated_at
+            FROM task_recurrences r
+            INNER JOIN tasks t ON r.task_id = t.id
+            WHERE r.next_due_at IS NOT NULL AND r.current_due_at <= $1
+              AND r.updated_at < $1 AND t.deleted_at IS NULL
+            ORDER BY r.current_due_at ASC
+            LIMIT 1
+            FOR UPDATE OF r SKIP LOCKED
+            "#,
+            started
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch due recurrences".to_string())
+        })?;
+
+        let Some(recurrence) = recurrence else {
+            return Ok(created);
+        };
+
+        create_next_occurrence(&mut tx, &recurrence).await?;
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to commit occurrence".to_string())
+        })?;
+        created += 1;
+    }
+}
+
+/// Run `run_due_recurrences` in the background every `scheduler_interval` seconds
+pub fn spawn_scheduler(db: PgPool, config: RecurrenceConfig) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+            config.scheduler_interval.max(1),
+        ));
+        loop {
+            interval.tick().await;
+            match run_due_recurrences(&db).await {
+                Ok(0) => {}
+                Ok(created) => tracing::info!("Created {} recurring task occurrences", created),
+                Err(e) => tracing::error!("Recurrence scheduler failed: {}", e),
+            }
+        }
+    });
+}
+
+async fn check_task_access(db: &PgPool, task_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    match access {
+        Some(_) => Ok(()),
+        None => Err(AppError::Authorization("You don't have access to this task".to_string())),
+    }
+}
+
+pub async fn get_task_recurrence(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<RecurrenceResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    let recurrence = sqlx::query_as!(
+        TaskRecurrence,
+        r#"
+        SELECT id, task_id, rule, timezone, starts_at, occurrence_index, current_due_at, next_due_at, created_by, created_at, updated_at
+        FROM task_recurrences
+        WHERE task_id = $1
+        "#,
+        task_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch recurrence".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task does not recur".to_string()))?;
+
+    Ok(Json(recurrence.into()))
+}
+
+/// Make a task the first occurrence of a series, or restart its series with a
+/// new rule. The series starts at the task's due date.
+pub async fn set_task_recurrence(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<SetRecurrenceRequest>,
+) -> Result<Json<RecurrenceResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    let rule = RecurrenceRule::parse(&payload.rule)?;
+    let tz = parse_timezone(&payload.timezone)?;
+
+    let due_date = sqlx::query_scalar!("SELECT due_date FROM tasks WHERE id = $1", task_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch task".to_string())
+        })?
+        .ok_or_else(|| {
+            AppError::Validation("Set a due date before making the task recur".to_string())
+        })?;
+
+    let next_due_at = rule.occurrence(due_date, tz, 1);
+
+    let recurrence = sqlx::query_as!(
+        TaskRecurrence,
+        r#"
+        INSERT INTO task_recurrences (task_id, rule, timezone, starts_at, current_due_at, next_due_at, created_by)
+        VALUES ($1, $2, $3, $4, $4, $5, $6)
+        ON CONFLICT (task_id) DO UPDATE
+        SET rule = EXCLUDED.rule,
+            timezone = EXCLUDED.timezone,
+            starts_at = EXCLUDED.starts_at,
+            occurrence_index = 0,
+            current_due_at = EXCLUDED.current_due_at,
+            next_due_at = EXCLUDED.next_due_at,
+            updated_at = NOW()
+        RETURNING id, task_id, rule, timezone, starts_at, occurrence_index, current_due_at, next_due_at, created_by, created_at, updated_at
+        "#,
+        task_id,
+        rule.to_string(),
+        tz.name(),
+        due_date,
+        next_due_at,
+        user_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to save recurrence".to_string())
+    })?;
+
+    tracing::info!("Recurrence set on task {}: {}", task_id, recurrence.rule);
+
+    Ok(Json(recurrence.into()))
+}
+
+/// Stop a series; existing occurrences are kept
+pub async fn delete_task_recurrence(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    let deleted = sqlx::query!("DELETE FROM task_recurrences WHERE task_id = $1", task_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete recurrence".to_string())
+        })?;
+
+    if deleted.rows_affected() == 0 {
+        return Err(AppError::NotFound("Task does not recur".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
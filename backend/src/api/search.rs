@@ -0,0 +1,148 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+    Extension,
+};
+use sqlx::{FromRow, Postgres, QueryBuilder, Row};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{AuthUser, PaginatedResponse, SearchQuery, SearchResult},
+    services::{
+        pagination::{page_size, Cursor, SortDirection, SortKey},
+        search::{normalize_query, parse_kinds, SearchKind, HEADLINE_OPTIONS},
+    },
+    utils::AppError,
+};
+
+/// Append the matching rows of one kind to the `UNION ALL` of candidates
+fn push_candidates(builder: &mut QueryBuilder<'_, Postgres>, kind: SearchKind, org_id: Uuid) {
+    builder.push(format!("SELECT '{}' AS kind, ", kind.as_str()));
+    match kind {
+        SearchKind::Task => {
+            builder.push(
+                "t.id, t.title, coalesce(t.title || ' ' || t.description, t.title) AS body, \
+                 ts_rank(t.search_vector, s.query) AS rank, p.id AS project_id, p.name AS project_name, t.id AS task_id \
+                 FROM tasks t \
+                 INNER JOIN projects p ON t.project_id = p.id \
+                 CROSS JOIN search s \
+                 WHERE t.search_vector @@ s.query AND t.deleted_at IS NULL AND p.organization_id = ",
+            );
+        }
+        SearchKind::Comment => {
+            builder.push(
+                "c.id, t.title, c.content AS body, \
+                 ts_rank(c.search_vector, s.query) AS rank, p.id AS project_id, p.name AS project_name, t.id AS task_id \
+                 FROM task_comments c \
+                 INNER JOIN tasks t ON c.task_id = t.id \
+                 INNER JOIN projects p ON t.project_id = p.id \
+                 CROSS JOIN search s \
+                 WHERE c.search_vector @@ s.query AND c.deleted_at IS NULL AND t.deleted_at IS NULL \
+                   AND p.organization_id = ",
+            );
+        }
+        SearchKind::Project => {
+            builder.push(
+                "p.id, p.name AS title, coalesce(p.name || ' ' || p.description, p.name) AS body, \
+                 ts_rank(p.search_vector, s.query) AS rank, p.id AS project_id, p.name AS project_name, NULL::uuid AS task_id \
+                 FROM projects p \
+                 CROSS JOIN search s \
+                 WHERE p.search_vector @@ s.query AND p.organization_id = ",
+            );
+        }
+    }
+    builder.push_bind(org_id);
+}
+
+/// Search tasks, comments and projects of an organization, best matches first
+pub async fn search_organization(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(org_id): Path<Uuid>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Json<PaginatedResponse<SearchResult>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let membership = sqlx::query!(
+        "SELECT id FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+        org_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+
+    if membership.is_none() {
+        return Err(AppError::Authorization(
+            "You are not a member of this organization".to_string(),
+        ));
+    }
+
+    let text = normalize_query(&query.q)?;
+    let kinds = parse_kinds(query.types.as_deref())?;
+    let limit = page_size(query.limit)?;
+    let sort_key = SortKey::new("rank", "r.rank", "float4");
+    let cursor = Cursor::decode_for(query.cursor.as_deref(), &sort_key)?;
+
+    let mut builder = QueryBuilder::<Postgres>::new(
+        "WITH search AS (SELECT websearch_to_tsquery('english', ",
+    );
+    builder.push_bind(text).push(") AS query) ");
+
+    builder.push(format!(
+        "SELECT r.kind, r.id, r.title, ts_headline('english', r.body, s.query, '{}') AS snippet, \
+         r.rank, r.project_id, r.project_name, r.task_id",
+        HEADLINE_OPTIONS
+    ));
+    sort_key.push_select(&mut builder);
+    builder.push(" FROM (");
+    for (i, kind) in kinds.iter().enumerate() {
+        if i > 0 {
+            builder.push(" UNION ALL ");
+        }
+        push_candidates(&mut builder, *kind, org_id);
+    }
+    builder.push(") r CROSS JOIN search s WHERE true");
+
+    if let Some(ref cursor) = cursor {
+        sort_key.push_after(&mut builder, "r.id", SortDirection::Desc, cursor);
+    }
+    sort_key.push_order_by(&mut builder, "r.id", SortDirection::Desc);
+    builder.push(" LIMIT ").push_bind(limit + 1);
+
+    let mut rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to search".to_string())
+        })?;
+
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(last) if has_more => Some(
+            Cursor {
+                sort: sort_key.name.clone(),
+                value: last.try_get("sort_key")?,
+                id: last.try_get("id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    let items = rows
+        .iter()
+        .map(SearchResult::from_row)
+        .collect::<Result<Vec<SearchResult>, _>>()?;
+
+    Ok(Json(PaginatedResponse { items, next_cursor }))
+}
@@ -1,17 +1,409 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     response::{IntoResponse, Json},
     Extension,
 };
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use sqlx::{postgres::PgRow, FromRow, PgConnection, PgExecutor, Postgres, QueryBuilder, Row};
 use uuid::Uuid;
 
 use crate::{
     AppState,
-    models::{AuthUser, CreateTaskRequest, Task, TaskResponse, UpdateTaskRequest, CreateCommentRequest, TaskComment, CommentResponse},
-    utils::AppError,
+    api::{
+        assignees::{assignees_for_tasks, ensure_assignable, insert_assignees, set_task_assignees},
+        checklists::checklist_progress_for_tasks,
+        custom_fields::{custom_field_values_for_tasks, fetch_project_field, prepare_initial_values},
+        dependencies::blocked_task_ids,
+        history::record_task_changes,
+        labels::labels_for_tasks,
+        recurrence::{advance_on_completion, recurrence_rules_for_tasks},
+        time_tracking::time_spent_for_tasks,
+        watchers::{add_watchers, watchers_for_tasks},
+    },
+    models::{AuthUser, CreateTaskRequest, MoveTaskRequest, MyTaskResponse, MyTasksQuery, MyTasksResponse, PaginatedResponse, Task, TaskListQuery, TaskResponse, UpdateTaskRequest, CreateCommentRequest, TaskComment, CommentListQuery, CommentResponse, TaskKeyQuery},
+    services::{
+        assignees::requested_assignees,
+        custom_fields::CustomFieldType,
+        due_buckets::{due_bucket, utc_offset, DueBucket},
+        pagination::{page_size, Cursor, SortDirection, SortKey},
+        rank,
+        task_keys::{format_task_key, parse_task_key},
+        task_query::{self, CompileContext, PRIORITY_RANK_SQL},
+        time_tracking::validate_estimate,
+    },
+    utils::{escape_like, etag, if_match_versions, split_list, AppError, Patch},
 };
 
+/// Convert tasks into responses, filling in fields derived from related tables
+pub(crate) async fn build_task_responses(
+    db: &sqlx::PgPool,
+    tasks: Vec<Task>,
+) -> Result<Vec<TaskResponse>, AppError> {
+    let task_ids: Vec<Uuid> = tasks.iter().map(|t| t.id).collect();
+    let project_ids: Vec<Uuid> = tasks.iter().map(|t| t.project_id).collect();
+    let project_keys = project_keys(db, &project_ids).await?;
+    let blocked = blocked_task_ids(db, &task_ids).await?;
+    let mut labels = labels_for_tasks(db, &task_ids).await?;
+    let mut custom_fields = custom_field_values_for_tasks(db, &task_ids).await?;
+    let mut recurrences = recurrence_rules_for_tasks(db, &task_ids).await?;
+    let time_spent = time_spent_for_tasks(db, &task_ids).await?;
+    let checklists = checklist_progress_for_tasks(db, &task_ids).await?;
+    let mut assignees = assignees_for_tasks(db, &task_ids).await?;
+    let mut watchers = watchers_for_tasks(db, &task_ids).await?;
+
+    Ok(tasks
+        .into_iter()
+        .map(|task| {
+            let is_blocked = blocked.contains(&task.id);
+            let labels = labels.remove(&task.id).unwrap_or_default();
+            let custom_fields = custom_fields.remove(&task.id).unwrap_or_default();
+            let recurrence = recurrences.remove(&task.id);
+            let time_spent_seconds = time_spent.get(&task.id).copied().unwrap_or(0);
+            let checklist_progress = checklists.get(&task.id).copied();
+            let assignees = assignees.remove(&task.id).unwrap_or_default();
+            let watchers = watchers.remove(&task.id).unwrap_or_default();
+            let key = project_keys
+                .get(&task.project_id)
+                .map(|project_key| format_task_key(project_key, task.number))
+                .unwrap_or_default();
+            TaskResponse {
+                key,
+                is_blocked,
+                labels,
+                custom_fields,
+                recurrence,
+                time_spent_seconds,
+                checklist_progress,
+                assignees,
+                watchers,
+                ..task.into()
+            }
+        })
+        .collect())
+}
+
+pub(crate) async fn build_task_response(
+    db: &sqlx::PgPool,
+    task: Task,
+) -> Result<TaskResponse, AppError> {
+    let mut responses = build_task_responses(db, vec![task]).await?;
+    Ok(responses.remove(0))
+}
+
+async fn project_keys(
+    db: &sqlx::PgPool,
+    project_ids: &[Uuid],
+) -> Result<HashMap<Uuid, String>, AppError> {
+    let rows = sqlx::query!(
+        "SELECT id, key FROM projects WHERE id = ANY($1)",
+        project_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch project keys".to_string())
+    })?;
+
+    Ok(rows.into_iter().map(|r| (r.id, r.key)).collect())
+}
+
+/// Take the next task number of a project. The row lock taken by the update
+/// is held until commit, so concurrent allocations never hand out the same number.
+pub(crate) async fn allocate_task_number(
+    conn: &mut PgConnection,
+    project_id: Uuid,
+) -> Result<i32, AppError> {
+    sqlx::query_scalar!(
+        r#"
+        UPDATE projects
+        SET next_task_number = next_task_number + 1
+        WHERE id = $1
+        RETURNING next_task_number - 1 AS "number!"
+        "#,
+        project_id
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to allocate task number".to_string())
+    })
+}
+
+/// Columns selected into `Task` from the `tasks t` alias
+pub(crate) const TASK_COLUMNS: &str = "t.id, t.project_id, t.title, t.description, t.status, t.priority, t.assigned_to, t.created_by, t.due_date, t.original_estimate_seconds, t.remaining_estimate_seconds, t.completed_at, t.rank, t.number, t.version, t.created_at, t.updated_at";
+
+/// Resolve one of the built-in task sort keys
+pub(crate) fn task_sort_key(sort: Option<&str>) -> Result<SortKey, AppError> {
+    let (name, expression, sql_type) = match sort.unwrap_or("rank") {
+        // `position` is the name manual ordering had before rank keys
+        "rank" | "position" => ("rank", "t.rank", "text"),
+        "created_at" => ("created_at", "t.created_at", "timestamptz"),
+        "updated_at" => ("updated_at", "t.updated_at", "timestamptz"),
+        "due_date" => ("due_date", "t.due_date", "timestamptz"),
+        "title" => ("title", "LOWER(t.title)", "text"),
+        "priority" => ("priority", PRIORITY_RANK_SQL, "int4"),
+        other => return Err(AppError::Validation(format!("Invalid sort: {}", other))),
+    };
+    Ok(SortKey::new(name, expression, sql_type))
+}
+
+/// Serialize rank changes within a project for the rest of the transaction
+pub(crate) async fn lock_project_ranks(
+    conn: &mut PgConnection,
+    project_id: Uuid,
+) -> Result<(), AppError> {
+    sqlx::query!(
+        "SELECT id FROM projects WHERE id = $1 FOR NO KEY UPDATE",
+        project_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to lock project".to_string())
+    })?;
+    Ok(())
+}
+
+/// A rank after every task in the project; call with the project locked
+pub(crate) async fn last_rank(conn: &mut PgConnection, project_id: Uuid) -> Result<String, AppError> {
+    let max_rank = sqlx::query_scalar!("SELECT MAX(rank) FROM tasks WHERE project_id = $1", project_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to get last rank".to_string())
+        })?;
+
+    rank::between(max_rank.as_deref(), None)
+}
+
+/// Projects enforcing dependencies refuse to complete blocked tasks
+pub(crate) async fn ensure_can_complete<'e>(
+    executor: impl PgExecutor<'e>,
+    task_id: Uuid,
+    project_id: Uuid,
+) -> Result<(), AppError> {
+    let guard = sqlx::query!(
+        r#"
+        SELECT
+            p.enforce_dependencies,
+            EXISTS(
+                SELECT 1
+                FROM task_dependencies d
+                INNER JOIN tasks b ON d.blocking_task_id = b.id
+                WHERE d.blocked_task_id = $1 AND b.status <> 'done' AND b.deleted_at IS NULL
+            ) AS "is_blocked!"
+        FROM projects p
+        WHERE p.id = $2
+        "#,
+        task_id,
+        project_id
+    )
+    .fetch_one(executor)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task dependencies".to_string())
+    })?;
+
+    if guard.enforce_dependencies && guard.is_blocked {
+        return Err(AppError::Conflict(
+            "Task is blocked by unfinished tasks and cannot be completed".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Split a fetched page (queried with `limit + 1` rows) into tasks and the next cursor
+pub(crate) fn paginate_task_rows(
+    mut rows: Vec<PgRow>,
+    sort_key: &SortKey,
+    limit: i64,
+) -> Result<(Vec<Task>, Option<String>), AppError> {
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(last) if has_more => Some(
+            Cursor {
+                sort: sort_key.name.clone(),
+                value: last.try_get("sort_key")?,
+                id: last.try_get("id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
+
+    let tasks = rows
+        .iter()
+        .map(Task::from_row)
+        .collect::<Result<Vec<Task>, _>>()?;
+
+    Ok((tasks, next_cursor))
+}
+
+fn parse_user_filter(value: &str, user_id: Uuid, param: &str) -> Result<Uuid, AppError> {
+    if value == "me" {
+        return Ok(user_id);
+    }
+    Uuid::parse_str(value)
+        .map_err(|_| AppError::Validation(format!("Invalid {}: {}", param, value)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AssigneeFilter {
+    User(Uuid),
+    Unassigned,
+}
+
+/// Task filters parsed from listing query parameters
+#[derive(Debug, Default)]
+pub(crate) struct TaskFilters {
+    pub statuses: Option<Vec<String>>,
+    pub priorities: Option<Vec<String>>,
+    pub assignee: Option<AssigneeFilter>,
+    pub created_by: Option<Uuid>,
+    pub due_after: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    pub completed: Option<bool>,
+    pub text: Option<String>,
+    pub updated_since: Option<DateTime<Utc>>,
+    pub label_ids: Option<Vec<Uuid>>,
+    /// Parsed `filter` expression and the context it is compiled against
+    pub advanced: Option<(task_query::Expr, CompileContext)>,
+}
+
+impl TaskFilters {
+    pub(crate) fn from_query(query: &TaskListQuery, user_id: Uuid) -> Result<Self, AppError> {
+        let assignee = match query.assigned_to.as_deref() {
+            None => None,
+            Some("none") => Some(AssigneeFilter::Unassigned),
+            Some(value) => Some(AssigneeFilter::User(parse_user_filter(
+                value,
+                user_id,
+                "assigned_to",
+            )?)),
+        };
+
+        let created_by = query
+            .created_by
+            .as_deref()
+            .map(|value| parse_user_filter(value, user_id, "created_by"))
+            .transpose()?;
+
+        let label_ids = split_list(query.labels.as_deref())
+            .map(|ids| {
+                ids.iter()
+                    .map(|id| {
+                        Uuid::parse_str(id)
+                            .map_err(|_| AppError::Validation(format!("Invalid label ID: {}", id)))
+                    })
+                    .collect::<Result<Vec<Uuid>, AppError>>()
+            })
+            .transpose()?
+            .map(|mut ids| {
+                ids.sort();
+                ids.dedup();
+                ids
+            });
+
+        let advanced = match query.filter.as_deref().map(str::trim) {
+            Some(filter) if !filter.is_empty() => {
+                let context = CompileContext {
+                    user_id,
+                    now: Utc::now(),
+                    offset: utc_offset(query.tz_offset)?,
+                };
+                Some((task_query::parse(filter)?, context))
+            }
+            _ => None,
+        };
+
+        Ok(TaskFilters {
+            statuses: split_list(query.status.as_deref()),
+            priorities: split_list(query.priority.as_deref()),
+            assignee,
+            created_by,
+            due_after: query.due_after,
+            due_before: query.due_before,
+            completed: query.completed,
+            text: query.q.as_deref().map(str::trim).filter(|q| !q.is_empty()).map(String::from),
+            updated_since: query.updated_since,
+            label_ids,
+            advanced,
+        })
+    }
+
+    /// Append the filters as `AND` clauses on the `tasks t` alias
+    pub(crate) fn push(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        if let Some(ref statuses) = self.statuses {
+            builder.push(" AND t.status = ANY(").push_bind(statuses.clone()).push(")");
+        }
+        if let Some(ref priorities) = self.priorities {
+            builder.push(" AND t.priority = ANY(").push_bind(priorities.clone()).push(")");
+        }
+        match self.assignee {
+            Some(AssigneeFilter::User(id)) => {
+                builder
+                    .push(" AND EXISTS (SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id AND ta.user_id = ")
+                    .push_bind(id)
+                    .push(")");
+            }
+            Some(AssigneeFilter::Unassigned) => {
+                builder.push(" AND NOT EXISTS (SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id)");
+            }
+            None => {}
+        }
+        if let Some(created_by) = self.created_by {
+            builder.push(" AND t.created_by = ").push_bind(created_by);
+        }
+        if let Some(due_after) = self.due_after {
+            builder.push(" AND t.due_date >= ").push_bind(due_after);
+        }
+        if let Some(due_before) = self.due_before {
+            builder.push(" AND t.due_date < ").push_bind(due_before);
+        }
+        match self.completed {
+            Some(true) => {
+                builder.push(" AND t.completed_at IS NOT NULL");
+            }
+            Some(false) => {
+                builder.push(" AND t.completed_at IS NULL");
+            }
+            None => {}
+        }
+        if let Some(ref text) = self.text {
+            let pattern = format!("%{}%", escape_like(text));
+            builder
+                .push(" AND (t.title ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" OR t.description ILIKE ")
+                .push_bind(pattern)
+                .push(")");
+        }
+        if let Some(updated_since) = self.updated_since {
+            builder.push(" AND t.updated_at >= ").push_bind(updated_since);
+        }
+        if let Some(ref label_ids) = self.label_ids {
+            builder
+                .push(" AND (SELECT COUNT(*) FROM task_labels tl WHERE tl.task_id = t.id AND tl.label_id = ANY(")
+                .push_bind(label_ids.clone())
+                .push(")) = ")
+                .push_bind(label_ids.len() as i64);
+        }
+        if let Some((ref expr, ref context)) = self.advanced {
+            builder.push(" AND ");
+            task_query::push_sql(expr, context, builder);
+        }
+    }
+}
+
 pub async fn create_task(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
@@ -24,7 +416,7 @@ pub async fn create_task(
     // Check if user has access to the project's organization
     let access = sqlx::query!(
         r#"
-        SELECT om.id
+        SELECT p.organization_id
         FROM projects p
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
         WHERE p.id = $1 AND om.user_id = $2
@@ -39,64 +431,102 @@ pub async fn create_task(
         AppError::DatabaseError("Failed to check project access".to_string())
     })?;
 
-    if access.is_none() {
+    let Some(access) = access else {
         return Err(AppError::Authorization(
             "You don't have access to this project".to_string(),
         ));
-    }
+    };
+
+    let assigned_to = match payload.assigned_to {
+        Some(id) => Patch::Value(id),
+        None => Patch::Missing,
+    };
+    let assignees = requested_assignees(assigned_to, payload.assignee_ids)?.unwrap_or_default();
 
     let status = payload.status.unwrap_or_else(|| "todo".to_string());
     let priority = payload.priority.unwrap_or_else(|| "medium".to_string());
+    validate_estimate("original_estimate_seconds", payload.original_estimate_seconds)?;
 
-    // Get the next position for this status
-    let max_position = sqlx::query!(
-        "SELECT COALESCE(MAX(position), -1) as max_pos FROM tasks WHERE project_id = $1 AND status = $2",
-        project_id,
-        status
-    )
-    .fetch_one(&state.db)
-    .await
-    .map_err(|e| {
+    let custom_values =
+        prepare_initial_values(&state.db, project_id, payload.custom_fields.as_ref()).await?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
         tracing::error!("Database error: {}", e);
-        AppError::DatabaseError("Failed to get max position".to_string())
+        AppError::DatabaseError("Failed to start transaction".to_string())
     })?;
 
-    let position = max_position.max_pos.unwrap_or(-1) + 1;
+    ensure_assignable(&mut tx, access.organization_id, &assignees).await?;
+
+    // New tasks go to the end of their column
+    lock_project_ranks(&mut tx, project_id).await?;
+    let rank = last_rank(&mut tx, project_id).await?;
+    let number = allocate_task_number(&mut tx, project_id).await?;
 
     let task = sqlx::query_as!(
         Task,
         r#"
-        INSERT INTO tasks (project_id, title, description, status, priority, assigned_to, created_by, due_date, position)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, completed_at, position, created_at, updated_at
+        INSERT INTO tasks (project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, rank, number)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $9, $10, $11)
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
         "#,
         project_id,
         payload.title,
         payload.description,
         status,
         priority,
-        payload.assigned_to,
+        assignees.first().copied(),
         user_id,
         payload.due_date,
-        position
+        payload.original_estimate_seconds,
+        rank,
+        number
     )
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
         tracing::error!("Database error: {}", e);
         AppError::DatabaseError("Failed to create task".to_string())
     })?;
 
+    for (field_id, value) in custom_values {
+        sqlx::query!(
+            r#"
+            INSERT INTO task_custom_field_values (task_id, field_id, value, updated_by)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            task.id,
+            field_id,
+            value,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to set custom field value".to_string())
+        })?;
+    }
+
+    // The creator and the assignees start out watching the task
+    insert_assignees(&mut tx, task.id, &assignees).await?;
+    add_watchers(&mut tx, task.id, &[user_id]).await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit task".to_string())
+    })?;
+
     tracing::info!("Task created: {} in project {}", task.title, project_id);
 
-    Ok((StatusCode::CREATED, Json(task.into())))
+    Ok((StatusCode::CREATED, Json(build_task_response(&state.db, task).await?)))
 }
 
 pub async fn get_project_tasks(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(project_id): Path<Uuid>,
-) -> Result<Json<Vec<TaskResponse>>, AppError> {
+    Query(query): Query<TaskListQuery>,
+) -> Result<Json<PaginatedResponse<TaskResponse>>, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
@@ -124,44 +554,229 @@ pub async fn get_project_tasks(
         ));
     }
 
-    let tasks = sqlx::query_as!(
-        Task,
-        r#"
-        SELECT id, project_id, title, description, status, priority, assigned_to, created_by, due_date, completed_at, position, created_at, updated_at
-        FROM tasks
-        WHERE project_id = $1
-        ORDER BY position ASC, created_at ASC
-        "#,
-        project_id
-    )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        AppError::DatabaseError("Failed to fetch tasks".to_string())
-    })?;
+    let filters = TaskFilters::from_query(&query, user_id)?;
+    let direction = SortDirection::parse(query.order.as_deref())?;
+    let limit = page_size(query.limit)?;
+
+    let (sort_key, sort_field) = match query.sort.as_deref() {
+        Some(sort) if sort.starts_with("custom_field:") => {
+            let field_id = sort
+                .strip_prefix("custom_field:")
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .ok_or_else(|| AppError::Validation(format!("Invalid sort: {}", sort)))?;
+            let field = fetch_project_field(&state.db, project_id, field_id).await?;
+            let expression = match CustomFieldType::parse(&field.field_type) {
+                Some(CustomFieldType::Number) => ("(sv.value #>> '{}')::float8", "float8"),
+                Some(CustomFieldType::Date) => ("(sv.value #>> '{}')::date", "date"),
+                _ => ("sv.value #>> '{}'", "text"),
+            };
+            (SortKey::new(sort, expression.0, expression.1), Some(field))
+        }
+        sort => (task_sort_key(sort)?, None),
+    };
+    let cursor = Cursor::decode_for(query.cursor.as_deref(), &sort_key)?;
+
+    let mut builder = QueryBuilder::<Postgres>::new(format!("SELECT {}", TASK_COLUMNS));
+    sort_key.push_select(&mut builder);
+    builder.push(" FROM tasks t");
+
+    if let Some(ref field) = sort_field {
+        builder
+            .push(" LEFT JOIN task_custom_field_values sv ON sv.task_id = t.id AND sv.field_id = ")
+            .push_bind(field.id);
+    }
+
+    builder
+        .push(" WHERE t.deleted_at IS NULL AND t.project_id = ")
+        .push_bind(project_id);
+    filters.push(&mut builder);
+
+    match (query.custom_field, query.custom_value) {
+        (Some(field_id), Some(value)) => {
+            let field = fetch_project_field(&state.db, project_id, field_id).await?;
+            let field_type = CustomFieldType::parse(&field.field_type).ok_or_else(|| {
+                AppError::Internal(format!("Unknown custom field type: {}", field.field_type))
+            })?;
+
+            builder
+                .push(" AND EXISTS (SELECT 1 FROM task_custom_field_values fv WHERE fv.task_id = t.id AND fv.field_id = ")
+                .push_bind(field.id);
+
+            match field_type {
+                CustomFieldType::Number => {
+                    let number: f64 = value.parse().map_err(|_| {
+                        AppError::Validation(format!("Invalid number: {}", value))
+                    })?;
+                    builder.push(" AND (fv.value #>> '{}')::float8 = ").push_bind(number);
+                }
+                CustomFieldType::MultiSelect => {
+                    builder.push(" AND fv.value @> jsonb_build_array(").push_bind(value).push("::text)");
+                }
+                CustomFieldType::Text | CustomFieldType::Url => {
+                    builder
+                        .push(" AND fv.value #>> '{}' ILIKE ")
+                        .push_bind(format!("%{}%", escape_like(&value)));
+                }
+                CustomFieldType::Date | CustomFieldType::SingleSelect | CustomFieldType::User => {
+                    builder.push(" AND fv.value #>> '{}' = ").push_bind(value);
+                }
+            }
+
+            builder.push(")");
+        }
+        (None, None) => {}
+        _ => {
+            return Err(AppError::Validation(
+                "custom_field and custom_value must be given together".to_string(),
+            ));
+        }
+    }
+
+    if let Some(ref cursor) = cursor {
+        sort_key.push_after(&mut builder, "t.id", direction, cursor);
+    }
+    sort_key.push_order_by(&mut builder, "t.id", direction);
+    builder.push(" LIMIT ").push_bind(limit + 1);
+
+    let rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch tasks".to_string())
+        })?;
+
+    let (tasks, next_cursor) = paginate_task_rows(rows, &sort_key, limit)?;
+    let items = build_task_responses(&state.db, tasks).await?;
 
-    let responses: Vec<TaskResponse> = tasks.into_iter().map(|t| t.into()).collect();
+    Ok(Json(PaginatedResponse { items, next_cursor }))
+}
+
+/// Tasks assigned to the current user across all of their organizations
+pub async fn get_my_tasks(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<MyTasksQuery>,
+) -> Result<Json<MyTasksResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
-    Ok(Json(responses))
+    let offset = utc_offset(query.tz_offset)?;
+
+    if let Some(org_id) = query.org_id {
+        let membership = sqlx::query!(
+            "SELECT id FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+            org_id,
+            user_id
+        )
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check organization membership".to_string())
+        })?;
+
+        if membership.is_none() {
+            return Err(AppError::Authorization(
+                "You are not a member of this organization".to_string(),
+            ));
+        }
+    }
+
+    let filters = TaskFilters {
+        statuses: split_list(query.status.as_deref()),
+        priorities: split_list(query.priority.as_deref()),
+        assignee: Some(AssigneeFilter::User(user_id)),
+        completed: if query.include_completed.unwrap_or(false) {
+            None
+        } else {
+            Some(false)
+        },
+        ..Default::default()
+    };
+
+    let mut builder = QueryBuilder::<Postgres>::new(format!(
+        "SELECT {}, p.name AS project_name, o.id AS organization_id, o.name AS organization_name",
+        TASK_COLUMNS
+    ));
+    builder
+        .push(" FROM tasks t")
+        .push(" INNER JOIN projects p ON t.project_id = p.id")
+        .push(" INNER JOIN organizations o ON p.organization_id = o.id")
+        .push(" INNER JOIN organization_members om ON om.organization_id = o.id AND om.user_id = ")
+        .push_bind(user_id)
+        .push(" WHERE t.deleted_at IS NULL");
+    if let Some(org_id) = query.org_id {
+        builder.push(" AND o.id = ").push_bind(org_id);
+    }
+    filters.push(&mut builder);
+    builder.push(format!(
+        " ORDER BY t.due_date ASC NULLS LAST, ({}) ASC, t.id ASC",
+        PRIORITY_RANK_SQL
+    ));
+
+    let rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch tasks".to_string())
+        })?;
+
+    let mut locations = Vec::with_capacity(rows.len());
+    let mut tasks = Vec::with_capacity(rows.len());
+    for row in &rows {
+        tasks.push(Task::from_row(row)?);
+        locations.push((
+            row.try_get::<String, _>("project_name")?,
+            row.try_get::<Uuid, _>("organization_id")?,
+            row.try_get::<String, _>("organization_name")?,
+        ));
+    }
+
+    let now = Utc::now();
+    let mut response = MyTasksResponse::default();
+    let responses = build_task_responses(&state.db, tasks).await?;
+
+    for (task, (project_name, organization_id, organization_name)) in
+        responses.into_iter().zip(locations)
+    {
+        let bucket = match due_bucket(task.due_date, now, offset) {
+            DueBucket::Overdue => &mut response.overdue,
+            DueBucket::Today => &mut response.today,
+            DueBucket::ThisWeek => &mut response.this_week,
+            DueBucket::Later => &mut response.later,
+            DueBucket::NoDueDate => &mut response.no_due_date,
+        };
+        bucket.push(MyTaskResponse {
+            task,
+            project_name,
+            organization_id,
+            organization_name,
+        });
+    }
+
+    Ok(Json(response))
 }
 
 pub async fn get_task(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(task_id): Path<Uuid>,
-) -> Result<Json<TaskResponse>, AppError> {
+) -> Result<impl IntoResponse, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
     let task = sqlx::query_as!(
         Task,
         r#"
-        SELECT t.id, t.project_id, t.title, t.description, t.status, t.priority, t.assigned_to, t.created_by, t.due_date, t.completed_at, t.position, t.created_at, t.updated_at
+        SELECT t.id, t.project_id, t.title, t.description, t.status, t.priority, t.assigned_to, t.created_by, t.due_date, t.original_estimate_seconds, t.remaining_estimate_seconds, t.completed_at, t.rank, t.number, t.version, t.created_at, t.updated_at
         FROM tasks t
         INNER JOIN projects p ON t.project_id = p.id
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
-        WHERE t.id = $1 AND om.user_id = $2
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
         "#,
         task_id,
         user_id
@@ -174,100 +789,422 @@ pub async fn get_task(
     })?;
 
     match task {
-        Some(t) => Ok(Json(t.into())),
+        Some(t) => Ok((
+            [(header::ETAG, etag(t.version))],
+            Json(build_task_response(&state.db, t).await?),
+        )),
         None => Err(AppError::NotFound("Task not found".to_string())),
     }
 }
 
+/// Resolve a key such as `WEB-123`, following redirects left by tasks that
+/// moved to another project. Pass `org_id` when the key exists in several of
+/// the user's organizations.
+pub async fn get_task_by_key(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(key): Path<String>,
+    Query(query): Query<TaskKeyQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let (project_key, number) = parse_task_key(&key)?;
+
+    let projects = sqlx::query_scalar!(
+        r#"
+        SELECT p.id
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE p.key = $1 AND om.user_id = $2 AND ($3::uuid IS NULL OR p.organization_id = $3)
+        "#,
+        project_key,
+        user_id,
+        query.org_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to resolve project key".to_string())
+    })?;
+
+    let project_id = match projects.as_slice() {
+        [] => return Err(AppError::NotFound("Task not found".to_string())),
+        [project_id] => *project_id,
+        _ => {
+            return Err(AppError::Validation(format!(
+                "{} exists in several of your organizations; pass org_id",
+                project_key
+            )))
+        }
+    };
+
+    let task_id = sqlx::query_scalar!(
+        r#"
+        SELECT COALESCE(
+            (SELECT id FROM tasks WHERE project_id = $1 AND number = $2),
+            (SELECT task_id FROM task_key_redirects WHERE project_id = $1 AND number = $2)
+        )
+        "#,
+        project_id,
+        number
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to resolve task key".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task not found".to_string()))?;
+
+    get_task(State(state), Extension(auth_user), Path(task_id)).await
+}
+
 pub async fn update_task(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(task_id): Path<Uuid>,
+    headers: HeaderMap,
     Json(payload): Json<UpdateTaskRequest>,
-) -> Result<Json<TaskResponse>, AppError> {
+) -> Result<impl IntoResponse, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+    let expected_versions = if_match_versions(&headers)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
 
     // Check access
     let access = sqlx::query!(
         r#"
-        SELECT om.id
+        SELECT t.project_id, p.organization_id
         FROM tasks t
         INNER JOIN projects p ON t.project_id = p.id
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
-        WHERE t.id = $1 AND om.user_id = $2
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
         "#,
         task_id,
         user_id
     )
-    .fetch_optional(&state.db)
+    .fetch_optional(&mut *tx)
     .await
     .map_err(|e| {
         tracing::error!("Database error: {}", e);
         AppError::DatabaseError("Failed to check task access".to_string())
     })?;
 
-    if access.is_none() {
+    let Some(access) = access else {
         return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    };
+
+    if payload.status.value().map(String::as_str) == Some("done") {
+        ensure_can_complete(&mut *tx, task_id, access.project_id).await?;
     }
 
-    // Check if task is being marked as completed
-    let completed_at = if let Some(ref status) = payload.status {
-        if status == "done" {
-            Some(chrono::Utc::now())
-        } else {
-            None
-        }
-    } else {
-        None
-    };
+    let old = lock_task(&mut tx, task_id).await?;
 
+    let title = payload.title.required("title")?;
+    let status = payload.status.required("status")?;
+    let priority = payload.priority.required("priority")?;
+    validate_estimate("original_estimate_seconds", payload.original_estimate_seconds.value().copied())?;
+    validate_estimate("remaining_estimate_seconds", payload.remaining_estimate_seconds.value().copied())?;
+    let assignees = requested_assignees(payload.assigned_to, payload.assignee_ids)?;
+    if let Some(ref ids) = assignees {
+        ensure_assignable(&mut tx, access.organization_id, ids).await?;
+    }
+
+    // `completed_at` is set on entering "done" and cleared on leaving it
     let task = sqlx::query_as!(
         Task,
         r#"
         UPDATE tasks
-        SET 
+        SET
             title = COALESCE($2, title),
-            description = COALESCE($3, description),
-            status = COALESCE($4, status),
-            priority = COALESCE($5, priority),
-            assigned_to = COALESCE($6, assigned_to),
-            due_date = COALESCE($7, due_date),
-            position = COALESCE($8, position),
-            completed_at = COALESCE($9, completed_at),
+            description = CASE WHEN $3 THEN $4 ELSE description END,
+            status = COALESCE($5, status),
+            priority = COALESCE($6, priority),
+            assigned_to = CASE WHEN $7 THEN $8 ELSE assigned_to END,
+            due_date = CASE WHEN $9 THEN $10 ELSE due_date END,
+            original_estimate_seconds = CASE WHEN $12 THEN $13 ELSE original_estimate_seconds END,
+            remaining_estimate_seconds = CASE WHEN $14 THEN $15 ELSE remaining_estimate_seconds END,
+            completed_at = CASE
+                WHEN COALESCE($5, status) <> 'done' THEN NULL
+                WHEN status = 'done' THEN completed_at
+                ELSE NOW()
+            END,
+            version = version + 1,
             updated_at = NOW()
-        WHERE id = $1
-        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, completed_at, position, created_at, updated_at
+        WHERE id = $1 AND ($11::int4[] IS NULL OR version = ANY($11))
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
         "#,
         task_id,
-        payload.title,
-        payload.description,
-        payload.status,
-        payload.priority,
-        payload.assigned_to,
-        payload.due_date,
-        payload.position,
-        completed_at
+        title,
+        payload.description.is_set(),
+        payload.description.into_option(),
+        status,
+        priority,
+        assignees.is_some(),
+        assignees.as_ref().and_then(|ids| ids.first().copied()),
+        payload.due_date.is_set(),
+        payload.due_date.into_option(),
+        expected_versions.as_deref(),
+        payload.original_estimate_seconds.is_set(),
+        payload.original_estimate_seconds.into_option(),
+        payload.remaining_estimate_seconds.is_set(),
+        payload.remaining_estimate_seconds.into_option()
     )
-    .fetch_one(&state.db)
+    .fetch_optional(&mut *tx)
     .await
     .map_err(|e| {
         tracing::error!("Database error: {}", e);
         AppError::DatabaseError("Failed to update task".to_string())
+    })?
+    .ok_or_else(|| {
+        AppError::PreconditionFailed("Task has been changed since it was loaded".to_string())
+    })?;
+
+    record_task_changes(&mut tx, &old, &task, user_id).await?;
+    if let Some(ids) = assignees {
+        set_task_assignees(&mut tx, task.id, &ids, user_id).await?;
+    }
+    advance_on_completion(&mut tx, &old, &task).await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit task update".to_string())
     })?;
 
     tracing::info!("Task updated: {}", task.id);
 
-    Ok(Json(task.into()))
+    Ok((
+        [(header::ETAG, etag(task.version))],
+        Json(build_task_response(&state.db, task).await?),
+    ))
+}
+
+/// Load a task and lock its row until the end of the transaction
+pub(crate) async fn lock_task(conn: &mut PgConnection, task_id: Uuid) -> Result<Task, AppError> {
+    sqlx::query_as!(
+        Task,
+        r#"
+        SELECT id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        FROM tasks
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        task_id
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch task".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task not found".to_string()))
+}
+
+/// Rank of a neighbor for a move; it must already be in the target column
+async fn neighbor_rank(
+    conn: &mut PgConnection,
+    neighbor_id: Uuid,
+    project_id: Uuid,
+    status: &str,
+) -> Result<String, AppError> {
+    let neighbor = sqlx::query_scalar!(
+        "SELECT rank FROM tasks WHERE id = $1 AND project_id = $2 AND status = $3 AND deleted_at IS NULL",
+        neighbor_id,
+        project_id,
+        status
+    )
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch neighbor task".to_string())
+    })?;
+
+    neighbor.ok_or_else(|| {
+        AppError::Validation("Neighbor tasks must be in the target column".to_string())
+    })
+}
+
+/// Move a task within its column or to another one, between the given neighbors
+pub async fn move_task(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<MoveTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    if payload.before_id == Some(task_id) || payload.after_id == Some(task_id) {
+        return Err(AppError::Validation("A task cannot be its own neighbor".to_string()));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let task = sqlx::query!(
+        r#"
+        SELECT t.project_id, t.status
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task not found".to_string()))?;
+
+    let status = payload.status.unwrap_or_else(|| task.status.clone());
+    if status == "done" && task.status != "done" {
+        ensure_can_complete(&mut *tx, task_id, task.project_id).await?;
+    }
+
+    lock_project_ranks(&mut tx, task.project_id).await?;
+    let old = lock_task(&mut tx, task_id).await?;
+
+    let before = match payload.before_id {
+        Some(id) => Some(neighbor_rank(&mut tx, id, task.project_id, &status).await?),
+        None => None,
+    };
+    let after = match payload.after_id {
+        Some(id) => Some(neighbor_rank(&mut tx, id, task.project_id, &status).await?),
+        None => None,
+    };
+
+    // Fill in a missing neighbor from the column itself
+    let (before, after) = match (before, after) {
+        (Some(before), None) => {
+            let next = sqlx::query_scalar!(
+                r#"
+                SELECT MIN(rank) FROM tasks
+                WHERE project_id = $1 AND status = $2 AND id <> $3 AND rank > $4 AND deleted_at IS NULL
+                "#,
+                task.project_id,
+                status,
+                task_id,
+                before
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to fetch neighbor task".to_string())
+            })?;
+            (Some(before), next)
+        }
+        (None, after) => {
+            let previous = sqlx::query_scalar!(
+                r#"
+                SELECT MAX(rank) FROM tasks
+                WHERE project_id = $1 AND status = $2 AND id <> $3 AND ($4::text IS NULL OR rank < $4)
+                  AND deleted_at IS NULL
+                "#,
+                task.project_id,
+                status,
+                task_id,
+                after
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to fetch neighbor task".to_string())
+            })?;
+            (previous, after)
+        }
+        neighbors => neighbors,
+    };
+
+    // Tasks of other columns share the project's key space, so step past any taken key
+    let mut new_rank = rank::between(before.as_deref(), after.as_deref())?;
+    loop {
+        let taken = sqlx::query_scalar!(
+            r#"SELECT EXISTS(SELECT 1 FROM tasks WHERE project_id = $1 AND rank = $2 AND id <> $3) AS "taken!""#,
+            task.project_id,
+            new_rank,
+            task_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check rank".to_string())
+        })?;
+
+        if !taken {
+            break;
+        }
+        new_rank = rank::between(Some(&new_rank), after.as_deref())?;
+    }
+
+    let task = sqlx::query_as!(
+        Task,
+        r#"
+        UPDATE tasks
+        SET
+            status = $2::varchar,
+            rank = $3,
+            completed_at = CASE
+                WHEN $2::varchar <> 'done' THEN NULL
+                WHEN status = 'done' THEN completed_at
+                ELSE NOW()
+            END,
+            version = version + 1,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        "#,
+        task_id,
+        status,
+        new_rank
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to move task".to_string())
+    })?;
+
+    record_task_changes(&mut tx, &old, &task, user_id).await?;
+    advance_on_completion(&mut tx, &old, &task).await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit task move".to_string())
+    })?;
+
+    tracing::info!("Task moved: {} to {}", task.id, task.status);
+
+    Ok((
+        [(header::ETAG, etag(task.version))],
+        Json(build_task_response(&state.db, task).await?),
+    ))
 }
 
 pub async fn delete_task(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(task_id): Path<Uuid>,
+    headers: HeaderMap,
 ) -> Result<StatusCode, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+    let expected_versions = if_match_versions(&headers)?;
 
     // Check access
     let access = sqlx::query!(
@@ -276,7 +1213,7 @@ pub async fn delete_task(
         FROM tasks t
         INNER JOIN projects p ON t.project_id = p.id
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
-        WHERE t.id = $1 AND om.user_id = $2
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
         "#,
         task_id,
         user_id
@@ -288,17 +1225,33 @@ pub async fn delete_task(
         AppError::DatabaseError("Failed to check task access".to_string())
     })?;
 
+    // Deleting only moves the task to the trash; it can be restored until purged
     match access {
         Some(_) => {
-            sqlx::query!("DELETE FROM tasks WHERE id = $1", task_id)
-                .execute(&state.db)
-                .await
-                .map_err(|e| {
-                    tracing::error!("Database error: {}", e);
-                    AppError::DatabaseError("Failed to delete task".to_string())
-                })?;
-
-            tracing::info!("Task deleted: {}", task_id);
+            let deleted = sqlx::query!(
+                r#"
+                UPDATE tasks
+                SET deleted_at = NOW(), deleted_by = $2, version = version + 1, updated_at = NOW()
+                WHERE id = $1 AND ($3::int4[] IS NULL OR version = ANY($3))
+                "#,
+                task_id,
+                user_id,
+                expected_versions.as_deref()
+            )
+            .execute(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to delete task".to_string())
+            })?;
+
+            if deleted.rows_affected() == 0 {
+                return Err(AppError::PreconditionFailed(
+                    "Task has been changed since it was loaded".to_string(),
+                ));
+            }
+
+            tracing::info!("Task moved to trash: {}", task_id);
             Ok(StatusCode::NO_CONTENT)
         }
         None => Err(AppError::NotFound("Task not found".to_string())),
@@ -321,7 +1274,7 @@ pub async fn create_comment(
         FROM tasks t
         INNER JOIN projects p ON t.project_id = p.id
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
-        WHERE t.id = $1 AND om.user_id = $2
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
         "#,
         task_id,
         user_id
@@ -355,16 +1308,81 @@ pub async fn create_comment(
         AppError::DatabaseError("Failed to create comment".to_string())
     })?;
 
+    sqlx::query!(
+        "INSERT INTO task_watchers (task_id, user_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        task_id,
+        user_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to add watcher".to_string())
+    })?;
+
     tracing::info!("Comment created on task: {}", task_id);
 
     Ok((StatusCode::CREATED, Json(comment.into())))
 }
 
+/// Move a comment to the trash. Authors may delete their own comments;
+/// organization owners and admins may delete any.
+pub async fn delete_comment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let access = sqlx::query!(
+        r#"
+        SELECT c.user_id, om.role
+        FROM task_comments c
+        INNER JOIN tasks t ON c.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE c.id = $1 AND om.user_id = $2 AND c.deleted_at IS NULL AND t.deleted_at IS NULL
+        "#,
+        comment_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check comment access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Comment not found".to_string()))?;
+
+    if access.user_id != user_id && access.role != "owner" && access.role != "admin" {
+        return Err(AppError::Authorization(
+            "Only the author or an organization admin can delete this comment".to_string(),
+        ));
+    }
+
+    sqlx::query!(
+        "UPDATE task_comments SET deleted_at = NOW(), deleted_by = $2 WHERE id = $1",
+        comment_id,
+        user_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to delete comment".to_string())
+    })?;
+
+    tracing::info!("Comment moved to trash: {}", comment_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
 pub async fn get_task_comments(
     State(state): State<AppState>,
     Extension(auth_user): Extension<AuthUser>,
     Path(task_id): Path<Uuid>,
-) -> Result<Json<Vec<CommentResponse>>, AppError> {
+    Query(query): Query<CommentListQuery>,
+) -> Result<Json<PaginatedResponse<CommentResponse>>, AppError> {
     let user_id = Uuid::parse_str(&auth_user.user_id)
         .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
 
@@ -375,7 +1393,7 @@ pub async fn get_task_comments(
         FROM tasks t
         INNER JOIN projects p ON t.project_id = p.id
         INNER JOIN organization_members om ON p.organization_id = om.organization_id
-        WHERE t.id = $1 AND om.user_id = $2
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
         "#,
         task_id,
         user_id
@@ -391,24 +1409,60 @@ pub async fn get_task_comments(
         return Err(AppError::Authorization("You don't have access to this task".to_string()));
     }
 
-    let comments = sqlx::query_as!(
-        TaskComment,
-        r#"
-        SELECT id, task_id, user_id, content, created_at, updated_at
-        FROM task_comments
-        WHERE task_id = $1
-        ORDER BY created_at ASC
-        "#,
-        task_id
-    )
-    .fetch_all(&state.db)
-    .await
-    .map_err(|e| {
-        tracing::error!("Database error: {}", e);
-        AppError::DatabaseError("Failed to fetch comments".to_string())
-    })?;
+    let direction = SortDirection::parse(query.order.as_deref())?;
+    let limit = page_size(query.limit)?;
+    let sort_key = SortKey::new("created_at", "c.created_at", "timestamptz");
+    let cursor = Cursor::decode_for(query.cursor.as_deref(), &sort_key)?;
+
+    let mut builder = QueryBuilder::<Postgres>::new(
+        "SELECT c.id, c.task_id, c.user_id, c.content, c.created_at, c.updated_at",
+    );
+    sort_key.push_select(&mut builder);
+    builder
+        .push(" FROM task_comments c WHERE c.deleted_at IS NULL AND c.task_id = ")
+        .push_bind(task_id);
+
+    if let Some(author) = query.user_id {
+        builder.push(" AND c.user_id = ").push_bind(author);
+    }
+    if let Some(since) = query.since {
+        builder.push(" AND c.created_at >= ").push_bind(since);
+    }
+    if let Some(ref cursor) = cursor {
+        sort_key.push_after(&mut builder, "c.id", direction, cursor);
+    }
+    sort_key.push_order_by(&mut builder, "c.id", direction);
+    builder.push(" LIMIT ").push_bind(limit + 1);
+
+    let mut rows = builder
+        .build()
+        .fetch_all(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to fetch comments".to_string())
+        })?;
+
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let next_cursor = match rows.last() {
+        Some(last) if has_more => Some(
+            Cursor {
+                sort: sort_key.name.clone(),
+                value: last.try_get("sort_key")?,
+                id: last.try_get("id")?,
+            }
+            .encode(),
+        ),
+        _ => None,
+    };
 
-    let responses: Vec<CommentResponse> = comments.into_iter().map(|c| c.into()).collect();
+    let items = rows
+        .iter()
+        .map(TaskComment::from_row)
+        .map(|c| c.map(CommentResponse::from))
+        .collect::<Result<Vec<CommentResponse>, _>>()?;
 
-    Ok(Json(responses))
+    Ok(Json(PaginatedResponse { items, next_cursor }))
 }
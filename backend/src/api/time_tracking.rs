@@ -0,0 +1,497 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{
+        AuthUser, CreateTimeLogRequest, ProjectTimeSummary, StartTimerRequest, TimeLog,
+        TimeLogResponse, TimesheetEntry, TimesheetQuery, TimesheetResponse, UserTimeTotal,
+    },
+    services::{
+        recurrence::parse_timezone,
+        task_keys::format_task_key,
+        time_tracking::{day_range, elapsed_seconds, timesheet_csv, validate_duration},
+    },
+    utils::AppError,
+};
+
+/// Finished time logged on each of the given tasks, in seconds
+pub(crate) async fn time_spent_for_tasks(
+    db: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, i64>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT task_id, SUM(duration_seconds)::bigint AS "total!"
+        FROM time_logs
+        WHERE task_id = ANY($1) AND ended_at IS NOT NULL
+        GROUP BY task_id
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch logged time".to_string())
+    })?;
+
+    Ok(rows.into_iter().map(|r| (r.task_id, r.total)).collect())
+}
+
+async fn check_task_access(db: &PgPool, task_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    match access {
+        Some(_) => Ok(()),
+        None => Err(AppError::Authorization("You don't have access to this task".to_string())),
+    }
+}
+
+async fn running_timer(db: &PgPool, user_id: Uuid) -> Result<Option<TimeLog>, AppError> {
+    sqlx::query_as!(
+        TimeLog,
+        r#"
+        SELECT id, task_id, user_id, description, started_at, ended_at, duration_seconds, created_at, updated_at
+        FROM time_logs
+        WHERE user_id = $1 AND ended_at IS NULL
+        "#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch running timer".to_string())
+    })
+}
+
+/// Start a timer on a task. A user runs one timer at a time.
+pub async fn start_timer(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<StartTimerRequest>,
+) -> Result<(StatusCode, Json<TimeLogResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    if running_timer(&state.db, user_id).await?.is_some() {
+        return Err(AppError::Conflict(
+            "Stop your running timer before starting another".to_string(),
+        ));
+    }
+
+    let log = sqlx::query_as!(
+        TimeLog,
+        r#"
+        INSERT INTO time_logs (task_id, user_id, description, started_at)
+        VALUES ($1, $2, $3, NOW())
+        RETURNING id, task_id, user_id, description, started_at, ended_at, duration_seconds, created_at, updated_at
+        "#,
+        task_id,
+        user_id,
+        payload.description
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start timer".to_string())
+    })?;
+
+    tracing::info!("Timer started on task {} by {}", task_id, user_id);
+
+    Ok((StatusCode::CREATED, Json(log.into())))
+}
+
+/// The caller's running timer, or `null`
+pub async fn get_running_timer(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<Option<TimeLogResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let log = running_timer(&state.db, user_id).await?;
+
+    Ok(Json(log.map(Into::into)))
+}
+
+/// Stop the caller's running timer and record its duration
+pub async fn stop_timer(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+) -> Result<Json<TimeLogResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let running = running_timer(&state.db, user_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("No timer is running".to_string()))?;
+
+    let ended_at = Utc::now();
+    let log = sqlx::query_as!(
+        TimeLog,
+        r#"
+        UPDATE time_logs
+        SET ended_at = $2, duration_seconds = $3, updated_at = NOW()
+        WHERE id = $1 AND ended_at IS NULL
+        RETURNING id, task_id, user_id, description, started_at, ended_at, duration_seconds, created_at, updated_at
+        "#,
+        running.id,
+        ended_at,
+        elapsed_seconds(running.started_at, ended_at)
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to stop timer".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("No timer is running".to_string()))?;
+
+    tracing::info!("Timer stopped on task {} by {}", log.task_id, user_id);
+
+    Ok(Json(log.into()))
+}
+
+pub async fn create_time_log(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    Json(payload): Json<CreateTimeLogRequest>,
+) -> Result<(StatusCode, Json<TimeLogResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    validate_duration(payload.duration_seconds)?;
+    let ended_at = payload.started_at + Duration::seconds(payload.duration_seconds.into());
+    if ended_at > Utc::now() {
+        return Err(AppError::Validation("Time cannot be logged in the future".to_string()));
+    }
+
+    let log = sqlx::query_as!(
+        TimeLog,
+        r#"
+        INSERT INTO time_logs (task_id, user_id, description, started_at, ended_at, duration_seconds)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, task_id, user_id, description, started_at, ended_at, duration_seconds, created_at, updated_at
+        "#,
+        task_id,
+        user_id,
+        payload.description,
+        payload.started_at,
+        ended_at,
+        payload.duration_seconds
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to log time".to_string())
+    })?;
+
+    tracing::info!("Time logged on task {}: {}s", task_id, payload.duration_seconds);
+
+    Ok((StatusCode::CREATED, Json(log.into())))
+}
+
+pub async fn get_task_time_logs(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TimeLogResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    check_task_access(&state.db, task_id, user_id).await?;
+
+    let logs = sqlx::query_as!(
+        TimeLog,
+        r#"
+        SELECT id, task_id, user_id, description, started_at, ended_at, duration_seconds, created_at, updated_at
+        FROM time_logs
+        WHERE task_id = $1
+        ORDER BY started_at DESC, id
+        "#,
+        task_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch time logs".to_string())
+    })?;
+
+    Ok(Json(logs.into_iter().map(Into::into).collect()))
+}
+
+/// Logs can be removed by their author or an organization admin
+pub async fn delete_time_log(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(log_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let access = sqlx::query!(
+        r#"
+        SELECT l.user_id, om.role
+        FROM time_logs l
+        INNER JOIN tasks t ON l.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE l.id = $1 AND om.user_id = $2
+        "#,
+        log_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check time log access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Time log not found".to_string()))?;
+
+    if access.user_id != user_id && access.role != "owner" && access.role != "admin" {
+        return Err(AppError::Authorization(
+            "Only the author or an organization admin can delete this time log".to_string(),
+        ));
+    }
+
+    sqlx::query!("DELETE FROM time_logs WHERE id = $1", log_id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete time log".to_string())
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn get_project_time_summary(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<ProjectTimeSummary>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE p.id = $1 AND om.user_id = $2
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization(
+            "You don't have access to this project".to_string(),
+        ));
+    }
+
+    let estimates = sqlx::query!(
+        r#"
+        SELECT
+            COALESCE(SUM(original_estimate_seconds), 0)::bigint AS "original!",
+            COALESCE(SUM(remaining_estimate_seconds), 0)::bigint AS "remaining!"
+        FROM tasks
+        WHERE project_id = $1 AND deleted_at IS NULL
+        "#,
+        project_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to sum estimates".to_string())
+    })?;
+
+    let users = sqlx::query_as!(
+        UserTimeTotal,
+        r#"
+        SELECT l.user_id, SUM(l.duration_seconds)::bigint AS "time_spent_seconds!"
+        FROM time_logs l
+        INNER JOIN tasks t ON l.task_id = t.id
+        WHERE t.project_id = $1 AND t.deleted_at IS NULL AND l.ended_at IS NOT NULL
+        GROUP BY l.user_id
+        ORDER BY 2 DESC, l.user_id
+        "#,
+        project_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to sum logged time".to_string())
+    })?;
+
+    Ok(Json(ProjectTimeSummary {
+        project_id,
+        original_estimate_seconds: estimates.original,
+        remaining_estimate_seconds: estimates.remaining,
+        time_spent_seconds: users.iter().map(|u| u.time_spent_seconds).sum(),
+        users,
+    }))
+}
+
+/// Finished time logs of one user that started within a range of days, as
+/// JSON or as a CSV download
+pub async fn get_timesheet(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Query(query): Query<TimesheetQuery>,
+) -> Result<Response, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let csv = match query.format.as_deref() {
+        None | Some("json") => false,
+        Some("csv") => true,
+        Some(other) => {
+            return Err(AppError::Validation(format!("Unknown format: {}", other)));
+        }
+    };
+    let tz = parse_timezone(query.timezone.as_deref().unwrap_or("UTC"))?;
+    let (start, end) = day_range(query.from, query.to, tz)?;
+
+    let subject = query.user_id.unwrap_or(user_id);
+    if subject != user_id {
+        let Some(org_id) = query.org_id else {
+            return Err(AppError::Validation(
+                "org_id is required for another user's timesheet".to_string(),
+            ));
+        };
+        let role = sqlx::query_scalar!(
+            "SELECT role FROM organization_members WHERE organization_id = $1 AND user_id = $2",
+            org_id,
+            user_id
+        )
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to check organization membership".to_string())
+        })?;
+
+        if !matches!(role.as_deref(), Some("owner") | Some("admin")) {
+            return Err(AppError::Authorization(
+                "Only organization admins can see other members' timesheets".to_string(),
+            ));
+        }
+    }
+
+    // Only time on tasks of organizations the caller belongs to is listed
+    let rows = sqlx::query!(
+        r#"
+        SELECT l.id, l.task_id, t.title AS task_title, t.number, p.id AS project_id,
+               p.name AS project_name, p.key AS project_key, l.description,
+               l.started_at, l.ended_at AS "ended_at!", l.duration_seconds AS "duration_seconds!"
+        FROM time_logs l
+        INNER JOIN tasks t ON l.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE l.user_id = $1 AND om.user_id = $2
+          AND l.ended_at IS NOT NULL
+          AND l.started_at >= $3 AND l.started_at < $4
+          AND ($5::uuid IS NULL OR p.organization_id = $5)
+          AND t.deleted_at IS NULL
+        ORDER BY l.started_at, l.id
+        "#,
+        subject,
+        user_id,
+        start,
+        end,
+        query.org_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch timesheet".to_string())
+    })?;
+
+    let entries: Vec<TimesheetEntry> = rows
+        .into_iter()
+        .map(|r| TimesheetEntry {
+            id: r.id,
+            task_id: r.task_id,
+            task_key: format_task_key(&r.project_key, r.number),
+            task_title: r.task_title,
+            project_id: r.project_id,
+            project_name: r.project_name,
+            description: r.description,
+            started_at: r.started_at,
+            ended_at: r.ended_at,
+            duration_seconds: r.duration_seconds,
+        })
+        .collect();
+
+    if csv {
+        let body = timesheet_csv(&entries, tz)?;
+        let disposition = format!(
+            "attachment; filename=\"timesheet-{}-{}.csv\"",
+            query.from, query.to
+        );
+        return Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (header::CONTENT_DISPOSITION, disposition),
+            ],
+            body,
+        )
+            .into_response());
+    }
+
+    Ok(Json(TimesheetResponse {
+        user_id: subject,
+        from: query.from,
+        to: query.to,
+        timezone: tz.name().to_string(),
+        total_seconds: entries.iter().map(|e| i64::from(e.duration_seconds)).sum(),
+        entries,
+    })
+    .into_response())
+}
@@ -0,0 +1,243 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Json},
+    Extension,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    api::{
+        assignees::set_task_assignees,
+        history::record_task_changes,
+        recurrence::advance_on_completion,
+        tasks::{allocate_task_number, build_task_response, ensure_can_complete, last_rank, lock_project_ranks, lock_task},
+    },
+    models::{AuthUser, Task, TransferTaskRequest},
+    utils::{etag, if_match_versions, AppError},
+};
+
+/// Rank at the end of the target project's board and the project's next task
+/// number, for a task about to move there
+pub(crate) async fn take_project_slot(
+    conn: &mut PgConnection,
+    project_id: Uuid,
+) -> Result<(String, i32), AppError> {
+    lock_project_ranks(&mut *conn, project_id).await?;
+    let rank = last_rank(&mut *conn, project_id).await?;
+    let number = allocate_task_number(&mut *conn, project_id).await?;
+    Ok((rank, number))
+}
+
+/// Tidy up after `old` left its project: its old key keeps resolving to the
+/// task, while project-scoped labels and custom fields do not follow it.
+/// Comments, attachments and history belong to the task and move with it.
+pub(crate) async fn leave_project(conn: &mut PgConnection, old: &Task) -> Result<(), AppError> {
+    sqlx::query!(
+        "INSERT INTO task_key_redirects (project_id, number, task_id) VALUES ($1, $2, $3)",
+        old.project_id,
+        old.number,
+        old.id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to record key redirect".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM task_labels tl
+        USING labels l
+        WHERE tl.label_id = l.id AND tl.task_id = $1 AND l.project_id = $2
+        "#,
+        old.id,
+        old.project_id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to remove labels".to_string())
+    })?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM task_custom_field_values v
+        USING custom_fields f
+        WHERE v.field_id = f.id AND v.task_id = $1 AND f.project_id = $2
+        "#,
+        old.id,
+        old.project_id
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to remove custom field values".to_string())
+    })?;
+
+    Ok(())
+}
+
+/// Move a task to another project of the same organization. The task keeps its
+/// id, so comments, attachments, dependencies and history come along; it gets a
+/// new key, lands at the end of the target board and the move is recorded in
+/// its history.
+pub async fn transfer_task(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+    headers: HeaderMap,
+    Json(payload): Json<TransferTaskRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+    let expected_versions = if_match_versions(&headers)?;
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT p.organization_id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Task not found".to_string()))?;
+
+    let old = lock_task(&mut tx, task_id).await?;
+    if let Some(ref versions) = expected_versions {
+        if !versions.contains(&old.version) {
+            return Err(AppError::PreconditionFailed(
+                "Task has been changed since it was loaded".to_string(),
+            ));
+        }
+    }
+
+    if payload.project_id == old.project_id {
+        return Err(AppError::Validation("The task is already in this project".to_string()));
+    }
+
+    let target = sqlx::query!(
+        "SELECT id FROM projects WHERE id = $1 AND organization_id = $2",
+        payload.project_id,
+        access.organization_id
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?;
+
+    if target.is_none() {
+        return Err(AppError::NotFound(
+            "Target project not found in this organization".to_string(),
+        ));
+    }
+
+    // Assignees who lost access to the organization are dropped rather than
+    // blocking the move; the change shows up in the task history
+    let assignees = sqlx::query_scalar!(
+        r#"
+        SELECT ta.user_id
+        FROM task_assignees ta
+        INNER JOIN organization_members om ON om.user_id = ta.user_id AND om.organization_id = $2
+        WHERE ta.task_id = $1
+        ORDER BY ta.user_id IS DISTINCT FROM $3, ta.created_at, ta.user_id
+        "#,
+        task_id,
+        access.organization_id,
+        old.assigned_to
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check organization membership".to_string())
+    })?;
+    let assigned_to = assignees.first().copied();
+
+    // Statuses are shared by all projects; what differs is whether the target
+    // enforces dependencies, so a done task must be completable there too
+    let status = payload.status.unwrap_or_else(|| old.status.clone());
+    if status == "done" {
+        ensure_can_complete(&mut *tx, task_id, payload.project_id).await?;
+    }
+
+    let (rank, number) = take_project_slot(&mut tx, payload.project_id).await?;
+
+    let task = sqlx::query_as!(
+        Task,
+        r#"
+        UPDATE tasks
+        SET
+            project_id = $2,
+            status = $3::varchar,
+            assigned_to = $4,
+            rank = $5,
+            number = $6,
+            completed_at = CASE
+                WHEN $3::varchar <> 'done' THEN NULL
+                WHEN status = 'done' THEN completed_at
+                ELSE NOW()
+            END,
+            version = version + 1,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        "#,
+        task_id,
+        payload.project_id,
+        status,
+        assigned_to,
+        rank,
+        number
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to move task".to_string())
+    })?;
+
+    leave_project(&mut tx, &old).await?;
+    record_task_changes(&mut tx, &old, &task, user_id).await?;
+    set_task_assignees(&mut tx, task.id, &assignees, user_id).await?;
+    advance_on_completion(&mut tx, &old, &task).await?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit task move".to_string())
+    })?;
+
+    tracing::info!(
+        "Task {} moved from project {} to {}",
+        task.id,
+        old.project_id,
+        task.project_id
+    );
+
+    Ok((
+        [(header::ETAG, etag(task.version))],
+        Json(build_task_response(&state.db, task).await?),
+    ))
+}
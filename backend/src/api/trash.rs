@@ -0,0 +1,226 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Json},
+    Extension,
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    api::tasks::build_task_response,
+    models::{AuthUser, CommentResponse, Task, TaskComment, TrashResponse, TrashedCommentResponse, TrashedTaskResponse},
+    services::trash::purge_at,
+    utils::{etag, AppError},
+};
+
+pub async fn get_project_trash(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<TrashResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM projects p
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE p.id = $1 AND om.user_id = $2
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check project access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this project".to_string()));
+    }
+
+    let retention_days = state.config.trash.retention_days;
+
+    let tasks = sqlx::query!(
+        r#"
+        SELECT id, title, status, deleted_at AS "deleted_at!", deleted_by
+        FROM tasks
+        WHERE project_id = $1 AND deleted_at IS NOT NULL
+        ORDER BY deleted_at DESC
+        "#,
+        project_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch trashed tasks".to_string())
+    })?
+    .into_iter()
+    .map(|t| TrashedTaskResponse {
+        id: t.id,
+        title: t.title,
+        status: t.status,
+        deleted_at: t.deleted_at,
+        deleted_by: t.deleted_by,
+        purge_at: purge_at(t.deleted_at, retention_days),
+    })
+    .collect();
+
+    let comments = sqlx::query!(
+        r#"
+        SELECT c.id, c.task_id, t.title AS task_title, c.user_id, c.content,
+               c.deleted_at AS "deleted_at!", c.deleted_by
+        FROM task_comments c
+        INNER JOIN tasks t ON c.task_id = t.id
+        WHERE t.project_id = $1 AND t.deleted_at IS NULL AND c.deleted_at IS NOT NULL
+        ORDER BY c.deleted_at DESC
+        "#,
+        project_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch trashed comments".to_string())
+    })?
+    .into_iter()
+    .map(|c| TrashedCommentResponse {
+        id: c.id,
+        task_id: c.task_id,
+        task_title: c.task_title,
+        user_id: c.user_id,
+        content: c.content,
+        deleted_at: c.deleted_at,
+        deleted_by: c.deleted_by,
+        purge_at: purge_at(c.deleted_at, retention_days),
+    })
+    .collect();
+
+    Ok(Json(TrashResponse { tasks, comments }))
+}
+
+/// Bring a trashed task back, with its comments, to its original place on the board
+pub async fn restore_task(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Check access
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NOT NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::NotFound("Task not found in trash".to_string()));
+    }
+
+    // The task kept its rank while trashed, so it returns to the same spot
+    let task = sqlx::query_as!(
+        Task,
+        r#"
+        UPDATE tasks
+        SET deleted_at = NULL, deleted_by = NULL, version = version + 1, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, title, description, status, priority, assigned_to, created_by, due_date, original_estimate_seconds, remaining_estimate_seconds, completed_at, rank, number, version, created_at, updated_at
+        "#,
+        task_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to restore task".to_string())
+    })?;
+
+    tracing::info!("Task restored: {}", task.id);
+
+    Ok((
+        [(header::ETAG, etag(task.version))],
+        Json(build_task_response(&state.db, task).await?),
+    ))
+}
+
+/// Restore a comment deleted on its own. Same rules as deleting it.
+pub async fn restore_comment(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(comment_id): Path<Uuid>,
+) -> Result<Json<CommentResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let access = sqlx::query!(
+        r#"
+        SELECT c.user_id, om.role, t.deleted_at AS task_deleted_at
+        FROM task_comments c
+        INNER JOIN tasks t ON c.task_id = t.id
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE c.id = $1 AND om.user_id = $2 AND c.deleted_at IS NOT NULL
+        "#,
+        comment_id,
+        user_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check comment access".to_string())
+    })?
+    .ok_or_else(|| AppError::NotFound("Comment not found in trash".to_string()))?;
+
+    if access.user_id != user_id && access.role != "owner" && access.role != "admin" {
+        return Err(AppError::Authorization(
+            "Only the author or an organization admin can restore this comment".to_string(),
+        ));
+    }
+    if access.task_deleted_at.is_some() {
+        return Err(AppError::Conflict(
+            "The comment's task is in the trash; restore the task first".to_string(),
+        ));
+    }
+
+    let comment = sqlx::query_as!(
+        TaskComment,
+        r#"
+        UPDATE task_comments
+        SET deleted_at = NULL, deleted_by = NULL
+        WHERE id = $1
+        RETURNING id, task_id, user_id, content, created_at, updated_at
+        "#,
+        comment_id
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to restore comment".to_string())
+    })?;
+
+    tracing::info!("Comment restored: {}", comment.id);
+
+    Ok(Json(comment.into()))
+}
@@ -0,0 +1,318 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    api::{
+        custom_fields::{fetch_project_field, is_project_admin},
+        tasks::task_sort_key,
+    },
+    models::{AuthUser, CreateSavedViewRequest, SavedView, SavedViewResponse, UpdateSavedViewRequest},
+    services::{pagination::SortDirection, task_query},
+    utils::AppError,
+};
+
+const LAYOUTS: [&str; 3] = ["board", "list", "calendar"];
+const GROUPINGS: [&str; 5] = ["status", "priority", "assignee", "label", "due_date"];
+
+/// Treat blank strings as "not set"
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn validate_name(name: &str) -> Result<String, AppError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(AppError::Validation(
+            "View name must be between 1 and 100 characters".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Check that the stored settings will produce a valid task listing
+async fn validate_settings(
+    db: &sqlx::PgPool,
+    project_id: Uuid,
+    view: &SavedView,
+) -> Result<(), AppError> {
+    if let Some(ref filter) = view.filter {
+        task_query::parse(filter)?;
+    }
+
+    match view.sort.as_deref() {
+        Some(sort) if sort.starts_with("custom_field:") => {
+            let field_id = sort
+                .strip_prefix("custom_field:")
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .ok_or_else(|| AppError::Validation(format!("Invalid sort: {}", sort)))?;
+            fetch_project_field(db, project_id, field_id).await?;
+        }
+        sort => {
+            task_sort_key(sort)?;
+        }
+    }
+
+    SortDirection::parse(Some(&view.sort_order))?;
+
+    if let Some(ref group_by) = view.group_by {
+        if !GROUPINGS.contains(&group_by.as_str()) {
+            return Err(AppError::Validation(format!(
+                "group_by must be one of: {}",
+                GROUPINGS.join(", ")
+            )));
+        }
+    }
+
+    if !LAYOUTS.contains(&view.layout.as_str()) {
+        return Err(AppError::Validation(format!(
+            "layout must be one of: {}",
+            LAYOUTS.join(", ")
+        )));
+    }
+
+    Ok(())
+}
+
+/// Load a view the user may see: their own, or one shared in a project they can access
+async fn fetch_visible_view(
+    db: &sqlx::PgPool,
+    view_id: Uuid,
+    user_id: Uuid,
+) -> Result<SavedView, AppError> {
+    let view = sqlx::query_as!(
+        SavedView,
+        r#"
+        SELECT sv.id, sv.project_id, sv.owner_id, sv.name, sv.filter, sv.sort, sv.sort_order, sv.group_by, sv.layout, sv.is_shared, sv.created_at, sv.updated_at
+        FROM saved_views sv
+        INNER JOIN projects p ON sv.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE sv.id = $1 AND om.user_id = $2 AND (sv.owner_id = $2 OR sv.is_shared)
+        "#,
+        view_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch view".to_string())
+    })?;
+
+    view.ok_or_else(|| AppError::NotFound("View not found".to_string()))
+}
+
+/// Owners manage their views; project admins may also manage shared ones
+async fn check_can_manage(
+    db: &sqlx::PgPool,
+    view: &SavedView,
+    user_id: Uuid,
+) -> Result<(), AppError> {
+    if view.owner_id == user_id {
+        return Ok(());
+    }
+    if view.is_shared && is_project_admin(db, view.project_id, user_id).await? {
+        return Ok(());
+    }
+    Err(AppError::Authorization(
+        "Only the owner or a project admin can change this view".to_string(),
+    ))
+}
+
+pub async fn create_saved_view(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+    Json(payload): Json<CreateSavedViewRequest>,
+) -> Result<(StatusCode, Json<SavedViewResponse>), AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    // Fails when the user cannot access the project
+    is_project_admin(&state.db, project_id, user_id).await?;
+
+    let now = chrono::Utc::now();
+    let view = SavedView {
+        id: Uuid::new_v4(),
+        project_id,
+        owner_id: user_id,
+        name: validate_name(&payload.name)?,
+        filter: non_empty(payload.filter),
+        sort: non_empty(payload.sort),
+        sort_order: payload.sort_order.unwrap_or_else(|| "asc".to_string()),
+        group_by: non_empty(payload.group_by),
+        layout: payload.layout.unwrap_or_else(|| "board".to_string()),
+        is_shared: payload.is_shared.unwrap_or(false),
+        created_at: now,
+        updated_at: now,
+    };
+    validate_settings(&state.db, project_id, &view).await?;
+
+    let view = sqlx::query_as!(
+        SavedView,
+        r#"
+        INSERT INTO saved_views (id, project_id, owner_id, name, filter, sort, sort_order, group_by, layout, is_shared, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+        RETURNING id, project_id, owner_id, name, filter, sort, sort_order, group_by, layout, is_shared, created_at, updated_at
+        "#,
+        view.id,
+        view.project_id,
+        view.owner_id,
+        view.name,
+        view.filter,
+        view.sort,
+        view.sort_order,
+        view.group_by,
+        view.layout,
+        view.is_shared,
+        view.created_at,
+        view.updated_at
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to create view".to_string())
+    })?;
+
+    tracing::info!("Saved view created: {} in project {}", view.id, project_id);
+
+    Ok((StatusCode::CREATED, Json(view.into())))
+}
+
+/// The user's own views followed by views shared with the project
+pub async fn get_project_saved_views(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(project_id): Path<Uuid>,
+) -> Result<Json<Vec<SavedViewResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    is_project_admin(&state.db, project_id, user_id).await?;
+
+    let views = sqlx::query_as!(
+        SavedView,
+        r#"
+        SELECT id, project_id, owner_id, name, filter, sort, sort_order, group_by, layout, is_shared, created_at, updated_at
+        FROM saved_views
+        WHERE project_id = $1 AND (owner_id = $2 OR is_shared)
+        ORDER BY owner_id = $2 DESC, LOWER(name) ASC, id ASC
+        "#,
+        project_id,
+        user_id
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch views".to_string())
+    })?;
+
+    Ok(Json(views.into_iter().map(SavedViewResponse::from).collect()))
+}
+
+pub async fn get_saved_view(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(view_id): Path<Uuid>,
+) -> Result<Json<SavedViewResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let view = fetch_visible_view(&state.db, view_id, user_id).await?;
+
+    Ok(Json(view.into()))
+}
+
+pub async fn update_saved_view(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(view_id): Path<Uuid>,
+    Json(payload): Json<UpdateSavedViewRequest>,
+) -> Result<Json<SavedViewResponse>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let mut view = fetch_visible_view(&state.db, view_id, user_id).await?;
+    check_can_manage(&state.db, &view, user_id).await?;
+
+    if let Some(name) = payload.name {
+        view.name = validate_name(&name)?;
+    }
+    if payload.filter.is_some() {
+        view.filter = non_empty(payload.filter);
+    }
+    if payload.sort.is_some() {
+        view.sort = non_empty(payload.sort);
+    }
+    if let Some(sort_order) = payload.sort_order {
+        view.sort_order = sort_order;
+    }
+    if payload.group_by.is_some() {
+        view.group_by = non_empty(payload.group_by);
+    }
+    if let Some(layout) = payload.layout {
+        view.layout = layout;
+    }
+    if let Some(is_shared) = payload.is_shared {
+        view.is_shared = is_shared;
+    }
+    validate_settings(&state.db, view.project_id, &view).await?;
+
+    let view = sqlx::query_as!(
+        SavedView,
+        r#"
+        UPDATE saved_views
+        SET name = $2, filter = $3, sort = $4, sort_order = $5, group_by = $6, layout = $7, is_shared = $8, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, project_id, owner_id, name, filter, sort, sort_order, group_by, layout, is_shared, created_at, updated_at
+        "#,
+        view.id,
+        view.name,
+        view.filter,
+        view.sort,
+        view.sort_order,
+        view.group_by,
+        view.layout,
+        view.is_shared
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to update view".to_string())
+    })?;
+
+    Ok(Json(view.into()))
+}
+
+pub async fn delete_saved_view(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(view_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    let view = fetch_visible_view(&state.db, view_id, user_id).await?;
+    check_can_manage(&state.db, &view, user_id).await?;
+
+    sqlx::query!("DELETE FROM saved_views WHERE id = $1", view.id)
+        .execute(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to delete view".to_string())
+        })?;
+
+    tracing::info!("Saved view deleted: {}", view.id);
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,178 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use sqlx::{PgConnection, PgPool};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    models::{AuthUser, TaskWatcherResponse},
+    utils::AppError,
+};
+
+/// Make the users watch a task; users already watching are left alone
+pub(crate) async fn add_watchers(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    user_ids: &[Uuid],
+) -> Result<(), AppError> {
+    sqlx::query!(
+        r#"
+        INSERT INTO task_watchers (task_id, user_id)
+        SELECT $1, UNNEST($2::uuid[])
+        ON CONFLICT DO NOTHING
+        "#,
+        task_id,
+        user_ids
+    )
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to add watchers".to_string())
+    })?;
+
+    Ok(())
+}
+
+/// Watchers of the given tasks, earliest first
+pub(crate) async fn watchers_for_tasks(
+    db: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<Uuid>>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT task_id, user_id
+        FROM task_watchers
+        WHERE task_id = ANY($1)
+        ORDER BY task_id, created_at, user_id
+        "#,
+        task_ids
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch watchers".to_string())
+    })?;
+
+    let mut watchers: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for row in rows {
+        watchers.entry(row.task_id).or_default().push(row.user_id);
+    }
+    Ok(watchers)
+}
+
+async fn ensure_task_access(db: &PgPool, task_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let access = sqlx::query!(
+        r#"
+        SELECT om.id
+        FROM tasks t
+        INNER JOIN projects p ON t.project_id = p.id
+        INNER JOIN organization_members om ON p.organization_id = om.organization_id
+        WHERE t.id = $1 AND om.user_id = $2 AND t.deleted_at IS NULL
+        "#,
+        task_id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to check task access".to_string())
+    })?;
+
+    if access.is_none() {
+        return Err(AppError::Authorization("You don't have access to this task".to_string()));
+    }
+    Ok(())
+}
+
+async fn list_watchers(db: &PgPool, task_id: Uuid) -> Result<Vec<TaskWatcherResponse>, AppError> {
+    sqlx::query_as!(
+        TaskWatcherResponse,
+        r#"
+        SELECT tw.user_id, u.email AS user_email, u.first_name AS user_first_name, u.last_name AS user_last_name, tw.created_at
+        FROM task_watchers tw
+        INNER JOIN users u ON u.id = tw.user_id
+        WHERE tw.task_id = $1
+        ORDER BY tw.created_at, tw.user_id
+        "#,
+        task_id
+    )
+    .fetch_all(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to fetch watchers".to_string())
+    })
+}
+
+pub async fn get_task_watchers(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskWatcherResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    ensure_task_access(&state.db, task_id, user_id).await?;
+
+    Ok(Json(list_watchers(&state.db, task_id).await?))
+}
+
+/// Start watching a task as the current user; answers with all watchers
+pub async fn watch_task(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<TaskWatcherResponse>>, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    ensure_task_access(&state.db, task_id, user_id).await?;
+
+    sqlx::query!(
+        "INSERT INTO task_watchers (task_id, user_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        task_id,
+        user_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to add watcher".to_string())
+    })?;
+
+    Ok(Json(list_watchers(&state.db, task_id).await?))
+}
+
+/// Stop watching a task as the current user
+pub async fn unwatch_task(
+    State(state): State<AppState>,
+    Extension(auth_user): Extension<AuthUser>,
+    Path(task_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id = Uuid::parse_str(&auth_user.user_id)
+        .map_err(|_| AppError::Authentication("Invalid user ID".to_string()))?;
+
+    ensure_task_access(&state.db, task_id, user_id).await?;
+
+    sqlx::query!(
+        "DELETE FROM task_watchers WHERE task_id = $1 AND user_id = $2",
+        task_id,
+        user_id
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to remove watcher".to_string())
+    })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -9,6 +9,9 @@ pub struct Config {
     pub jwt: JwtConfig,
     pub storage: StorageConfig,
     pub cors: CorsConfig,
+    pub trash: TrashConfig,
+    pub recurrence: RecurrenceConfig,
+    pub scanner: ScannerConfig,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -36,6 +39,7 @@ pub struct JwtConfig {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StorageConfig {
+    pub backend: String, // "local" or "s3"
     pub s3_endpoint: String,
     pub s3_access_key: String,
     pub s3_secret_key: String,
@@ -43,6 +47,12 @@ pub struct StorageConfig {
     pub s3_region: String,
     pub max_file_size: usize,
     pub upload_dir: String,
+    pub presign_expiry: u64, // in seconds
+    pub org_quota: i64, // in bytes, 0 for unlimited
+    pub gc_interval: u64, // in seconds
+    pub gc_grace_period: i64, // in seconds
+    pub thumbnail_size: u32, // longest side, in pixels
+    pub thumbnail_interval: u64, // in seconds
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -50,6 +60,26 @@ pub struct CorsConfig {
     pub origin: String,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrashConfig {
+    pub retention_days: i64,
+    pub purge_interval: u64, // in seconds
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScannerConfig {
+    pub backend: String, // "none" or "clamd"
+    pub clamd_address: String,
+    pub timeout: u64, // in seconds
+    pub interval: u64, // in seconds
+    pub max_size: u64, // in bytes, 0 for unlimited
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecurrenceConfig {
+    pub scheduler_interval: u64, // in seconds
+}
+
 impl Config {
     pub fn from_env() -> Result<Self, config::ConfigError> {
         dotenvy::dotenv().ok();
@@ -86,6 +116,8 @@ impl Config {
         };
 
         let storage = StorageConfig {
+            backend: env::var("STORAGE_BACKEND")
+                .unwrap_or_else(|_| "local".to_string()),
             s3_endpoint: env::var("S3_ENDPOINT")
                 .unwrap_or_else(|_| "http://localhost:9000".to_string()),
             s3_access_key: env::var("S3_ACCESS_KEY")
@@ -102,6 +134,30 @@ impl Config {
                 .unwrap_or(10485760),
             upload_dir: env::var("UPLOAD_DIR")
                 .unwrap_or_else(|_| "./uploads".to_string()),
+            presign_expiry: env::var("STORAGE_PRESIGN_EXPIRY")
+                .unwrap_or_else(|_| "900".to_string())
+                .parse()
+                .unwrap_or(900),
+            org_quota: env::var("STORAGE_ORG_QUOTA")
+                .unwrap_or_else(|_| "1073741824".to_string())
+                .parse()
+                .unwrap_or(1073741824),
+            gc_interval: env::var("STORAGE_GC_INTERVAL")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+            gc_grace_period: env::var("STORAGE_GC_GRACE_PERIOD")
+                .unwrap_or_else(|_| "86400".to_string())
+                .parse()
+                .unwrap_or(86400),
+            thumbnail_size: env::var("THUMBNAIL_SIZE")
+                .unwrap_or_else(|_| "256".to_string())
+                .parse()
+                .unwrap_or(256),
+            thumbnail_interval: env::var("THUMBNAIL_INTERVAL")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
         };
 
         let cors = CorsConfig {
@@ -109,6 +165,43 @@ impl Config {
                 .unwrap_or_else(|_| "http://localhost:8080".to_string()),
         };
 
+        let trash = TrashConfig {
+            retention_days: env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .unwrap_or(30),
+            purge_interval: env::var("TRASH_PURGE_INTERVAL")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .unwrap_or(3600),
+        };
+
+        let recurrence = RecurrenceConfig {
+            scheduler_interval: env::var("RECURRENCE_SCHEDULER_INTERVAL")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+        };
+
+        let scanner = ScannerConfig {
+            backend: env::var("SCANNER_BACKEND")
+                .unwrap_or_else(|_| "none".to_string()),
+            clamd_address: env::var("CLAMD_ADDRESS")
+                .unwrap_or_else(|_| "localhost:3310".to_string()),
+            timeout: env::var("SCANNER_TIMEOUT")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .unwrap_or(60),
+            interval: env::var("SCANNER_INTERVAL")
+                .unwrap_or_else(|_| "5".to_string())
+                .parse()
+                .unwrap_or(5),
+            max_size: env::var("SCANNER_MAX_SIZE")
+                .unwrap_or_else(|_| "26214400".to_string())
+                .parse()
+                .unwrap_or(26214400),
+        };
+
         Ok(Config {
             server,
             database,
@@ -116,6 +209,9 @@ impl Config {
             jwt,
             storage,
             cors,
+            trash,
+            recurrence,
+            scanner,
         })
     }
 }
@@ -1,5 +1,6 @@
 use axum::{
-    routing::{get, post, put, delete},
+    extract::DefaultBodyLimit,
+    routing::{get, post, put, patch, delete},
     Router,
     response::Json,
     middleware as axum_middleware,
@@ -24,6 +25,7 @@ use config::Config;
 pub struct AppState {
     pub db: sqlx::PgPool,
     pub config: Arc<Config>,
+    pub storage: Arc<dyn services::storage::StorageBackend>,
 }
 
 #[tokio::main]
@@ -55,10 +57,34 @@ async fn main() {
     
     tracing::info!("✅ Database connected");
 
+    // Purge trashed tasks and comments once their retention period is over
+    services::trash::spawn_purge_job(db.clone(), config.trash.clone());
+
+    // Create the next occurrence of recurring tasks as they fall due
+    api::recurrence::spawn_scheduler(db.clone(), config.recurrence.clone());
+
+    // Attachments go to local disk or an S3-compatible bucket
+    let storage = services::storage::from_config(&config.storage)
+        .expect("Failed to configure file storage");
+    tracing::info!("📦 File storage: {}", config.storage.backend);
+
+    // Remove attachment blobs no attachment refers to any more
+    services::blobs::spawn_gc_job(db.clone(), storage.clone(), config.storage.clone());
+
+    // Render thumbnails of uploaded images
+    services::thumbnails::spawn_thumbnail_job(db.clone(), storage.clone(), config.storage.clone());
+
+    // Scan uploads for malware before they can be downloaded
+    let scanner = services::scanner::from_config(&config.scanner)
+        .expect("Failed to configure attachment scanner");
+    tracing::info!("🛡️ Attachment scanner: {}", config.scanner.backend);
+    services::scans::spawn_scan_job(db.clone(), storage.clone(), scanner, config.scanner.clone());
+
     // Create app state
     let state = AppState {
         db: db.clone(),
         config: config.clone(),
+        storage,
     };
 
     // Configure CORS
@@ -70,14 +96,62 @@ async fn main() {
     // Build application routes
     let protected_routes = Router::new()
         .route("/api/auth/me", get(api::me))
+        .route("/api/me/tasks", get(api::get_my_tasks))
         .route("/api/organizations", post(api::create_organization).get(api::get_my_organizations))
         .route("/api/organizations/:org_id", get(api::get_organization))
         .route("/api/organizations/:org_id/members", get(api::get_organization_members))
+        .route("/api/organizations/:org_id/labels", post(api::create_organization_label).get(api::get_organization_labels))
+        .route("/api/organizations/:org_id/search", get(api::search_organization))
         .route("/api/organizations/:org_id/projects", post(api::create_project).get(api::get_organization_projects))
-        .route("/api/projects/:project_id", get(api::get_project).put(api::update_project).delete(api::delete_project))
+        .route("/api/projects/:project_id", get(api::get_project).put(api::update_project).patch(api::update_project).delete(api::delete_project))
         .route("/api/projects/:project_id/tasks", post(api::create_task).get(api::get_project_tasks))
-        .route("/api/tasks/:task_id", get(api::get_task).put(api::update_task).delete(api::delete_task))
+        .route("/api/projects/:project_id/labels", post(api::create_project_label).get(api::get_project_labels))
+        .route("/api/projects/:project_id/custom-fields", post(api::create_custom_field).get(api::get_project_custom_fields))
+        .route("/api/projects/:project_id/views", post(api::create_saved_view).get(api::get_project_saved_views))
+        .route("/api/projects/:project_id/dependency-graph", get(api::get_project_dependency_graph))
+        .route("/api/projects/:project_id/trash", get(api::get_project_trash))
+        .route("/api/projects/:project_id/time-summary", get(api::get_project_time_summary))
+        .route("/api/tasks/bulk", post(api::bulk_update_tasks))
+        .route("/api/tasks/by-key/:key", get(api::get_task_by_key))
+        .route("/api/tasks/:task_id", get(api::get_task).put(api::update_task).patch(api::update_task).delete(api::delete_task))
+        .route("/api/tasks/:task_id/move", post(api::move_task))
+        .route("/api/tasks/:task_id/transfer", post(api::transfer_task))
+        .route("/api/tasks/:task_id/restore", post(api::restore_task))
+        .route("/api/tasks/:task_id/history", get(api::get_task_history))
+        .route("/api/tasks/:task_id/recurrence", get(api::get_task_recurrence).put(api::set_task_recurrence).delete(api::delete_task_recurrence))
+        .route("/api/tasks/:task_id/timer", post(api::start_timer))
+        .route("/api/tasks/:task_id/time-logs", post(api::create_time_log).get(api::get_task_time_logs))
+        .route(
+            "/api/tasks/:task_id/attachments",
+            post(api::upload_attachments)
+                .get(api::get_task_attachments)
+                .layer(DefaultBodyLimit::max(api::attachments::upload_body_limit(
+                    config.storage.max_file_size,
+                ))),
+        )
+        .route("/api/tasks/:task_id/watchers", get(api::get_task_watchers))
+        .route("/api/tasks/:task_id/watch", post(api::watch_task).delete(api::unwatch_task))
+        .route("/api/tasks/:task_id/checklist", post(api::create_checklist_item).get(api::get_checklist))
         .route("/api/tasks/:task_id/comments", post(api::create_comment).get(api::get_task_comments))
+        .route("/api/tasks/:task_id/labels/:label_id", post(api::add_task_label).delete(api::remove_task_label))
+        .route("/api/tasks/:task_id/custom-fields/:field_id", put(api::set_task_custom_field_value))
+        .route("/api/tasks/:task_id/dependencies", post(api::create_dependency).get(api::get_task_dependencies))
+        .route("/api/tasks/:task_id/dependencies/:dependency_id", delete(api::delete_dependency))
+        .route("/api/comments/:comment_id", delete(api::delete_comment))
+        .route("/api/attachments/:attachment_id", delete(api::delete_attachment))
+        .route("/api/attachments/:attachment_id/download", get(api::download_attachment))
+        .route("/api/attachments/:attachment_id/link", get(api::get_attachment_link))
+        .route("/api/attachments/:attachment_id/thumbnail", get(api::get_attachment_thumbnail))
+        .route("/api/checklist-items/:item_id", patch(api::update_checklist_item).delete(api::delete_checklist_item))
+        .route("/api/checklist-items/:item_id/move", post(api::move_checklist_item))
+        .route("/api/time-logs/:log_id", delete(api::delete_time_log))
+        .route("/api/timer", get(api::get_running_timer))
+        .route("/api/timer/stop", post(api::stop_timer))
+        .route("/api/timesheet", get(api::get_timesheet))
+        .route("/api/comments/:comment_id/restore", post(api::restore_comment))
+        .route("/api/labels/:label_id", put(api::update_label).delete(api::delete_label))
+        .route("/api/views/:view_id", get(api::get_saved_view).put(api::update_saved_view).delete(api::delete_saved_view))
+        .route("/api/custom-fields/:field_id", put(api::update_custom_field).delete(api::delete_custom_field))
         .route_layer(axum_middleware::from_fn_with_state(
             state.clone(),
             crate::middleware::auth_middleware,
@@ -1,8 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::utils::Patch;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct User {
     pub id: Uuid,
@@ -48,9 +51,12 @@ pub struct Project {
     pub organization_id: Uuid,
     pub name: String,
     pub slug: String,
+    pub key: String,
     pub description: Option<String>,
     pub status: String,
     pub color: Option<String>,
+    pub enforce_dependencies: bool,
+    pub version: i32,
     pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -77,8 +83,57 @@ pub struct Task {
     pub assigned_to: Option<Uuid>,
     pub created_by: Uuid,
     pub due_date: Option<DateTime<Utc>>,
+    pub original_estimate_seconds: Option<i32>,
+    pub remaining_estimate_seconds: Option<i32>,
     pub completed_at: Option<DateTime<Utc>>,
-    pub position: i32,
+    pub rank: String,
+    pub number: i32,
+    pub version: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaskRecurrence {
+    pub id: Uuid,
+    /// The newest occurrence, which the next one is created from
+    pub task_id: Uuid,
+    pub rule: String,
+    pub timezone: String,
+    pub starts_at: DateTime<Utc>,
+    pub occurrence_index: i32,
+    pub current_due_at: DateTime<Utc>,
+    pub next_due_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Time a user spent on a task; a log without an end is a running timer
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TimeLog {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub checked: bool,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub checked_by: Option<Uuid>,
+    pub assigned_to: Option<Uuid>,
+    pub rank: String,
+    pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -105,6 +160,57 @@ pub struct TaskAttachment {
     pub created_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaskDependency {
+    pub id: Uuid,
+    pub blocking_task_id: Uuid,
+    pub blocked_task_id: Uuid,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Label {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub name: String,
+    pub color: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CustomField {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub field_type: String,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    pub position: i32,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SavedView {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: String,
+    pub group_by: Option<String>,
+    pub layout: String,
+    pub is_shared: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum MemberRole {
     Owner,
@@ -206,17 +312,23 @@ impl From<Organization> for OrganizationResponse {
 pub struct CreateProjectRequest {
     pub name: String,
     pub slug: String,
+    /// Prefix of task keys such as `WEB-123`; derived from the name when absent
+    pub key: Option<String>,
     pub description: Option<String>,
     pub status: Option<String>,
     pub color: Option<String>,
+    pub enforce_dependencies: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Merge patch: absent fields are unchanged, `null` clears `description` and `color`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
 pub struct UpdateProjectRequest {
-    pub name: Option<String>,
-    pub description: Option<String>,
-    pub status: Option<String>,
-    pub color: Option<String>,
+    pub name: Patch<String>,
+    pub description: Patch<String>,
+    pub status: Patch<String>,
+    pub color: Patch<String>,
+    pub enforce_dependencies: Patch<bool>,
 }
 
 #[derive(Debug, Serialize)]
@@ -225,14 +337,31 @@ pub struct ProjectResponse {
     pub organization_id: Uuid,
     pub name: String,
     pub slug: String,
+    pub key: String,
     pub description: Option<String>,
     pub status: String,
     pub color: Option<String>,
+    pub enforce_dependencies: bool,
+    pub version: i32,
     pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ProjectListQuery {
+    /// Comma-separated statuses
+    pub status: Option<String>,
+    /// Case-insensitive match on name and description
+    pub q: Option<String>,
+    /// `created_at` (default), `updated_at` or `name`
+    pub sort: Option<String>,
+    /// `desc` (default) or `asc`
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
 impl From<Project> for ProjectResponse {
     fn from(project: Project) -> Self {
         ProjectResponse {
@@ -240,9 +369,12 @@ impl From<Project> for ProjectResponse {
             organization_id: project.organization_id,
             name: project.name,
             slug: project.slug,
+            key: project.key,
             description: project.description,
             status: project.status,
             color: project.color,
+            enforce_dependencies: project.enforce_dependencies,
+            version: project.version,
             created_by: project.created_by,
             created_at: project.created_at,
             updated_at: project.updated_at,
@@ -258,33 +390,177 @@ pub struct CreateTaskRequest {
     pub status: Option<String>,
     pub priority: Option<String>,
     pub assigned_to: Option<Uuid>,
+    /// Every assignee, the first one primary; an alternative to `assigned_to`
+    pub assignee_ids: Option<Vec<Uuid>>,
     pub due_date: Option<DateTime<Utc>>,
+    /// The remaining estimate starts out equal to this
+    pub original_estimate_seconds: Option<i32>,
+    /// Initial custom field values keyed by field ID
+    pub custom_fields: Option<HashMap<Uuid, serde_json::Value>>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Merge patch: absent fields are unchanged, `null` clears `description`,
+/// `assigned_to`, `due_date` and the estimates
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
 pub struct UpdateTaskRequest {
-    pub title: Option<String>,
-    pub description: Option<String>,
+    pub title: Patch<String>,
+    pub description: Patch<String>,
+    pub status: Patch<String>,
+    pub priority: Patch<String>,
+    /// Replaces all assignees with this one user
+    pub assigned_to: Patch<Uuid>,
+    /// Replaces all assignees, the first one primary
+    pub assignee_ids: Option<Vec<Uuid>>,
+    pub due_date: Patch<DateTime<Utc>>,
+    pub original_estimate_seconds: Patch<i32>,
+    pub remaining_estimate_seconds: Patch<i32>,
+}
+
+/// Place a task between two neighbors of the target column. With one neighbor
+/// the task goes right next to it; with neither it goes to the end.
+#[derive(Debug, Deserialize)]
+pub struct MoveTaskRequest {
+    /// Defaults to the task's current status
+    pub status: Option<String>,
+    /// The task that will sit directly above the moved one
+    pub before_id: Option<Uuid>,
+    /// The task that will sit directly below the moved one
+    pub after_id: Option<Uuid>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct TaskListQuery {
+    /// Comma-separated statuses
     pub status: Option<String>,
+    /// Comma-separated priorities
     pub priority: Option<String>,
-    pub assigned_to: Option<Uuid>,
-    pub due_date: Option<DateTime<Utc>>,
-    pub position: Option<i32>,
+    /// User ID, `me` or `none`
+    pub assigned_to: Option<String>,
+    /// User ID or `me`
+    pub created_by: Option<String>,
+    pub due_after: Option<DateTime<Utc>>,
+    pub due_before: Option<DateTime<Utc>>,
+    /// `true` for completed tasks only, `false` for open tasks only
+    pub completed: Option<bool>,
+    /// Case-insensitive match on title and description
+    pub q: Option<String>,
+    pub updated_since: Option<DateTime<Utc>>,
+    /// Comma-separated label IDs; only tasks carrying all of them are returned
+    pub labels: Option<String>,
+    /// Advanced filter expression, e.g. `assignee = me AND due < +7d`
+    pub filter: Option<String>,
+    /// Client's offset from UTC in minutes, used for dates in `filter`
+    pub tz_offset: Option<i32>,
+    /// Custom field to filter on, together with `custom_value`
+    pub custom_field: Option<Uuid>,
+    pub custom_value: Option<String>,
+    /// `rank` (default, the manual board order), `created_at`, `updated_at`, `due_date`, `priority`,
+    /// `title` or `custom_field:<field_id>`
+    pub sort: Option<String>,
+    /// `asc` (default) or `desc`
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+/// A page of results with the cursor to fetch the next one
+#[derive(Debug, Serialize)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MyTasksQuery {
+    /// Only tasks from this organization
+    pub org_id: Option<Uuid>,
+    /// Comma-separated statuses
+    pub status: Option<String>,
+    /// Comma-separated priorities
+    pub priority: Option<String>,
+    /// Include completed tasks (default false)
+    pub include_completed: Option<bool>,
+    /// Client's offset from UTC in minutes, used to decide what "today" is
+    pub tz_offset: Option<i32>,
+}
+
+/// An assigned task together with where it lives
+#[derive(Debug, Serialize)]
+pub struct MyTaskResponse {
+    #[serde(flatten)]
+    pub task: TaskResponse,
+    pub project_name: String,
+    pub organization_id: Uuid,
+    pub organization_name: String,
+}
+
+/// Tasks assigned to the current user grouped by due date
+#[derive(Debug, Default, Serialize)]
+pub struct MyTasksResponse {
+    pub overdue: Vec<MyTaskResponse>,
+    pub today: Vec<MyTaskResponse>,
+    pub this_week: Vec<MyTaskResponse>,
+    pub later: Vec<MyTaskResponse>,
+    pub no_due_date: Vec<MyTaskResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    /// Search text; supports quoted phrases, `or` and `-` exclusions
+    pub q: String,
+    /// Comma-separated kinds: `task`, `comment`, `project` (default all)
+    pub types: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+/// A ranked search hit. `snippet` marks matched terms with `<mark>` and is not
+/// otherwise escaped, so clients must render it as text.
+#[derive(Debug, Serialize, FromRow)]
+pub struct SearchResult {
+    pub kind: String,
+    pub id: Uuid,
+    pub title: String,
+    pub snippet: String,
+    pub rank: f32,
+    pub project_id: Uuid,
+    pub project_name: String,
+    /// The task a task or comment hit belongs to
+    pub task_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct TaskResponse {
     pub id: Uuid,
+    /// Human-readable key such as `WEB-123`
+    pub key: String,
     pub project_id: Uuid,
     pub title: String,
     pub description: Option<String>,
     pub status: String,
     pub priority: String,
+    /// The primary assignee, also listed first in `assignees`
     pub assigned_to: Option<Uuid>,
+    pub assignees: Vec<Uuid>,
+    pub watchers: Vec<Uuid>,
     pub created_by: Uuid,
     pub due_date: Option<DateTime<Utc>>,
+    pub original_estimate_seconds: Option<i32>,
+    pub remaining_estimate_seconds: Option<i32>,
+    /// Sum of the finished time logs
+    pub time_spent_seconds: i64,
+    /// Absent when the task has no checklist
+    pub checklist_progress: Option<ChecklistProgress>,
     pub completed_at: Option<DateTime<Utc>>,
-    pub position: i32,
+    pub rank: String,
+    pub number: i32,
+    pub version: i32,
+    /// Recurrence rule when this task is the newest occurrence of a series
+    pub recurrence: Option<String>,
+    pub is_blocked: bool,
+    pub labels: Vec<LabelResponse>,
+    pub custom_fields: Vec<CustomFieldValueResponse>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -293,22 +569,493 @@ impl From<Task> for TaskResponse {
     fn from(task: Task) -> Self {
         TaskResponse {
             id: task.id,
+            key: String::new(),
             project_id: task.project_id,
             title: task.title,
             description: task.description,
             status: task.status,
             priority: task.priority,
             assigned_to: task.assigned_to,
+            assignees: Vec::new(),
+            watchers: Vec::new(),
             created_by: task.created_by,
             due_date: task.due_date,
+            original_estimate_seconds: task.original_estimate_seconds,
+            remaining_estimate_seconds: task.remaining_estimate_seconds,
+            time_spent_seconds: 0,
+            checklist_progress: None,
             completed_at: task.completed_at,
-            position: task.position,
+            rank: task.rank,
+            number: task.number,
+            version: task.version,
+            recurrence: None,
+            is_blocked: false,
+            labels: Vec::new(),
+            custom_fields: Vec::new(),
             created_at: task.created_at,
             updated_at: task.updated_at,
         }
     }
 }
 
+/// Target of a move to another project; `status` defaults to the current one
+#[derive(Debug, Deserialize)]
+pub struct TransferTaskRequest {
+    pub project_id: Uuid,
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TaskKeyQuery {
+    pub org_id: Option<Uuid>,
+}
+
+// Recurrence DTOs
+/// RRULE subset and IANA timezone; the series starts at the task's due date
+#[derive(Debug, Deserialize)]
+pub struct SetRecurrenceRequest {
+    pub rule: String,
+    pub timezone: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RecurrenceResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub rule: String,
+    pub timezone: String,
+    pub starts_at: DateTime<Utc>,
+    pub occurrence_index: i32,
+    /// Due date of the occurrence that will be created next, if the series goes on
+    pub next_due_at: Option<DateTime<Utc>>,
+}
+
+impl From<TaskRecurrence> for RecurrenceResponse {
+    fn from(recurrence: TaskRecurrence) -> Self {
+        RecurrenceResponse {
+            id: recurrence.id,
+            task_id: recurrence.task_id,
+            rule: recurrence.rule,
+            timezone: recurrence.timezone,
+            starts_at: recurrence.starts_at,
+            occurrence_index: recurrence.occurrence_index,
+            next_due_at: recurrence.next_due_at,
+        }
+    }
+}
+
+// Time tracking DTOs
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StartTimerRequest {
+    pub description: Option<String>,
+}
+
+/// Time worked without a timer, e.g. entered at the end of the day
+#[derive(Debug, Deserialize)]
+pub struct CreateTimeLogRequest {
+    pub started_at: DateTime<Utc>,
+    pub duration_seconds: i32,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimeLogResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// Absent while the timer is running
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TimeLog> for TimeLogResponse {
+    fn from(log: TimeLog) -> Self {
+        TimeLogResponse {
+            id: log.id,
+            task_id: log.task_id,
+            user_id: log.user_id,
+            description: log.description,
+            started_at: log.started_at,
+            ended_at: log.ended_at,
+            duration_seconds: log.duration_seconds,
+            created_at: log.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct UserTimeTotal {
+    pub user_id: Uuid,
+    pub time_spent_seconds: i64,
+}
+
+/// Estimates and logged time summed over a project's live tasks
+#[derive(Debug, Serialize)]
+pub struct ProjectTimeSummary {
+    pub project_id: Uuid,
+    pub original_estimate_seconds: i64,
+    pub remaining_estimate_seconds: i64,
+    pub time_spent_seconds: i64,
+    pub users: Vec<UserTimeTotal>,
+}
+
+/// Days are inclusive and interpreted in `timezone` (default UTC). `user_id`
+/// defaults to the caller; other users' timesheets need `org_id` and an owner
+/// or admin role there.
+#[derive(Debug, Deserialize)]
+pub struct TimesheetQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: Option<String>,
+    pub user_id: Option<Uuid>,
+    pub org_id: Option<Uuid>,
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct TimesheetEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub task_key: String,
+    pub task_title: String,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub duration_seconds: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TimesheetResponse {
+    pub user_id: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+    pub total_seconds: i64,
+    pub entries: Vec<TimesheetEntry>,
+}
+
+// Task history DTOs
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct TaskHistoryEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub field: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TaskHistoryQuery {
+    /// Only changes to this field, e.g. `status`
+    pub field: Option<String>,
+    /// `asc` (default) or `desc` by time of change
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+// Attachment DTOs
+#[derive(Debug, Deserialize)]
+pub struct UploadAttachmentsQuery {
+    /// Remove EXIF and similar metadata from images before storing them
+    #[serde(default)]
+    pub strip_metadata: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub filename: String,
+    pub file_size: i64,
+    pub mime_type: String,
+    /// Set once a thumbnail of an image has been rendered
+    pub thumbnail_url: Option<String>,
+    /// `pending` until scanned for malware, then `clean` or `infected`.
+    /// Only clean attachments can be downloaded.
+    pub scan_status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TaskAttachment> for AttachmentResponse {
+    fn from(attachment: TaskAttachment) -> Self {
+        AttachmentResponse {
+            id: attachment.id,
+            task_id: attachment.task_id,
+            user_id: attachment.user_id,
+            filename: attachment.filename,
+            file_size: attachment.file_size,
+            mime_type: attachment.mime_type,
+            thumbnail_url: None,
+            scan_status: "pending".to_string(),
+            created_at: attachment.created_at,
+        }
+    }
+}
+
+/// Where to fetch an attachment; `expires_at` is set for presigned links
+#[derive(Debug, Serialize)]
+pub struct AttachmentLinkResponse {
+    pub url: String,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+// Watcher DTOs
+#[derive(Debug, Serialize)]
+pub struct TaskWatcherResponse {
+    pub user_id: Uuid,
+    pub user_email: String,
+    pub user_first_name: Option<String>,
+    pub user_last_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Checklist DTOs
+#[derive(Debug, Deserialize)]
+pub struct CreateChecklistItemRequest {
+    pub content: String,
+    pub assigned_to: Option<Uuid>,
+}
+
+/// Merge patch: absent fields are unchanged, `null` clears `assigned_to`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct UpdateChecklistItemRequest {
+    pub content: Patch<String>,
+    pub checked: Patch<bool>,
+    pub assigned_to: Patch<Uuid>,
+}
+
+/// Place an item between two neighbors; with neither it goes to the end
+#[derive(Debug, Deserialize)]
+pub struct MoveChecklistItemRequest {
+    /// The item that will sit directly above the moved one
+    pub before_id: Option<Uuid>,
+    /// The item that will sit directly below the moved one
+    pub after_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChecklistItemResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub checked: bool,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub checked_by: Option<Uuid>,
+    pub assigned_to: Option<Uuid>,
+    pub rank: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ChecklistItem> for ChecklistItemResponse {
+    fn from(item: ChecklistItem) -> Self {
+        ChecklistItemResponse {
+            id: item.id,
+            task_id: item.task_id,
+            content: item.content,
+            checked: item.checked,
+            checked_at: item.checked_at,
+            checked_by: item.checked_by,
+            assigned_to: item.assigned_to,
+            rank: item.rank,
+            created_by: item.created_by,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct ChecklistProgress {
+    pub checked: i64,
+    pub total: i64,
+}
+
+// Label DTOs
+#[derive(Debug, Deserialize)]
+pub struct CreateLabelRequest {
+    pub name: String,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateLabelRequest {
+    pub name: Option<String>,
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LabelResponse {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub name: String,
+    pub color: String,
+}
+
+impl From<Label> for LabelResponse {
+    fn from(label: Label) -> Self {
+        LabelResponse {
+            id: label.id,
+            organization_id: label.organization_id,
+            project_id: label.project_id,
+            name: label.name,
+            color: label.color,
+        }
+    }
+}
+
+// Custom field DTOs
+#[derive(Debug, Deserialize)]
+pub struct CreateCustomFieldRequest {
+    pub name: String,
+    pub field_type: String,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCustomFieldRequest {
+    pub name: Option<String>,
+    pub options: Option<Vec<String>>,
+    pub is_required: Option<bool>,
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CustomFieldResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub name: String,
+    pub field_type: String,
+    pub options: Vec<String>,
+    pub is_required: bool,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<CustomField> for CustomFieldResponse {
+    fn from(field: CustomField) -> Self {
+        CustomFieldResponse {
+            id: field.id,
+            project_id: field.project_id,
+            name: field.name,
+            field_type: field.field_type,
+            options: field.options,
+            is_required: field.is_required,
+            position: field.position,
+            created_at: field.created_at,
+            updated_at: field.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetCustomFieldValueRequest {
+    /// `null` clears the value
+    pub value: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomFieldValueResponse {
+    pub field_id: Uuid,
+    pub name: String,
+    pub field_type: String,
+    pub value: serde_json::Value,
+}
+
+// Task dependency DTOs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyRelation {
+    /// The task in the path blocks `task_id`
+    Blocks,
+    /// The task in the path is blocked by `task_id`
+    BlockedBy,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDependencyRequest {
+    pub task_id: Uuid,
+    pub relation: DependencyRelation,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DependencyResponse {
+    pub id: Uuid,
+    pub blocking_task_id: Uuid,
+    pub blocked_task_id: Uuid,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<TaskDependency> for DependencyResponse {
+    fn from(dependency: TaskDependency) -> Self {
+        DependencyResponse {
+            id: dependency.id,
+            blocking_task_id: dependency.blocking_task_id,
+            blocked_task_id: dependency.blocked_task_id,
+            created_by: dependency.created_by,
+            created_at: dependency.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LinkedTaskResponse {
+    pub dependency_id: Uuid,
+    pub task_id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TaskDependenciesResponse {
+    pub blocked_by: Vec<LinkedTaskResponse>,
+    pub blocks: Vec<LinkedTaskResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DependencyGraphNode {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub is_blocked: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DependencyGraphResponse {
+    pub nodes: Vec<DependencyGraphNode>,
+    pub edges: Vec<DependencyResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentListQuery {
+    pub user_id: Option<Uuid>,
+    pub since: Option<DateTime<Utc>>,
+    /// `asc` (default) or `desc` by creation time
+    pub order: Option<String>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateCommentRequest {
     pub content: String,
@@ -337,6 +1084,137 @@ impl From<TaskComment> for CommentResponse {
     }
 }
 
+// Saved view DTOs
+#[derive(Debug, Deserialize)]
+pub struct CreateSavedViewRequest {
+    pub name: String,
+    /// Task query language expression
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: Option<String>,
+    pub group_by: Option<String>,
+    pub layout: Option<String>,
+    pub is_shared: Option<bool>,
+}
+
+/// Empty strings clear `filter`, `sort` and `group_by`
+#[derive(Debug, Deserialize)]
+pub struct UpdateSavedViewRequest {
+    pub name: Option<String>,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: Option<String>,
+    pub group_by: Option<String>,
+    pub layout: Option<String>,
+    pub is_shared: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SavedViewResponse {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: String,
+    pub group_by: Option<String>,
+    pub layout: String,
+    pub is_shared: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<SavedView> for SavedViewResponse {
+    fn from(view: SavedView) -> Self {
+        SavedViewResponse {
+            id: view.id,
+            project_id: view.project_id,
+            owner_id: view.owner_id,
+            name: view.name,
+            filter: view.filter,
+            sort: view.sort,
+            sort_order: view.sort_order,
+            group_by: view.group_by,
+            layout: view.layout,
+            is_shared: view.is_shared,
+            created_at: view.created_at,
+            updated_at: view.updated_at,
+        }
+    }
+}
+
+// Bulk task DTOs
+/// Changes applied to every listed task. `delete` moves the tasks to the trash
+/// and excludes all other changes.
+#[derive(Debug, Default, Deserialize)]
+pub struct BulkTaskChanges {
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    /// `null` unassigns
+    #[serde(default)]
+    pub assigned_to: Patch<Uuid>,
+    #[serde(default)]
+    pub add_label_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub remove_label_ids: Vec<Uuid>,
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub delete: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkTaskRequest {
+    pub task_ids: Vec<Uuid>,
+    pub changes: BulkTaskChanges,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkTaskResult {
+    pub task_id: Uuid,
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+/// Either every task was changed (`applied`) or none was; `results` says why
+#[derive(Debug, Serialize)]
+pub struct BulkTaskResponse {
+    pub applied: bool,
+    pub results: Vec<BulkTaskResult>,
+}
+
+// Trash DTOs
+#[derive(Debug, Serialize)]
+pub struct TrashedTaskResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+    /// When the task is removed for good
+    pub purge_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TrashedCommentResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub user_id: Uuid,
+    pub content: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+    pub purge_at: DateTime<Utc>,
+}
+
+/// A project's trash. Comments of trashed tasks are restored with their task,
+/// so only comments deleted on their own are listed.
+#[derive(Debug, Serialize)]
+pub struct TrashResponse {
+    pub tasks: Vec<TrashedTaskResponse>,
+    pub comments: Vec<TrashedCommentResponse>,
+}
+
 // Extractor for authenticated user ID
 #[derive(Debug, Clone)]
 pub struct AuthUser {
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::utils::{AppError, Patch};
+
+/// Most users a task can be assigned to
+pub const MAX_ASSIGNEES: usize = 20;
+
+/// The assignees a create or update asks for, primary first, or `None` when
+/// it leaves them alone. `assigned_to` is shorthand for a single assignee, or
+/// for none when `null`; it cannot be combined with `assignee_ids`.
+pub fn requested_assignees(
+    assigned_to: Patch<Uuid>,
+    assignee_ids: Option<Vec<Uuid>>,
+) -> Result<Option<Vec<Uuid>>, AppError> {
+    let ids = match (assigned_to, assignee_ids) {
+        (Patch::Missing, None) => return Ok(None),
+        (Patch::Missing, Some(ids)) => ids,
+        (Patch::Null, None) => Vec::new(),
+        (Patch::Value(id), None) => vec![id],
+        (_, Some(_)) => {
+            return Err(AppError::Validation(
+                "Set either assigned_to or assignee_ids, not both".to_string(),
+            ))
+        }
+    };
+
+    let mut unique = Vec::with_capacity(ids.len());
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    if unique.len() > MAX_ASSIGNEES {
+        return Err(AppError::Validation(format!(
+            "A task can have at most {} assignees",
+            MAX_ASSIGNEES
+        )));
+    }
+    Ok(Some(unique))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requested_assignees() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(requested_assignees(Patch::Missing, None).unwrap(), None);
+        assert_eq!(requested_assignees(Patch::Null, None).unwrap(), Some(vec![]));
+        assert_eq!(requested_assignees(Patch::Value(a), None).unwrap(), Some(vec![a]));
+        assert_eq!(
+            requested_assignees(Patch::Missing, Some(vec![b, a, b])).unwrap(),
+            Some(vec![b, a])
+        );
+        assert!(requested_assignees(Patch::Value(a), Some(vec![b])).is_err());
+        assert!(requested_assignees(Patch::Null, Some(vec![])).is_err());
+
+        let many: Vec<Uuid> = (0..=MAX_ASSIGNEES).map(|_| Uuid::new_v4()).collect();
+        assert!(requested_assignees(Patch::Missing, Some(many)).is_err());
+    }
+}
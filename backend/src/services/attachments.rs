@@ -0,0 +1,172 @@
+use crate::utils::AppError;
+
+/// Most files a single upload request may carry
+pub const MAX_FILES_PER_UPLOAD: usize = 10;
+
+/// Longest stored filename, in characters
+const MAX_FILENAME_CHARS: usize = 255;
+
+/// Types that may be attached. Anything a browser would run as a page, such as
+/// HTML or SVG, stays out.
+pub const ALLOWED_MIME_TYPES: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+    "text/markdown",
+    "application/json",
+    "application/zip",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+];
+
+/// The last path component of a client-supplied name, without control
+/// characters or quotes and cut to a sane length
+pub fn sanitize_filename(name: Option<&str>) -> String {
+    let base = name
+        .unwrap_or_default()
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(MAX_FILENAME_CHARS)
+        .collect();
+    match cleaned.trim() {
+        "" | "." | ".." => "file".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/// The type to store for an upload: the declared one, or a guess from the
+/// extension when the client sent none. Types outside the allowlist are rejected.
+pub fn resolve_mime_type(declared: Option<&str>, filename: &str) -> Result<String, AppError> {
+    let declared = declared
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+        .filter(|value| !value.is_empty() && value != "application/octet-stream");
+
+    let mime = match declared {
+        Some(mime) => mime,
+        None => mime_guess::from_path(filename)
+            .first_raw()
+            .unwrap_or("application/octet-stream")
+            .to_string(),
+    };
+
+    if !ALLOWED_MIME_TYPES.contains(&mime.as_str()) {
+        return Err(AppError::Validation(format!("File type {} is not allowed", mime)));
+    }
+    Ok(mime)
+}
+
+/// Reject binary formats whose leading bytes do not match their declared type
+pub fn check_signature(mime: &str, head: &[u8]) -> Result<(), AppError> {
+    let matches = match mime {
+        "image/png" => head.starts_with(b"\x89PNG\r\n\x1a\n"),
+        "image/jpeg" => head.starts_with(b"\xff\xd8\xff"),
+        "image/gif" => head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a"),
+        "image/webp" => head.len() >= 12 && head.starts_with(b"RIFF") && &head[8..12] == b"WEBP",
+        "application/pdf" => head.starts_with(b"%PDF-"),
+        "application/zip" => head.starts_with(b"PK\x03\x04"),
+        office if office.starts_with("application/vnd.openxmlformats") => head.starts_with(b"PK\x03\x04"),
+        _ => true,
+    };
+
+    if !matches {
+        return Err(AppError::Validation(format!(
+            "File content does not match its type {}",
+            mime
+        )));
+    }
+    Ok(())
+}
+
+/// Images are shown in the browser; everything else is downloaded
+pub fn is_inline(mime: &str) -> bool {
+    mime.starts_with("image/")
+}
+
+/// `Content-Disposition` with an ASCII fallback and the exact UTF-8 name (RFC 6266)
+pub fn content_disposition(filename: &str, inline: bool) -> String {
+    let fallback: String = filename
+        .chars()
+        .map(|c| if c.is_ascii_graphic() || c == ' ' { c } else { '_' })
+        .filter(|c| *c != '"' && *c != '\\')
+        .collect();
+
+    let mut encoded = String::new();
+    for byte in filename.bytes() {
+        if byte.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+
+    format!(
+        "{}; filename=\"{}\"; filename*=UTF-8''{}",
+        if inline { "inline" } else { "attachment" },
+        fallback,
+        encoded
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_filename() {
+        assert_eq!(sanitize_filename(Some("report.pdf")), "report.pdf");
+        assert_eq!(sanitize_filename(Some("../../etc/passwd")), "passwd");
+        assert_eq!(sanitize_filename(Some("C:\\Users\\me\\notes.txt")), "notes.txt");
+        assert_eq!(sanitize_filename(Some("a\"b\u{0}c.txt")), "abc.txt");
+        assert_eq!(sanitize_filename(Some("..")), "file");
+        assert_eq!(sanitize_filename(None), "file");
+        assert_eq!(sanitize_filename(Some(&"x".repeat(300))).len(), 255);
+    }
+
+    #[test]
+    fn test_resolve_mime_type() {
+        assert_eq!(resolve_mime_type(Some("image/PNG"), "a.png").unwrap(), "image/png");
+        assert_eq!(
+            resolve_mime_type(Some("text/plain; charset=utf-8"), "a.txt").unwrap(),
+            "text/plain"
+        );
+        assert_eq!(
+            resolve_mime_type(Some("application/octet-stream"), "scan.pdf").unwrap(),
+            "application/pdf"
+        );
+        assert_eq!(resolve_mime_type(None, "photo.jpg").unwrap(), "image/jpeg");
+        assert!(resolve_mime_type(Some("text/html"), "page.html").is_err());
+        assert!(resolve_mime_type(None, "logo.svg").is_err());
+        assert!(resolve_mime_type(None, "setup.exe").is_err());
+    }
+
+    #[test]
+    fn test_check_signature() {
+        assert!(check_signature("image/png", b"\x89PNG\r\n\x1a\n....").is_ok());
+        assert!(check_signature("image/png", b"<html>").is_err());
+        assert!(check_signature("image/webp", b"RIFF\x00\x00\x00\x00WEBPVP8 ").is_ok());
+        assert!(check_signature("application/pdf", b"%PDF-1.7").is_ok());
+        assert!(check_signature("text/plain", b"anything").is_ok());
+    }
+
+    #[test]
+    fn test_content_disposition() {
+        assert_eq!(
+            content_disposition("report.pdf", false),
+            "attachment; filename=\"report.pdf\"; filename*=UTF-8''report.pdf"
+        );
+        assert_eq!(
+            content_disposition("Übersicht 2025.png", true),
+            "inline; filename=\"_bersicht 2025.png\"; filename*=UTF-8''%C3%9Cbersicht%202025.png"
+        );
+    }
+}
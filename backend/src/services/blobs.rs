@@ -0,0 +1,181 @@
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{config::StorageConfig, services::storage::StorageBackend, utils::AppError};
+
+/// Blobs removed per garbage collection pass
+const GC_BATCH_SIZE: i64 = 100;
+
+/// Storage key of the blob with a SHA-256 digest, fanned out by its first byte
+pub fn blob_key(sha256: &str) -> String {
+    format!("blobs/{}/{}", &sha256[..2], sha256)
+}
+
+/// Storage key of the thumbnail rendered from a blob
+pub fn thumbnail_key(sha256: &str) -> String {
+    format!("thumbnails/{}/{}", &sha256[..2], sha256)
+}
+
+/// Storage key infected content is moved to, out of reach of downloads
+pub fn quarantine_key(sha256: &str) -> String {
+    format!("quarantine/{}/{}", &sha256[..2], sha256)
+}
+
+/// Bytes a batch of uploads adds to an organization: each distinct blob
+/// counts once, and blobs the organization already holds count nothing
+pub fn added_bytes<'a>(
+    uploads: impl IntoIterator<Item = (&'a str, i64)>,
+    held: &HashSet<String>,
+) -> i64 {
+    let mut seen = HashSet::new();
+    uploads
+        .into_iter()
+        .filter(|(sha256, _)| !held.contains(*sha256) && seen.insert(*sha256))
+        .map(|(_, size)| size)
+        .sum()
+}
+
+/// The quota in effect: the organization's override, else the configured
+/// default. Zero or less means unlimited.
+pub fn effective_quota(override_bytes: Option<i64>, default_bytes: i64) -> Option<i64> {
+    Some(override_bytes.unwrap_or(default_bytes)).filter(|quota| *quota > 0)
+}
+
+pub fn check_quota(used: i64, added: i64, quota: Option<i64>) -> Result<(), AppError> {
+    match quota {
+        Some(quota) if added > 0 && used + added > quota => {
+            Err(AppError::PayloadTooLarge(format!(
+            "Organization storage quota exceeded: {} of {} bytes used, this upload needs {} more",
+            used, quota, added
+        )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Delete blobs nobody has referenced or claimed within `grace`. Returns the
+/// number removed. Quarantined blobs are kept for review.
+///
+/// Rows stay locked while their content is deleted, so an upload claiming
+/// the same digest waits and then stores the content afresh.
+pub async fn collect_garbage(
+    db: &PgPool,
+    storage: &dyn StorageBackend,
+    grace: Duration,
+) -> Result<u64, AppError> {
+    let cutoff = Utc::now() - grace;
+
+    let mut tx = db.begin().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to start transaction".to_string())
+    })?;
+
+    let candidates = sqlx::query_scalar!(
+        r#"
+        SELECT sha256
+        FROM attachment_blobs
+        WHERE ref_count = 0 AND last_used_at < $1 AND scan_status <> 'infected'
+        ORDER BY last_used_at
+        LIMIT $2
+        FOR UPDATE SKIP LOCKED
+        "#,
+        cutoff,
+        GC_BATCH_SIZE
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to find unreferenced blobs".to_string())
+    })?;
+
+    let mut removed = Vec::with_capacity(candidates.len());
+    for sha256 in candidates {
+        let deleted = async {
+            storage.delete(&thumbnail_key(&sha256)).await?;
+            storage.delete(&blob_key(&sha256)).await
+        };
+        match deleted.await {
+            Ok(()) => removed.push(sha256),
+            Err(e) => tracing::warn!("Failed to delete blob {}: {}", sha256, e),
+        }
+    }
+
+    let deleted = sqlx::query!(
+        "DELETE FROM attachment_blobs WHERE sha256 = ANY($1)",
+        &removed
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to delete blobs".to_string())
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to commit blob cleanup".to_string())
+    })?;
+
+    Ok(deleted.rows_affected())
+}
+
+/// Run `collect_garbage` in the background every `gc_interval` seconds
+pub fn spawn_gc_job(db: PgPool, storage: Arc<dyn StorageBackend>, config: StorageConfig) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(config.gc_interval.max(1)));
+        let grace = Duration::seconds(config.gc_grace_period.max(0));
+        loop {
+            interval.tick().await;
+            match collect_garbage(&db, storage.as_ref(), grace).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Removed {} unreferenced attachment blobs", count),
+                Err(e) => tracing::error!("Attachment blob cleanup failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+    const B: &str = "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb";
+
+    #[test]
+    fn test_blob_key() {
+        assert_eq!(blob_key(A), format!("blobs/aa/{}", A));
+        assert_eq!(thumbnail_key(B), format!("thumbnails/bb/{}", B));
+        assert_eq!(quarantine_key(A), format!("quarantine/aa/{}", A));
+    }
+
+    #[test]
+    fn test_added_bytes_counts_each_new_blob_once() {
+        let none = HashSet::new();
+        assert_eq!(added_bytes([(A, 10), (B, 5), (A, 10)], &none), 15);
+
+        let held: HashSet<String> = [A.to_string()].into();
+        assert_eq!(added_bytes([(A, 10), (B, 5)], &held), 5);
+        assert_eq!(added_bytes([(A, 10)], &held), 0);
+    }
+
+    #[test]
+    fn test_quota() {
+        assert_eq!(effective_quota(None, 100), Some(100));
+        assert_eq!(effective_quota(Some(500), 100), Some(500));
+        assert_eq!(effective_quota(None, 0), None);
+        assert_eq!(effective_quota(Some(0), 100), None);
+
+        assert!(check_quota(60, 40, Some(100)).is_ok());
+        assert!(matches!(
+            check_quota(60, 41, Some(100)),
+            Err(AppError::PayloadTooLarge(_))
+        ));
+        assert!(check_quota(1_000, 1_000, None).is_ok());
+        // Deduplicated uploads go through even when already over quota
+        assert!(check_quota(200, 0, Some(100)).is_ok());
+    }
+}
@@ -0,0 +1,222 @@
+use chrono::NaiveDate;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::utils::AppError;
+
+const MAX_TEXT_LENGTH: usize = 10_000;
+
+/// Supported custom field types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+    MultiSelect,
+    User,
+    Url,
+}
+
+impl CustomFieldType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CustomFieldType::Text => "text",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Date => "date",
+            CustomFieldType::SingleSelect => "single_select",
+            CustomFieldType::MultiSelect => "multi_select",
+            CustomFieldType::User => "user",
+            CustomFieldType::Url => "url",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(CustomFieldType::Text),
+            "number" => Some(CustomFieldType::Number),
+            "date" => Some(CustomFieldType::Date),
+            "single_select" => Some(CustomFieldType::SingleSelect),
+            "multi_select" => Some(CustomFieldType::MultiSelect),
+            "user" => Some(CustomFieldType::User),
+            "url" => Some(CustomFieldType::Url),
+            _ => None,
+        }
+    }
+
+    pub fn has_options(&self) -> bool {
+        matches!(self, CustomFieldType::SingleSelect | CustomFieldType::MultiSelect)
+    }
+}
+
+/// Validate the option list of a field definition
+pub fn validate_options(field_type: CustomFieldType, options: &[String]) -> Result<(), AppError> {
+    if !field_type.has_options() {
+        if !options.is_empty() {
+            return Err(AppError::Validation(format!(
+                "Fields of type {} do not take options",
+                field_type.as_str()
+            )));
+        }
+        return Ok(());
+    }
+
+    if options.is_empty() {
+        return Err(AppError::Validation(
+            "Select fields need at least one option".to_string(),
+        ));
+    }
+
+    for (i, option) in options.iter().enumerate() {
+        if option.trim().is_empty() {
+            return Err(AppError::Validation("Options cannot be empty".to_string()));
+        }
+        if options[..i].contains(option) {
+            return Err(AppError::Validation(format!("Duplicate option: {}", option)));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a task value against its field definition and return its canonical form.
+///
+/// User values are only checked for shape here; membership is verified by the caller.
+pub fn normalize_value(
+    field_type: CustomFieldType,
+    options: &[String],
+    value: &Value,
+) -> Result<Value, AppError> {
+    let invalid = |expected: &str| {
+        AppError::Validation(format!(
+            "Invalid value for {} field: expected {}",
+            field_type.as_str(),
+            expected
+        ))
+    };
+
+    match field_type {
+        CustomFieldType::Text => {
+            let text = value.as_str().ok_or_else(|| invalid("a string"))?;
+            if text.chars().count() > MAX_TEXT_LENGTH {
+                return Err(AppError::Validation(format!(
+                    "Text values are limited to {} characters",
+                    MAX_TEXT_LENGTH
+                )));
+            }
+            Ok(Value::String(text.to_string()))
+        }
+        CustomFieldType::Number => {
+            let number = value.as_f64().ok_or_else(|| invalid("a number"))?;
+            if !number.is_finite() {
+                return Err(invalid("a finite number"));
+            }
+            Ok(value.clone())
+        }
+        CustomFieldType::Date => {
+            let date = value
+                .as_str()
+                .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
+                .ok_or_else(|| invalid("a date formatted as YYYY-MM-DD"))?;
+            Ok(Value::String(date.format("%Y-%m-%d").to_string()))
+        }
+        CustomFieldType::SingleSelect => {
+            let choice = value.as_str().ok_or_else(|| invalid("one of the options"))?;
+            if !options.iter().any(|o| o == choice) {
+                return Err(invalid("one of the options"));
+            }
+            Ok(Value::String(choice.to_string()))
+        }
+        CustomFieldType::MultiSelect => {
+            let choices = value.as_array().ok_or_else(|| invalid("a list of options"))?;
+            let mut selected: Vec<Value> = Vec::with_capacity(choices.len());
+            for choice in choices {
+                let choice = choice.as_str().ok_or_else(|| invalid("a list of options"))?;
+                if !options.iter().any(|o| o == choice) {
+                    return Err(AppError::Validation(format!("Unknown option: {}", choice)));
+                }
+                let choice = Value::String(choice.to_string());
+                if !selected.contains(&choice) {
+                    selected.push(choice);
+                }
+            }
+            Ok(Value::Array(selected))
+        }
+        CustomFieldType::User => {
+            let user_id = value
+                .as_str()
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .ok_or_else(|| invalid("a user ID"))?;
+            Ok(Value::String(user_id.to_string()))
+        }
+        CustomFieldType::Url => {
+            let url = value
+                .as_str()
+                .and_then(|s| reqwest::Url::parse(s).ok())
+                .filter(|u| u.scheme() == "http" || u.scheme() == "https")
+                .ok_or_else(|| invalid("an http(s) URL"))?;
+            Ok(Value::String(url.to_string()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn options() -> Vec<String> {
+        vec!["low".to_string(), "high".to_string()]
+    }
+
+    #[test]
+    fn test_field_type_round_trip() {
+        for name in ["text", "number", "date", "single_select", "multi_select", "user", "url"] {
+            assert_eq!(CustomFieldType::parse(name).unwrap().as_str(), name);
+        }
+        assert!(CustomFieldType::parse("checkbox").is_none());
+    }
+
+    #[test]
+    fn test_validate_options() {
+        assert!(validate_options(CustomFieldType::SingleSelect, &options()).is_ok());
+        assert!(validate_options(CustomFieldType::SingleSelect, &[]).is_err());
+        assert!(validate_options(CustomFieldType::Text, &options()).is_err());
+
+        let duplicated = vec!["a".to_string(), "a".to_string()];
+        assert!(validate_options(CustomFieldType::MultiSelect, &duplicated).is_err());
+    }
+
+    #[test]
+    fn test_normalize_scalar_values() {
+        assert_eq!(
+            normalize_value(CustomFieldType::Number, &[], &json!(3.5)).unwrap(),
+            json!(3.5)
+        );
+        assert!(normalize_value(CustomFieldType::Number, &[], &json!("3")).is_err());
+
+        assert_eq!(
+            normalize_value(CustomFieldType::Date, &[], &json!("2025-11-03")).unwrap(),
+            json!("2025-11-03")
+        );
+        assert!(normalize_value(CustomFieldType::Date, &[], &json!("03/11/2025")).is_err());
+
+        assert!(normalize_value(CustomFieldType::Url, &[], &json!("https://example.com")).is_ok());
+        assert!(normalize_value(CustomFieldType::Url, &[], &json!("ftp://example.com")).is_err());
+
+        assert!(normalize_value(CustomFieldType::User, &[], &json!("not-a-uuid")).is_err());
+    }
+
+    #[test]
+    fn test_normalize_select_values() {
+        assert!(normalize_value(CustomFieldType::SingleSelect, &options(), &json!("high")).is_ok());
+        assert!(normalize_value(CustomFieldType::SingleSelect, &options(), &json!("medium")).is_err());
+
+        assert_eq!(
+            normalize_value(CustomFieldType::MultiSelect, &options(), &json!(["high", "low", "high"]))
+                .unwrap(),
+            json!(["high", "low"])
+        );
+        assert!(normalize_value(CustomFieldType::MultiSelect, &options(), &json!("high")).is_err());
+    }
+}
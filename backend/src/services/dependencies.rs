@@ -0,0 +1,65 @@
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Check whether adding the edge `blocking -> blocked` to a dependency graph
+/// would introduce a cycle.
+///
+/// `edges` holds existing `(blocking_task_id, blocked_task_id)` pairs. A cycle
+/// exists if `blocking` is already reachable from `blocked`.
+pub fn creates_cycle(edges: &[(Uuid, Uuid)], blocking: Uuid, blocked: Uuid) -> bool {
+    if blocking == blocked {
+        return true;
+    }
+
+    let mut adjacency: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (from, to) in edges {
+        adjacency.entry(*from).or_default().push(*to);
+    }
+
+    let mut visited = HashSet::new();
+    let mut stack = vec![blocked];
+
+    while let Some(current) = stack.pop() {
+        if current == blocking {
+            return true;
+        }
+        if !visited.insert(current) {
+            continue;
+        }
+        if let Some(next) = adjacency.get(&current) {
+            stack.extend(next.iter().copied());
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_dependency_is_a_cycle() {
+        let a = Uuid::new_v4();
+        assert!(creates_cycle(&[], a, a));
+    }
+
+    #[test]
+    fn test_direct_and_transitive_cycles() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let edges = vec![(a, b), (b, c)];
+
+        assert!(creates_cycle(&edges, b, a));
+        assert!(creates_cycle(&edges, c, a));
+        assert!(!creates_cycle(&edges, a, c));
+    }
+
+    #[test]
+    fn test_diamond_is_not_a_cycle() {
+        let (a, b, c, d) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let edges = vec![(a, b), (a, c), (b, d)];
+
+        assert!(!creates_cycle(&edges, c, d));
+        assert!(creates_cycle(&edges, d, a));
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Utc};
+
+use crate::utils::AppError;
+
+/// Largest UTC offset in use, in minutes (UTC+14:00)
+const MAX_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// Where a task falls relative to the user's current day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DueBucket {
+    Overdue,
+    Today,
+    ThisWeek,
+    Later,
+    NoDueDate,
+}
+
+/// Resolve the user's UTC offset from minutes east of UTC
+pub fn utc_offset(minutes: Option<i32>) -> Result<FixedOffset, AppError> {
+    let minutes = minutes.unwrap_or(0);
+    if !(-MAX_OFFSET_MINUTES..=MAX_OFFSET_MINUTES).contains(&minutes) {
+        return Err(AppError::Validation(format!(
+            "tz_offset must be between -{} and {} minutes",
+            MAX_OFFSET_MINUTES, MAX_OFFSET_MINUTES
+        )));
+    }
+    FixedOffset::east_opt(minutes * 60)
+        .ok_or_else(|| AppError::Validation("Invalid tz_offset".to_string()))
+}
+
+/// Classify a due date against `now` as seen in the user's timezone.
+///
+/// Weeks start on Monday, so "this week" runs from tomorrow to the end of Sunday.
+pub fn due_bucket(
+    due_date: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+    offset: FixedOffset,
+) -> DueBucket {
+    let Some(due_date) = due_date else {
+        return DueBucket::NoDueDate;
+    };
+    if due_date < now {
+        return DueBucket::Overdue;
+    }
+
+    let today = now.with_timezone(&offset).date_naive();
+    let due_day = due_date.with_timezone(&offset).date_naive();
+    let days_left_in_week = 6 - i64::from(today.weekday().num_days_from_monday());
+
+    if due_day == today {
+        DueBucket::Today
+    } else if due_day <= today + Duration::days(days_left_in_week) {
+        DueBucket::ThisWeek
+    } else {
+        DueBucket::Later
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_due_bucket_utc() {
+        // Wednesday
+        let now = at(2025, 11, 5, 12);
+        let utc = utc_offset(None).unwrap();
+
+        assert_eq!(due_bucket(None, now, utc), DueBucket::NoDueDate);
+        assert_eq!(due_bucket(Some(at(2025, 11, 5, 9)), now, utc), DueBucket::Overdue);
+        assert_eq!(due_bucket(Some(at(2025, 11, 5, 18)), now, utc), DueBucket::Today);
+        assert_eq!(due_bucket(Some(at(2025, 11, 9, 23)), now, utc), DueBucket::ThisWeek);
+        assert_eq!(due_bucket(Some(at(2025, 11, 10, 1)), now, utc), DueBucket::Later);
+    }
+
+    #[test]
+    fn test_due_bucket_respects_offset() {
+        // Sunday 22:00 UTC is already Monday in UTC+3
+        let now = at(2025, 11, 9, 22);
+        let due = Some(at(2025, 11, 10, 8));
+
+        assert_eq!(due_bucket(due, now, utc_offset(None).unwrap()), DueBucket::Later);
+        assert_eq!(due_bucket(due, now, utc_offset(Some(180)).unwrap()), DueBucket::Today);
+        assert!(utc_offset(Some(15 * 60)).is_err());
+    }
+}
@@ -0,0 +1,288 @@
+use image::{
+    codecs::jpeg::JpegEncoder, DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits,
+};
+use std::io::Cursor;
+
+/// Image types thumbnails are generated for
+pub const THUMBNAIL_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// Largest image dimension decoded for a thumbnail
+const MAX_DECODE_DIMENSION: u32 = 12_000;
+/// Memory a single decode may allocate
+const MAX_DECODE_ALLOC: u64 = 256 * 1024 * 1024;
+const JPEG_QUALITY: u8 = 80;
+
+pub fn has_thumbnail(mime_type: &str) -> bool {
+    THUMBNAIL_MIME_TYPES.contains(&mime_type)
+}
+
+/// A rendered thumbnail and its content type
+pub struct Thumbnail {
+    pub data: Vec<u8>,
+    pub mime_type: &'static str,
+}
+
+/// Scale an image to fit within `max_side` pixels, upright per its EXIF
+/// orientation. Images with transparency become PNGs, others JPEGs.
+pub fn render_thumbnail(data: &[u8], max_side: u32) -> Result<Thumbnail, String> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DECODE_DIMENSION);
+    limits.max_image_height = Some(MAX_DECODE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOC);
+
+    let mut reader = ImageReader::new(Cursor::new(data))
+        .with_guessed_format()
+        .map_err(|e| e.to_string())?;
+    reader.limits(limits);
+    let mut decoder = reader.into_decoder().map_err(|e| e.to_string())?;
+    let orientation = decoder.orientation().map_err(|e| e.to_string())?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|e| e.to_string())?;
+    image.apply_orientation(orientation);
+
+    let thumbnail = image.thumbnail(max_side, max_side);
+    let mut out = Vec::new();
+    if thumbnail.color().has_alpha() {
+        thumbnail
+            .write_to(&mut Cursor::new(&mut out), ImageFormat::Png)
+            .map_err(|e| e.to_string())?;
+        Ok(Thumbnail {
+            data: out,
+            mime_type: "image/png",
+        })
+    } else {
+        DynamicImage::ImageRgb8(thumbnail.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut out, JPEG_QUALITY))
+            .map_err(|e| e.to_string())?;
+        Ok(Thumbnail {
+            data: out,
+            mime_type: "image/jpeg",
+        })
+    }
+}
+
+/// Remove EXIF and similar metadata, such as camera details and location,
+/// without re-encoding. Returns `None` when there was nothing to remove.
+///
+/// The EXIF orientation goes with the rest, so photos taken sideways may
+/// display sideways afterwards.
+pub fn strip_metadata(mime_type: &str, data: &[u8]) -> Result<Option<Vec<u8>>, String> {
+    let stripped = match mime_type {
+        "image/jpeg" => strip_jpeg(data)?,
+        "image/png" => strip_png(data)?,
+        "image/webp" => strip_webp(data)?,
+        _ => return Ok(None),
+    };
+    Ok(Some(stripped).filter(|s| s.len() != data.len()))
+}
+
+/// Drop APP1 (EXIF, XMP) and APP13 (IPTC) segments
+fn strip_jpeg(data: &[u8]) -> Result<Vec<u8>, String> {
+    const TRUNCATED: &str = "Truncated JPEG";
+    if !data.starts_with(&[0xFF, 0xD8]) {
+        return Err("Not a JPEG".to_string());
+    }
+
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..2]);
+    let mut pos = 2;
+    loop {
+        if pos + 2 > data.len() || data[pos] != 0xFF {
+            return Err(TRUNCATED.to_string());
+        }
+        let marker = data[pos + 1];
+        match marker {
+            // Fill byte before a marker
+            0xFF => pos += 1,
+            // Start of scan or end of image: the rest is image data
+            0xDA | 0xD9 => {
+                out.extend_from_slice(&data[pos..]);
+                return Ok(out);
+            }
+            // Markers without a length
+            0x01 | 0xD0..=0xD7 => {
+                out.extend_from_slice(&data[pos..pos + 2]);
+                pos += 2;
+            }
+            _ => {
+                if pos + 4 > data.len() {
+                    return Err(TRUNCATED.to_string());
+                }
+                let len = usize::from(u16::from_be_bytes([data[pos + 2], data[pos + 3]]));
+                let end = pos + 2 + len;
+                if len < 2 || end > data.len() {
+                    return Err(TRUNCATED.to_string());
+                }
+                if marker != 0xE1 && marker != 0xED {
+                    out.extend_from_slice(&data[pos..end]);
+                }
+                pos = end;
+            }
+        }
+    }
+}
+
+/// Drop eXIf, text and timestamp chunks
+fn strip_png(data: &[u8]) -> Result<Vec<u8>, String> {
+    const SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+    if !data.starts_with(SIGNATURE) {
+        return Err("Not a PNG".to_string());
+    }
+
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(SIGNATURE);
+    let mut pos = SIGNATURE.len();
+    while pos < data.len() {
+        if pos + 12 > data.len() {
+            return Err("Truncated PNG".to_string());
+        }
+        let len =
+            u32::from_be_bytes([data[pos], data[pos + 1], data[pos + 2], data[pos + 3]]) as usize;
+        let kind = &data[pos + 4..pos + 8];
+        let end = pos
+            .checked_add(12 + len)
+            .filter(|end| *end <= data.len())
+            .ok_or_else(|| "Truncated PNG".to_string())?;
+        if !matches!(kind, b"eXIf" | b"tEXt" | b"iTXt" | b"zTXt" | b"tIME") {
+            out.extend_from_slice(&data[pos..end]);
+        }
+        pos = end;
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    Ok(out)
+}
+
+/// Drop EXIF and XMP chunks and clear their flags in the extended header
+fn strip_webp(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < 12 || &data[..4] != b"RIFF" || &data[8..12] != b"WEBP" {
+        return Err("Not a WebP image".to_string());
+    }
+
+    let mut out = Vec::with_capacity(data.len());
+    out.extend_from_slice(&data[..12]);
+    let mut pos = 12;
+    while pos < data.len() {
+        if pos + 8 > data.len() {
+            return Err("Truncated WebP image".to_string());
+        }
+        let kind = &data[pos..pos + 4];
+        let len = u32::from_le_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]])
+            as usize;
+        // Chunks are padded to an even length
+        let end = pos
+            .checked_add(8 + len + len % 2)
+            .filter(|end| *end <= data.len())
+            .ok_or_else(|| "Truncated WebP image".to_string())?;
+        match kind {
+            b"EXIF" | b"XMP " => {}
+            b"VP8X" if len > 0 => {
+                let start = out.len();
+                out.extend_from_slice(&data[pos..end]);
+                out[start + 8] &= !(0x08 | 0x04);
+            }
+            _ => out.extend_from_slice(&data[pos..end]),
+        }
+        pos = end;
+    }
+
+    let riff_size = u32::try_from(out.len() - 8).map_err(|_| "WebP image too large".to_string())?;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, ImageBuffer, Rgb, Rgba};
+
+    fn encode(image: DynamicImage, format: ImageFormat) -> Vec<u8> {
+        let mut out = Vec::new();
+        image.write_to(&mut Cursor::new(&mut out), format).unwrap();
+        out
+    }
+
+    fn photo(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(ImageBuffer::from_fn(width, height, |x, _| {
+            Rgb([(x % 256) as u8, 80, 160])
+        }))
+    }
+
+    #[test]
+    fn test_render_thumbnail_keeps_aspect_ratio() {
+        let thumbnail = render_thumbnail(&encode(photo(800, 400), ImageFormat::Png), 256).unwrap();
+        assert_eq!(thumbnail.mime_type, "image/jpeg");
+        let decoded = image::load_from_memory(&thumbnail.data).unwrap();
+        assert_eq!(decoded.dimensions(), (256, 128));
+
+        let transparent =
+            DynamicImage::ImageRgba8(ImageBuffer::from_pixel(100, 300, Rgba([0, 0, 0, 0])));
+        let thumbnail = render_thumbnail(&encode(transparent, ImageFormat::Png), 256).unwrap();
+        assert_eq!(thumbnail.mime_type, "image/png");
+        assert_eq!(
+            image::load_from_memory(&thumbnail.data)
+                .unwrap()
+                .dimensions(),
+            (85, 256)
+        );
+
+        assert!(render_thumbnail(b"not an image", 256).is_err());
+    }
+
+    #[test]
+    fn test_strip_jpeg_metadata() {
+        let jpeg = encode(photo(16, 8), ImageFormat::Jpeg);
+        let exif = [&[0xFF, 0xE1, 0x00, 0x0C][..], b"Exif\0\0GPS!"].concat();
+        let tagged = [&jpeg[..2], &exif[..], &jpeg[2..]].concat();
+
+        let stripped = strip_metadata("image/jpeg", &tagged).unwrap().unwrap();
+        assert_eq!(stripped, jpeg);
+        assert_eq!(
+            image::load_from_memory(&stripped).unwrap().dimensions(),
+            (16, 8)
+        );
+
+        assert_eq!(strip_metadata("image/jpeg", &jpeg).unwrap(), None);
+        assert!(strip_metadata("image/jpeg", &tagged[..20]).is_err());
+    }
+
+    #[test]
+    fn test_strip_png_metadata() {
+        let png = encode(photo(4, 4), ImageFormat::Png);
+        let iend = png.len() - 12;
+        let exif = [&6u32.to_be_bytes()[..], b"eXIf", b"MM\0*GP", &[0, 0, 0, 0]].concat();
+        let tagged = [&png[..iend], &exif[..], &png[iend..]].concat();
+
+        let stripped = strip_metadata("image/png", &tagged).unwrap().unwrap();
+        assert_eq!(stripped, png);
+        assert_eq!(strip_metadata("image/png", &png).unwrap(), None);
+    }
+
+    #[test]
+    fn test_strip_webp_metadata() {
+        let vp8x = [
+            &b"VP8X"[..],
+            &10u32.to_le_bytes(),
+            &[0x08 | 0x04 | 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+        ]
+        .concat();
+        let image = [&b"VP8L"[..], &3u32.to_le_bytes(), &[1, 2, 3, 0]].concat();
+        let exif = [&b"EXIF"[..], &4u32.to_le_bytes(), b"GPS!"].concat();
+        let xmp = [&b"XMP "[..], &1u32.to_le_bytes(), &[b'x', 0]].concat();
+        let body = [&b"WEBP"[..], &vp8x, &image, &exif, &xmp].concat();
+        let webp = [&b"RIFF"[..], &(body.len() as u32).to_le_bytes(), &body].concat();
+
+        let stripped = strip_metadata("image/webp", &webp).unwrap().unwrap();
+        let expected_body = [&b"WEBP"[..], &vp8x, &image].concat();
+        assert_eq!(&stripped[4..8], &(expected_body.len() as u32).to_le_bytes());
+        assert_eq!(stripped[20], 0x10);
+        assert_eq!(stripped.len(), 8 + expected_body.len());
+        assert!(!stripped.windows(4).any(|w| w == b"EXIF"));
+    }
+
+    #[test]
+    fn test_strip_metadata_ignores_other_types() {
+        assert_eq!(strip_metadata("image/gif", b"GIF89a").unwrap(), None);
+        assert_eq!(strip_metadata("application/pdf", b"%PDF").unwrap(), None);
+    }
+}
@@ -1 +1,21 @@
 // Business services module
+pub mod assignees;
+pub mod attachments;
+pub mod blobs;
+pub mod custom_fields;
+pub mod dependencies;
+pub mod due_buckets;
+pub mod images;
+pub mod pagination;
+pub mod rank;
+pub mod recurrence;
+pub mod scanner;
+pub mod scans;
+pub mod search;
+pub mod storage;
+pub mod task_history;
+pub mod task_keys;
+pub mod task_query;
+pub mod thumbnails;
+pub mod time_tracking;
+pub mod trash;
@@ -0,0 +1,216 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::{Deserialize, Serialize};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::utils::AppError;
+
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+pub const MAX_PAGE_SIZE: i64 = 200;
+
+/// Resolve the requested page size, applying the default and upper bound
+pub fn page_size(limit: Option<i64>) -> Result<i64, AppError> {
+    match limit {
+        None => Ok(DEFAULT_PAGE_SIZE),
+        Some(limit) if (1..=MAX_PAGE_SIZE).contains(&limit) => Ok(limit),
+        Some(_) => Err(AppError::Validation(format!(
+            "limit must be between 1 and {}",
+            MAX_PAGE_SIZE
+        ))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn parse(order: Option<&str>) -> Result<Self, AppError> {
+        match order {
+            None | Some("asc") => Ok(SortDirection::Asc),
+            Some("desc") => Ok(SortDirection::Desc),
+            Some(other) => Err(AppError::Validation(format!("Invalid sort order: {}", other))),
+        }
+    }
+
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+
+    fn comparison(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => ">",
+            SortDirection::Desc => "<",
+        }
+    }
+}
+
+/// A column or expression that a listing can be ordered by.
+///
+/// Rows are always ordered by the key with NULLs last and then by `id`, so the
+/// pair `(key, id)` identifies a unique position for keyset pagination.
+#[derive(Debug, Clone)]
+pub struct SortKey {
+    /// Name echoed back in cursors so they cannot be reused with another sort
+    pub name: String,
+    pub expression: String,
+    /// Postgres type the expression is compared as, e.g. `timestamptz`
+    pub sql_type: &'static str,
+}
+
+impl SortKey {
+    pub fn new(name: impl Into<String>, expression: impl Into<String>, sql_type: &'static str) -> Self {
+        SortKey {
+            name: name.into(),
+            expression: expression.into(),
+            sql_type,
+        }
+    }
+
+    /// Select the key as text so it can be carried in a cursor
+    pub fn push_select(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        builder.push(format!(", ({})::text AS sort_key", self.expression));
+    }
+
+    /// Restrict rows to those after `cursor` in the given direction
+    pub fn push_after(
+        &self,
+        builder: &mut QueryBuilder<'_, Postgres>,
+        id_column: &str,
+        direction: SortDirection,
+        cursor: &Cursor,
+    ) {
+        let op = direction.comparison();
+        match cursor.value {
+            // NULLs sort last, so only NULL rows with a later id remain
+            None => {
+                builder
+                    .push(format!(" AND (({}) IS NULL AND {} {} ", self.expression, id_column, op))
+                    .push_bind(cursor.id)
+                    .push(")");
+            }
+            Some(ref value) => {
+                builder
+                    .push(format!(" AND (({}) {} ", self.expression, op))
+                    .push_bind(value.clone())
+                    .push(format!("::{} OR (({}) = ", self.sql_type, self.expression))
+                    .push_bind(value.clone())
+                    .push(format!("::{} AND {} {} ", self.sql_type, id_column, op))
+                    .push_bind(cursor.id)
+                    .push(format!(") OR ({}) IS NULL)", self.expression));
+            }
+        }
+    }
+
+    pub fn push_order_by(
+        &self,
+        builder: &mut QueryBuilder<'_, Postgres>,
+        id_column: &str,
+        direction: SortDirection,
+    ) {
+        builder.push(format!(
+            " ORDER BY ({}) {} NULLS LAST, {} {}",
+            self.expression,
+            direction.as_sql(),
+            id_column,
+            direction.as_sql()
+        ));
+    }
+}
+
+/// Opaque position in a listing, handed to clients as `next_cursor`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub sort: String,
+    pub value: Option<String>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor serializes");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, AppError> {
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| AppError::Validation("Invalid cursor".to_string()))
+    }
+
+    /// Decode a cursor and make sure it was issued for the same sort key
+    pub fn decode_for(encoded: Option<&str>, key: &SortKey) -> Result<Option<Self>, AppError> {
+        let Some(encoded) = encoded else {
+            return Ok(None);
+        };
+        let cursor = Cursor::decode(encoded)?;
+        if cursor.sort != key.name {
+            return Err(AppError::Validation(
+                "Cursor does not match the requested sort".to_string(),
+            ));
+        }
+        Ok(Some(cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_size_bounds() {
+        assert_eq!(page_size(None).unwrap(), DEFAULT_PAGE_SIZE);
+        assert_eq!(page_size(Some(10)).unwrap(), 10);
+        assert!(page_size(Some(0)).is_err());
+        assert!(page_size(Some(MAX_PAGE_SIZE + 1)).is_err());
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor {
+            sort: "due_date".to_string(),
+            value: Some("2025-11-01 10:00:00+00".to_string()),
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(Cursor::decode("not a cursor").is_err());
+    }
+
+    #[test]
+    fn test_cursor_must_match_sort() {
+        let key = SortKey::new("title", "t.title", "text");
+        let cursor = Cursor {
+            sort: "due_date".to_string(),
+            value: None,
+            id: Uuid::new_v4(),
+        };
+        assert!(Cursor::decode_for(Some(&cursor.encode()), &key).is_err());
+        assert!(Cursor::decode_for(None, &key).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_keyset_sql() {
+        let key = SortKey::new("title", "t.title", "text");
+        let cursor = Cursor {
+            sort: "title".to_string(),
+            value: Some("b".to_string()),
+            id: Uuid::new_v4(),
+        };
+
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT t.id FROM tasks t WHERE true");
+        key.push_after(&mut builder, "t.id", SortDirection::Desc, &cursor);
+        key.push_order_by(&mut builder, "t.id", SortDirection::Desc);
+
+        assert_eq!(
+            builder.sql(),
+            "SELECT t.id FROM tasks t WHERE true AND ((t.title) < $1::text OR ((t.title) = $2::text AND t.id < $3) OR (t.title) IS NULL) ORDER BY (t.title) DESC NULLS LAST, t.id DESC"
+        );
+    }
+}
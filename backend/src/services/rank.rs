@@ -0,0 +1,118 @@
+use crate::utils::AppError;
+
+/// Rank digits in ascending byte order, so keys compare correctly under `COLLATE "C"`
+const DIGITS: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE: usize = DIGITS.len();
+
+fn digit_value(c: u8) -> Option<usize> {
+    DIGITS.iter().position(|&d| d == c)
+}
+
+/// A rank key is a base-62 fraction `0.xxx` written without the leading `0.`.
+/// Keys never end in the smallest digit, so there is always room below them.
+pub fn validate(key: &str) -> Result<(), AppError> {
+    let bytes = key.as_bytes();
+    let valid = !bytes.is_empty()
+        && bytes.iter().all(|&c| digit_value(c).is_some())
+        && bytes.last() != Some(&DIGITS[0]);
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::Internal(format!("Invalid rank key: {:?}", key)))
+    }
+}
+
+/// Shortest key strictly between `a` and `b`, where `a` may be empty (zero) and
+/// a missing `b` stands for one. Requires `a < b`.
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> Vec<u8> {
+    if let Some(b) = b {
+        // Copy the shared prefix, treating `a` as padded with zeros
+        let mut n = 0;
+        while n < b.len() && a.get(n).copied().unwrap_or(DIGITS[0]) == b[n] {
+            n += 1;
+        }
+        if n > 0 {
+            let mut key = b[..n].to_vec();
+            key.extend(midpoint(a.get(n..).unwrap_or(&[]), Some(&b[n..])));
+            return key;
+        }
+    }
+
+    let low = a.first().and_then(|&c| digit_value(c)).unwrap_or(0);
+    let high = b.and_then(|b| digit_value(b[0])).unwrap_or(BASE);
+
+    if high - low > 1 {
+        return vec![DIGITS[(low + high) / 2]];
+    }
+    match b {
+        // `b` is longer than one digit, so its first digit alone sorts below it
+        Some(b) if b.len() > 1 => vec![b[0]],
+        _ => {
+            let mut key = vec![DIGITS[low]];
+            key.extend(midpoint(a.get(1..).unwrap_or(&[]), None));
+            key
+        }
+    }
+}
+
+/// A key sorting after `before` and ahead of `after`; either end may be open
+pub fn between(before: Option<&str>, after: Option<&str>) -> Result<String, AppError> {
+    if let Some(before) = before {
+        validate(before)?;
+    }
+    if let Some(after) = after {
+        validate(after)?;
+    }
+    if let (Some(before), Some(after)) = (before, after) {
+        if before >= after {
+            return Err(AppError::Validation(
+                "The task before must be ranked ahead of the task after".to_string(),
+            ));
+        }
+    }
+
+    let key = midpoint(
+        before.unwrap_or("").as_bytes(),
+        after.map(str::as_bytes),
+    );
+    Ok(String::from_utf8(key).expect("rank digits are ASCII"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_between() {
+        assert_eq!(between(None, None).unwrap(), "V");
+        assert_eq!(between(Some("V"), None).unwrap(), "k");
+        assert_eq!(between(None, Some("V")).unwrap(), "F");
+        assert_eq!(between(Some("a"), Some("b")).unwrap(), "aV");
+        assert_eq!(between(Some("az"), Some("b")).unwrap(), "azV");
+        assert_eq!(between(None, Some("01")).unwrap(), "00V");
+        assert_eq!(between(Some("z"), None).unwrap(), "zV");
+        assert!(between(Some("b"), Some("a")).is_err());
+        assert!(between(Some("a"), Some("a")).is_err());
+        assert!(between(Some("a0"), None).is_err());
+        assert!(between(Some("a-"), None).is_err());
+    }
+
+    proptest! {
+        /// Inserting anywhere keeps every key valid and strictly ordered
+        #[test]
+        fn prop_inserts_stay_ordered(slots in prop::collection::vec(any::<prop::sample::Index>(), 1..200)) {
+            let mut keys: Vec<String> = Vec::new();
+            for slot in slots {
+                let i = slot.index(keys.len() + 1);
+                let before = i.checked_sub(1).map(|j| keys[j].as_str());
+                let after = keys.get(i).map(String::as_str);
+                let key = between(before, after).unwrap();
+                prop_assert!(validate(&key).is_ok());
+                prop_assert!(before.is_none_or(|b| b < key.as_str()));
+                prop_assert!(after.is_none_or(|a| key.as_str() < a));
+                keys.insert(i, key);
+            }
+        }
+    }
+}
@@ -0,0 +1,317 @@
+//! Recurrence rules: the subset of RFC 5545 `RRULE` needed for repeating tasks.
+//!
+//! Supported parts are `FREQ` (`DAILY`, `WEEKLY`, `MONTHLY`), `INTERVAL`,
+//! `BYDAY` (weekly rules only, plain weekday codes), `UNTIL` and `COUNT`.
+//! Occurrences keep the local wall-clock time of the first one in the series'
+//! timezone, so a 09:00 deadline stays at 09:00 across DST changes.
+
+use chrono::{
+    DateTime, Datelike, Days, Duration, LocalResult, Months, NaiveDate, NaiveDateTime, Offset,
+    TimeZone, Utc, Weekday,
+};
+use chrono_tz::Tz;
+use std::fmt;
+
+use crate::utils::AppError;
+
+/// Guard against rules that can never produce another occurrence
+const MAX_PERIODS: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    /// Weekdays of a weekly rule, Monday first; empty means the weekday of the start
+    pub by_day: Vec<Weekday>,
+    pub until: Option<DateTime<Utc>>,
+    pub count: Option<u32>,
+}
+
+fn invalid(message: impl Into<String>) -> AppError {
+    AppError::Validation(format!("Invalid recurrence rule: {}", message.into()))
+}
+
+fn parse_weekday(code: &str) -> Result<Weekday, AppError> {
+    Ok(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        other => return Err(invalid(format!("unsupported BYDAY value {}", other))),
+    })
+}
+
+fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// `UNTIL` as a UTC date-time (`20251231T170000Z`) or a date (`20251231`),
+/// where a date includes the whole day in UTC
+fn parse_until(value: &str) -> Result<DateTime<Utc>, AppError> {
+    if let Ok(at) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(at.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map(|date| date.and_hms_opt(23, 59, 59).expect("valid time").and_utc())
+        .map_err(|_| invalid(format!("UNTIL must be YYYYMMDD or YYYYMMDDTHHMMSSZ, got {}", value)))
+}
+
+impl RecurrenceRule {
+    /// Parse a rule such as `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;COUNT=10`.
+    /// A leading `RRULE:` is accepted.
+    pub fn parse(input: &str) -> Result<Self, AppError> {
+        let input = input.trim();
+        let input = input.strip_prefix("RRULE:").unwrap_or(input);
+
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut by_day = Vec::new();
+        let mut until = None;
+        let mut count = None;
+
+        for part in input.split(';').filter(|p| !p.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected NAME=VALUE, got {}", part)))?;
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        other => return Err(invalid(format!("unsupported FREQ {}", other))),
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n >= 1)
+                        .ok_or_else(|| invalid("INTERVAL must be a positive number"))?;
+                }
+                "BYDAY" => {
+                    for code in value.split(',') {
+                        let day = parse_weekday(&code.to_ascii_uppercase())?;
+                        if !by_day.contains(&day) {
+                            by_day.push(day);
+                        }
+                    }
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|n| *n >= 1)
+                            .ok_or_else(|| invalid("COUNT must be a positive number"))?,
+                    );
+                }
+                other => return Err(invalid(format!("unsupported part {}", other))),
+            }
+        }
+
+        let frequency = frequency.ok_or_else(|| invalid("FREQ is required"))?;
+        if !by_day.is_empty() && frequency != Frequency::Weekly {
+            return Err(invalid("BYDAY is only supported with FREQ=WEEKLY"));
+        }
+        if until.is_some() && count.is_some() {
+            return Err(invalid("UNTIL and COUNT cannot be combined"));
+        }
+        by_day.sort_by_key(|d| d.num_days_from_monday());
+
+        Ok(RecurrenceRule { frequency, interval, by_day, until, count })
+    }
+
+    /// Local dates of the series in order, starting on `start`
+    fn dates(&self, start: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        let step = self.interval;
+        (0..MAX_PERIODS).flat_map(move |period| -> Vec<NaiveDate> {
+            let Some(n) = period.checked_mul(step) else {
+                return Vec::new();
+            };
+            match self.frequency {
+                Frequency::Daily => start.checked_add_days(Days::new(n as u64)).into_iter().collect(),
+                // Months without the start day (e.g. the 31st) are skipped, as in RFC 5545
+                Frequency::Monthly => start
+                    .checked_add_months(Months::new(n))
+                    .filter(|d| d.day() == start.day())
+                    .into_iter()
+                    .collect(),
+                Frequency::Weekly if self.by_day.is_empty() => {
+                    start.checked_add_days(Days::new(7 * n as u64)).into_iter().collect()
+                }
+                Frequency::Weekly => {
+                    // Weeks start on Monday (WKST=MO)
+                    let monday = start - Duration::days(start.weekday().num_days_from_monday() as i64);
+                    let week = monday + Duration::weeks(n as i64);
+                    self.by_day
+                        .iter()
+                        .map(|d| week + Duration::days(d.num_days_from_monday() as i64))
+                        .filter(|d| *d >= start)
+                        .collect()
+                }
+            }
+        })
+    }
+
+    /// The `index`-th occurrence (0 is the first) of a series starting at
+    /// `start`, or `None` once the series has ended
+    pub fn occurrence(&self, start: DateTime<Utc>, tz: Tz, index: u32) -> Option<DateTime<Utc>> {
+        if self.count.is_some_and(|count| index >= count) {
+            return None;
+        }
+        let local_start = start.with_timezone(&tz).naive_local();
+        let time = local_start.time();
+        let date = self.dates(local_start.date()).nth(index as usize)?;
+        let at = resolve_local(tz, date.and_time(time));
+        match self.until {
+            Some(until) if at > until => None,
+            _ => Some(at),
+        }
+    }
+}
+
+/// A local time as an instant: ambiguous times take the earlier instant and
+/// times skipped by a DST jump move forward by the size of the gap
+pub(crate) fn resolve_local(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(at) => at.with_timezone(&Utc),
+        LocalResult::Ambiguous(earlier, _) => earlier.with_timezone(&Utc),
+        LocalResult::None => {
+            // Read the local time with the offset in effect before the gap
+            let offset = tz
+                .offset_from_utc_datetime(&(local - Duration::hours(3)))
+                .fix()
+                .local_minus_utc();
+            (local - Duration::seconds(offset as i64)).and_utc()
+        }
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<&str> = self.by_day.iter().map(|d| weekday_code(*d)).collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        Ok(())
+    }
+}
+
+/// An IANA timezone name such as `Europe/Berlin`
+pub fn parse_timezone(name: &str) -> Result<Tz, AppError> {
+    name.parse::<Tz>()
+        .map_err(|_| AppError::Validation(format!("Unknown timezone: {}", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn series(rule: &str, start: &str, tz: &str, n: u32) -> Vec<DateTime<Utc>> {
+        let rule = RecurrenceRule::parse(rule).unwrap();
+        let tz = parse_timezone(tz).unwrap();
+        (0..n).map_while(|i| rule.occurrence(utc(start), tz, i)).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let rule = RecurrenceRule::parse("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TH,MO;COUNT=4").unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.by_day, vec![Weekday::Mon, Weekday::Thu]);
+        assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;COUNT=4");
+        assert_eq!(
+            RecurrenceRule::parse("FREQ=DAILY;UNTIL=20251231").unwrap().until,
+            Some(utc("2025-12-31T23:59:59Z"))
+        );
+
+        assert!(RecurrenceRule::parse("INTERVAL=2").is_err());
+        assert!(RecurrenceRule::parse("FREQ=YEARLY").is_err());
+        assert!(RecurrenceRule::parse("FREQ=DAILY;INTERVAL=0").is_err());
+        assert!(RecurrenceRule::parse("FREQ=MONTHLY;BYDAY=MO").is_err());
+        assert!(RecurrenceRule::parse("FREQ=DAILY;COUNT=2;UNTIL=20251231").is_err());
+        assert!(RecurrenceRule::parse("FREQ=DAILY;BYHOUR=9").is_err());
+    }
+
+    #[test]
+    fn test_weekly_by_day() {
+        // Starts on a Wednesday, so the first Monday of the first week is skipped
+        assert_eq!(
+            series("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE", "2025-11-05T09:00:00Z", "UTC", 4),
+            vec![
+                utc("2025-11-05T09:00:00Z"),
+                utc("2025-11-17T09:00:00Z"),
+                utc("2025-11-19T09:00:00Z"),
+                utc("2025-12-01T09:00:00Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monthly_skips_short_months_and_ends() {
+        assert_eq!(
+            series("FREQ=MONTHLY;COUNT=3", "2025-01-31T12:00:00Z", "UTC", 10),
+            vec![
+                utc("2025-01-31T12:00:00Z"),
+                utc("2025-03-31T12:00:00Z"),
+                utc("2025-05-31T12:00:00Z"),
+            ]
+        );
+        assert_eq!(
+            series("FREQ=DAILY;UNTIL=20251103", "2025-11-01T08:00:00Z", "UTC", 10).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_keeps_local_time_across_dst() {
+        // 09:00 in Berlin is 07:00 UTC in summer time and 08:00 UTC after the clocks go back
+        assert_eq!(
+            series("FREQ=WEEKLY", "2025-10-20T07:00:00Z", "Europe/Berlin", 2),
+            vec![utc("2025-10-20T07:00:00Z"), utc("2025-10-27T08:00:00Z")]
+        );
+        // 02:30 does not exist on the spring-forward day and moves to 03:30
+        assert_eq!(
+            series("FREQ=DAILY", "2025-03-29T01:30:00Z", "Europe/Berlin", 2),
+            vec![utc("2025-03-29T01:30:00Z"), utc("2025-03-30T01:30:00Z")]
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use axum::async_trait;
+use futures::StreamExt;
+use std::{sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::{config::ScannerConfig, services::storage::ByteStream, utils::AppError};
+
+/// Largest chunk sent to clamd in one INSTREAM frame
+const CLAMD_CHUNK_SIZE: usize = 64 * 1024;
+/// Longest reply read back from clamd
+const CLAMD_MAX_REPLY: u64 = 4096;
+
+/// What a scan found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanVerdict {
+    Clean,
+    /// Malware was found; holds the name of the matching signature
+    Infected(String),
+}
+
+/// Checks attachment content for malware before anyone can download it
+#[async_trait]
+pub trait AttachmentScanner: Send + Sync {
+    /// Scan the whole of `body`. Errors mean the content couldn't be scanned
+    /// and should be tried again later.
+    async fn scan(&self, body: ByteStream) -> Result<ScanVerdict, AppError>;
+}
+
+/// Build the scanner named by `SCANNER_BACKEND`
+pub fn from_config(config: &ScannerConfig) -> Result<Arc<dyn AttachmentScanner>, AppError> {
+    match config.backend.as_str() {
+        "none" => Ok(Arc::new(NoopScanner)),
+        "clamd" => Ok(Arc::new(ClamdScanner::new(
+            &config.clamd_address,
+            Duration::from_secs(config.timeout.max(1)),
+        ))),
+        other => Err(AppError::Validation(format!(
+            "Unknown scanner backend {}; use none or clamd",
+            other
+        ))),
+    }
+}
+
+/// Largest content the configured scanner accepts, if it has a limit
+pub fn size_limit(config: &ScannerConfig) -> Option<u64> {
+    (config.backend == "clamd" && config.max_size > 0).then_some(config.max_size)
+}
+
+/// Passes everything as clean, for deployments without a scanner
+pub struct NoopScanner;
+
+#[async_trait]
+impl AttachmentScanner for NoopScanner {
+    async fn scan(&self, _body: ByteStream) -> Result<ScanVerdict, AppError> {
+        Ok(ScanVerdict::Clean)
+    }
+}
+
+/// A ClamAV daemon reached over TCP, sent content with `INSTREAM`
+pub struct ClamdScanner {
+    address: String,
+    timeout: Duration,
+}
+
+impl ClamdScanner {
+    pub fn new(address: &str, timeout: Duration) -> Self {
+        ClamdScanner {
+            address: address.to_string(),
+            timeout,
+        }
+    }
+
+    async fn instream(&self, mut body: ByteStream) -> Result<String, AppError> {
+        let mut conn = TcpStream::connect(&self.address)
+            .await
+            .map_err(|e| clamd_error("Failed to connect to clamd", e))?;
+
+        // clamd stops reading and replies as soon as the stream exceeds its
+        // size limit, so a failed write may still leave a reply to read
+        let sent = async {
+            conn.write_all(b"zINSTREAM\0").await?;
+            while let Some(chunk) = body.next().await {
+                for part in chunk?.chunks(CLAMD_CHUNK_SIZE) {
+                    conn.write_all(&(part.len() as u32).to_be_bytes()).await?;
+                    conn.write_all(part).await?;
+                }
+            }
+            conn.write_all(&0u32.to_be_bytes()).await?;
+            conn.flush().await
+        }
+        .await;
+
+        let mut reply = Vec::new();
+        let read = (&mut conn)
+            .take(CLAMD_MAX_REPLY)
+            .read_to_end(&mut reply)
+            .await;
+        match (sent, read) {
+            (_, Ok(_)) if !reply.is_empty() => Ok(String::from_utf8_lossy(&reply).into_owned()),
+            (Err(e), _) => Err(clamd_error("Failed to send content to clamd", e)),
+            (Ok(()), Err(e)) => Err(clamd_error("Failed to read the clamd reply", e)),
+            (Ok(()), Ok(_)) => Err(AppError::ExternalService(
+                "clamd closed the connection without a reply".to_string(),
+            )),
+        }
+    }
+}
+
+fn clamd_error(context: &str, e: std::io::Error) -> AppError {
+    AppError::ExternalService(format!("{}: {}", context, e))
+}
+
+/// Interpret a reply such as `stream: OK` or `stream: Eicar-Signature FOUND`
+fn parse_clamd_reply(reply: &str) -> Result<ScanVerdict, AppError> {
+    let reply = reply.trim_end_matches(['\0', '\n']);
+    let result = reply.strip_prefix("stream: ").unwrap_or(reply);
+    if result == "OK" {
+        Ok(ScanVerdict::Clean)
+    } else if let Some(signature) = result.strip_suffix(" FOUND") {
+        Ok(ScanVerdict::Infected(signature.to_string()))
+    } else {
+        Err(AppError::ExternalService(format!(
+            "clamd failed to scan: {}",
+            result
+        )))
+    }
+}
+
+#[async_trait]
+impl AttachmentScanner for ClamdScanner {
+    async fn scan(&self, body: ByteStream) -> Result<ScanVerdict, AppError> {
+        let reply = tokio::time::timeout(self.timeout, self.instream(body))
+            .await
+            .map_err(|_| AppError::ExternalService("clamd timed out".to_string()))??;
+        parse_clamd_reply(&reply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use tokio::net::TcpListener;
+
+    fn body_of(chunks: Vec<Vec<u8>>) -> ByteStream {
+        Box::pin(futures::stream::iter(
+            chunks.into_iter().map(|c| Ok(Bytes::from(c))),
+        ))
+    }
+
+    /// Speaks enough of the clamd protocol to answer one INSTREAM per
+    /// connection, flagging content that contains `EICAR` or exceeds `limit`
+    async fn stub_clamd(limit: usize) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut conn, _) = listener.accept().await.unwrap();
+                tokio::spawn(async move {
+                    let mut command = [0u8; 10];
+                    conn.read_exact(&mut command).await.unwrap();
+                    assert_eq!(&command, b"zINSTREAM\0");
+
+                    let mut content = Vec::new();
+                    let reply: &[u8] = loop {
+                        let len = conn.read_u32().await.unwrap() as usize;
+                        if len == 0 {
+                            break if content.windows(5).any(|w| w == b"EICAR") {
+                                b"stream: Eicar-Test-Signature FOUND\0"
+                            } else {
+                                b"stream: OK\0"
+                            };
+                        }
+                        assert!(len <= CLAMD_CHUNK_SIZE);
+                        let start = content.len();
+                        content.resize(start + len, 0);
+                        conn.read_exact(&mut content[start..]).await.unwrap();
+                        if content.len() > limit {
+                            break b"INSTREAM size limit exceeded. ERROR\0";
+                        }
+                    };
+                    conn.write_all(reply).await.unwrap();
+                });
+            }
+        });
+        addr.to_string()
+    }
+
+    #[test]
+    fn test_parse_clamd_reply() {
+        assert_eq!(
+            parse_clamd_reply("stream: OK\0").unwrap(),
+            ScanVerdict::Clean
+        );
+        assert_eq!(
+            parse_clamd_reply("stream: Win.Test.EICAR_HDB-1 FOUND\0").unwrap(),
+            ScanVerdict::Infected("Win.Test.EICAR_HDB-1".to_string())
+        );
+        assert!(matches!(
+            parse_clamd_reply("INSTREAM size limit exceeded. ERROR\0"),
+            Err(AppError::ExternalService(_))
+        ));
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let mut config = ScannerConfig {
+            backend: "clamd".to_string(),
+            clamd_address: String::new(),
+            timeout: 60,
+            interval: 5,
+            max_size: 1024,
+        };
+        assert_eq!(size_limit(&config), Some(1024));
+        config.max_size = 0;
+        assert_eq!(size_limit(&config), None);
+        config.backend = "none".to_string();
+        config.max_size = 1024;
+        assert_eq!(size_limit(&config), None);
+    }
+
+    #[tokio::test]
+    async fn test_clamd_scanner_streams_content() {
+        let addr = stub_clamd(1024 * 1024).await;
+        let scanner = ClamdScanner::new(&addr, Duration::from_secs(5));
+
+        // Larger than one frame, so it is split
+        let clean = body_of(vec![b"hello ".to_vec(), vec![b'x'; 3 * CLAMD_CHUNK_SIZE]]);
+        assert_eq!(scanner.scan(clean).await.unwrap(), ScanVerdict::Clean);
+
+        let infected = body_of(vec![b"X5O!P%@AP...EI".to_vec(), b"CAR-STANDARD".to_vec()]);
+        assert_eq!(
+            scanner.scan(infected).await.unwrap(),
+            ScanVerdict::Infected("Eicar-Test-Signature".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clamd_scanner_reports_errors() {
+        let addr = stub_clamd(16).await;
+        let scanner = ClamdScanner::new(&addr, Duration::from_secs(5));
+        assert!(matches!(
+            scanner.scan(body_of(vec![vec![b'x'; 100]])).await,
+            Err(AppError::ExternalService(_))
+        ));
+
+        // Nothing listening
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        let scanner = ClamdScanner::new(&addr, Duration::from_secs(5));
+        assert!(matches!(
+            scanner.scan(body_of(vec![b"hello".to_vec()])).await,
+            Err(AppError::ExternalService(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_noop_scanner_passes_everything() {
+        let body = body_of(vec![b"EICAR".to_vec()]);
+        assert_eq!(NoopScanner.scan(body).await.unwrap(), ScanVerdict::Clean);
+    }
+}
@@ -0,0 +1,307 @@
+use bytes::Bytes;
+use futures::TryStreamExt;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    config::ScannerConfig,
+    services::{
+        blobs::{blob_key, quarantine_key, thumbnail_key},
+        images::has_thumbnail,
+        scanner::{self, AttachmentScanner, ScanVerdict},
+        storage::StorageBackend,
+    },
+    utils::AppError,
+};
+
+/// Attachments moved or blobs scanned per pass
+const BATCH_SIZE: usize = 20;
+
+/// Move attachments uploaded before deduplication onto blobs, so they are
+/// scanned like any other. Returns the number moved.
+///
+/// Attachments whose file is missing are added to `missing` and skipped from
+/// then on; storage errors end the pass.
+pub async fn adopt_legacy_attachments(
+    db: &PgPool,
+    storage: &dyn StorageBackend,
+    missing: &mut Vec<Uuid>,
+) -> Result<u64, AppError> {
+    let mut adopted = 0;
+    for _ in 0..BATCH_SIZE {
+        let mut tx = db.begin().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to start transaction".to_string())
+        })?;
+
+        let Some(attachment) = sqlx::query!(
+            r#"
+            SELECT id, file_path, mime_type
+            FROM task_attachments
+            WHERE blob_sha256 IS NULL AND NOT id = ANY($1)
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+            "#,
+            &missing[..]
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to find attachments without a blob".to_string())
+        })?
+        else {
+            break;
+        };
+
+        let object = match storage.get(&attachment.file_path).await {
+            Ok(object) => object,
+            Err(AppError::NotFound(_)) => {
+                tracing::warn!("Attachment {} has no stored file", attachment.id);
+                missing.push(attachment.id);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        let data: Vec<u8> = object
+            .body
+            .try_fold(
+                Vec::with_capacity(object.size as usize),
+                |mut acc, chunk| async move {
+                    acc.extend_from_slice(&chunk);
+                    Ok(acc)
+                },
+            )
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to read attachment {}: {}", attachment.id, e);
+                AppError::Internal("Failed to read attachment file".to_string())
+            })?;
+        let sha256 = hex::encode(Sha256::digest(&data));
+        let size = data.len() as u64;
+
+        let claim = sqlx::query!(
+            r#"
+            INSERT INTO attachment_blobs (sha256, size)
+            VALUES ($1, $2)
+            ON CONFLICT (sha256) DO UPDATE SET last_used_at = NOW()
+            RETURNING stored
+            "#,
+            sha256,
+            size as i64
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to claim attachment blob".to_string())
+        })?;
+
+        if !claim.stored {
+            let body = futures::stream::once(async move { Ok(Bytes::from(data)) });
+            storage
+                .put(
+                    &blob_key(&sha256),
+                    Box::pin(body),
+                    size,
+                    &attachment.mime_type,
+                )
+                .await?;
+            sqlx::query!(
+                "UPDATE attachment_blobs SET stored = TRUE, thumbnail_status = $2 WHERE sha256 = $1",
+                sha256,
+                has_thumbnail(&attachment.mime_type).then_some("pending")
+            )
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error: {}", e);
+                AppError::DatabaseError("Failed to update attachment blob".to_string())
+            })?;
+        }
+
+        sqlx::query!(
+            "UPDATE task_attachments SET blob_sha256 = $2, file_path = $3 WHERE id = $1",
+            attachment.id,
+            sha256,
+            blob_key(&sha256)
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to move attachment onto its blob".to_string())
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to commit attachment blob".to_string())
+        })?;
+        adopted += 1;
+
+        if let Err(e) = storage.delete(&attachment.file_path).await {
+            tracing::warn!(
+                "Failed to remove attachment file {}: {}",
+                attachment.file_path,
+                e
+            );
+        }
+    }
+
+    Ok(adopted)
+}
+
+/// Move infected content out of the blob's key so it can no longer be
+/// served, keeping a copy for review, and drop any thumbnail of it
+async fn quarantine(storage: &dyn StorageBackend, sha256: &str) -> Result<(), AppError> {
+    storage.delete(&thumbnail_key(sha256)).await?;
+    let object = storage.get(&blob_key(sha256)).await?;
+    storage
+        .put(
+            &quarantine_key(sha256),
+            object.body,
+            object.size,
+            "application/octet-stream",
+        )
+        .await?;
+    storage.delete(&blob_key(sha256)).await
+}
+
+/// Scan pending blobs for malware, quarantining infected ones. Returns the
+/// number scanned.
+///
+/// Each blob stays locked while it is scanned. The pass stops at the first
+/// blob that can't be scanned, which is tried again after the others. Blobs
+/// over `max_size`, which the scanner would refuse, stay pending unscanned.
+pub async fn scan_pending(
+    db: &PgPool,
+    storage: &dyn StorageBackend,
+    scanner: &dyn AttachmentScanner,
+    max_size: Option<u64>,
+) -> Result<u64, AppError> {
+    let mut scanned = 0;
+    for _ in 0..BATCH_SIZE {
+        let mut tx = db.begin().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to start transaction".to_string())
+        })?;
+
+        let Some(sha256) = sqlx::query_scalar!(
+            r#"
+            SELECT sha256
+            FROM attachment_blobs
+            WHERE scan_status = 'pending' AND stored
+              AND ($1::BIGINT IS NULL OR size <= $1)
+            ORDER BY scanned_at NULLS FIRST, created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+            "#,
+            max_size.map(|size| size as i64)
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to find pending scans".to_string())
+        })?
+        else {
+            break;
+        };
+
+        let verdict = match storage.get(&blob_key(&sha256)).await {
+            Ok(object) => scanner.scan(object.body).await,
+            Err(e) => Err(e),
+        };
+        let verdict = match verdict {
+            Ok(ScanVerdict::Infected(signature)) => quarantine(storage, &sha256)
+                .await
+                .map(|()| ScanVerdict::Infected(signature)),
+            other => other,
+        };
+
+        let (status, signature) = match verdict {
+            Ok(ScanVerdict::Clean) => ("clean", None),
+            Ok(ScanVerdict::Infected(signature)) => {
+                tracing::warn!("Quarantined blob {}: {} found", sha256, signature);
+                ("infected", Some(signature))
+            }
+            Err(e) => {
+                // Record the attempt so the next pass starts elsewhere
+                sqlx::query!(
+                    "UPDATE attachment_blobs SET scanned_at = NOW() WHERE sha256 = $1",
+                    sha256
+                )
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    tracing::error!("Database error: {}", e);
+                    AppError::DatabaseError("Failed to record scan attempt".to_string())
+                })?;
+                tx.commit().await.map_err(|e| {
+                    tracing::error!("Database error: {}", e);
+                    AppError::DatabaseError("Failed to commit scan attempt".to_string())
+                })?;
+                tracing::warn!("Failed to scan blob {}", sha256);
+                return Err(e);
+            }
+        };
+
+        sqlx::query!(
+            r#"
+            UPDATE attachment_blobs
+            SET scan_status = $2::VARCHAR, scan_signature = $3, scanned_at = NOW(),
+                thumbnail_status = CASE WHEN $2::VARCHAR = 'infected' THEN NULL ELSE thumbnail_status END
+            WHERE sha256 = $1
+            "#,
+            sha256,
+            status,
+            signature
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to record scan result".to_string())
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to commit scan result".to_string())
+        })?;
+        scanned += 1;
+    }
+
+    Ok(scanned)
+}
+
+/// Run `adopt_legacy_attachments` and `scan_pending` in the background every
+/// `interval` seconds
+pub fn spawn_scan_job(
+    db: PgPool,
+    storage: Arc<dyn StorageBackend>,
+    scanner: Arc<dyn AttachmentScanner>,
+    config: ScannerConfig,
+) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(config.interval.max(1)));
+        let mut missing = Vec::new();
+        let max_size = scanner::size_limit(&config);
+        loop {
+            interval.tick().await;
+            match adopt_legacy_attachments(&db, storage.as_ref(), &mut missing).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Moved {} older attachments onto blobs", count),
+                Err(e) => tracing::error!("Moving older attachments onto blobs failed: {}", e),
+            }
+            match scan_pending(&db, storage.as_ref(), scanner.as_ref(), max_size).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Scanned {} attachment blobs", count),
+                Err(e) => tracing::error!("Attachment scanning failed: {}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,91 @@
+use crate::utils::{split_list, AppError};
+
+/// Longest accepted search query, in characters
+pub const MAX_QUERY_LENGTH: usize = 500;
+
+/// Options passed to `ts_headline` when building snippets
+pub const HEADLINE_OPTIONS: &str =
+    "StartSel=<mark>, StopSel=</mark>, MaxWords=30, MinWords=10, MaxFragments=2, FragmentDelimiter=\" … \"";
+
+/// Kinds of records covered by search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Task,
+    Comment,
+    Project,
+}
+
+impl SearchKind {
+    pub const ALL: [SearchKind; 3] = [SearchKind::Task, SearchKind::Comment, SearchKind::Project];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchKind::Task => "task",
+            SearchKind::Comment => "comment",
+            SearchKind::Project => "project",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "task" => Some(SearchKind::Task),
+            "comment" => Some(SearchKind::Comment),
+            "project" => Some(SearchKind::Project),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the comma-separated `types` parameter, defaulting to every kind
+pub fn parse_kinds(types: Option<&str>) -> Result<Vec<SearchKind>, AppError> {
+    let Some(names) = split_list(types) else {
+        return Ok(SearchKind::ALL.to_vec());
+    };
+
+    let mut kinds = Vec::with_capacity(names.len());
+    for name in names {
+        let kind = SearchKind::parse(&name)
+            .ok_or_else(|| AppError::Validation(format!("Invalid search type: {}", name)))?;
+        if !kinds.contains(&kind) {
+            kinds.push(kind);
+        }
+    }
+    Ok(kinds)
+}
+
+/// Trim and bound the user's search text
+pub fn normalize_query(q: &str) -> Result<String, AppError> {
+    let q = q.trim();
+    if q.is_empty() {
+        return Err(AppError::Validation("Search query cannot be empty".to_string()));
+    }
+    if q.chars().count() > MAX_QUERY_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Search query is limited to {} characters",
+            MAX_QUERY_LENGTH
+        )));
+    }
+    Ok(q.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kinds() {
+        assert_eq!(parse_kinds(None).unwrap(), SearchKind::ALL.to_vec());
+        assert_eq!(
+            parse_kinds(Some("comment, task,comment")).unwrap(),
+            vec![SearchKind::Comment, SearchKind::Task]
+        );
+        assert!(parse_kinds(Some("task,label")).is_err());
+    }
+
+    #[test]
+    fn test_normalize_query() {
+        assert_eq!(normalize_query("  login bug ").unwrap(), "login bug");
+        assert!(normalize_query("   ").is_err());
+        assert!(normalize_query(&"a".repeat(MAX_QUERY_LENGTH + 1)).is_err());
+    }
+}
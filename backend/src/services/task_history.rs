@@ -0,0 +1,154 @@
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::models::Task;
+
+/// One field of a task that differs between two versions
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old_value: Option<Value>,
+    pub new_value: Option<Value>,
+}
+
+fn json<T: Serialize>(value: &Option<T>) -> Option<Value> {
+    value
+        .as_ref()
+        .map(|v| serde_json::to_value(v).expect("task fields serialize to JSON"))
+}
+
+/// The tracked fields that changed from `old` to `new`. Derived columns such as
+/// `rank`, `version` and timestamps are not part of the history.
+pub fn diff_tasks(old: &Task, new: &Task) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let mut track = |field: &'static str, old_value: Option<Value>, new_value: Option<Value>| {
+        if old_value != new_value {
+            changes.push(FieldChange { field, old_value, new_value });
+        }
+    };
+
+    track("title", json(&Some(&old.title)), json(&Some(&new.title)));
+    track("description", json(&old.description), json(&new.description));
+    track("status", json(&Some(&old.status)), json(&Some(&new.status)));
+    track("priority", json(&Some(&old.priority)), json(&Some(&new.priority)));
+    track("assigned_to", json(&old.assigned_to), json(&new.assigned_to));
+    track("due_date", json(&old.due_date), json(&new.due_date));
+    track(
+        "original_estimate_seconds",
+        json(&old.original_estimate_seconds),
+        json(&new.original_estimate_seconds),
+    );
+    track(
+        "remaining_estimate_seconds",
+        json(&old.remaining_estimate_seconds),
+        json(&new.remaining_estimate_seconds),
+    );
+    track("project_id", json(&Some(old.project_id)), json(&Some(new.project_id)));
+
+    changes
+}
+
+/// The change of a task's assignees, if the set of users differs
+pub fn diff_assignees(old: &[Uuid], new: &[Uuid]) -> Option<FieldChange> {
+    let mut old_sorted = old.to_vec();
+    let mut new_sorted = new.to_vec();
+    old_sorted.sort();
+    new_sorted.sort();
+    if old_sorted == new_sorted {
+        return None;
+    }
+
+    let list = |ids: &[Uuid]| if ids.is_empty() { None } else { json(&Some(ids)) };
+    Some(FieldChange {
+        field: "assignees",
+        old_value: list(old),
+        new_value: list(new),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+
+    fn task() -> Task {
+        let now = Utc::now();
+        Task {
+            id: Uuid::new_v4(),
+            project_id: Uuid::new_v4(),
+            title: "Write docs".to_string(),
+            description: Some("draft".to_string()),
+            status: "todo".to_string(),
+            priority: "medium".to_string(),
+            assigned_to: None,
+            created_by: Uuid::new_v4(),
+            due_date: None,
+            original_estimate_seconds: None,
+            remaining_estimate_seconds: None,
+            completed_at: None,
+            rank: "V".to_string(),
+            number: 1,
+            version: 1,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_diff_tasks() {
+        let old = task();
+        let assignee = Uuid::new_v4();
+        let new = Task {
+            status: "done".to_string(),
+            description: None,
+            assigned_to: Some(assignee),
+            rank: "k".to_string(),
+            version: 2,
+            completed_at: Some(Utc::now()),
+            ..old.clone()
+        };
+
+        assert_eq!(
+            diff_tasks(&old, &new),
+            vec![
+                FieldChange {
+                    field: "description",
+                    old_value: Some(json!("draft")),
+                    new_value: None,
+                },
+                FieldChange {
+                    field: "status",
+                    old_value: Some(json!("todo")),
+                    new_value: Some(json!("done")),
+                },
+                FieldChange {
+                    field: "assigned_to",
+                    old_value: None,
+                    new_value: Some(json!(assignee.to_string())),
+                },
+            ]
+        );
+        assert!(diff_tasks(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn test_diff_assignees() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+
+        assert_eq!(diff_assignees(&[a, b], &[b, a]), None);
+        assert_eq!(
+            diff_assignees(&[], &[a, b]),
+            Some(FieldChange {
+                field: "assignees",
+                old_value: None,
+                new_value: Some(json!([a.to_string(), b.to_string()])),
+            })
+        );
+        assert_eq!(
+            diff_assignees(&[a], &[]).map(|change| change.new_value),
+            Some(None)
+        );
+    }
+}
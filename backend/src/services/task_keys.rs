@@ -0,0 +1,105 @@
+use crate::utils::AppError;
+
+const MAX_PROJECT_KEY_LEN: usize = 10;
+
+/// Default key for a project: the first three letters of its name, upper-cased.
+/// Matches the backfill in the migration that introduced keys.
+pub fn derive_project_key(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .take(3)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if key.is_empty() {
+        "PRJ".to_string()
+    } else {
+        key
+    }
+}
+
+/// Upper-case letters and digits, starting with a letter
+pub fn validate_project_key(key: &str) -> Result<(), AppError> {
+    let valid = key.len() <= MAX_PROJECT_KEY_LEN
+        && key.chars().next().is_some_and(|c| c.is_ascii_uppercase())
+        && key.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    if valid {
+        Ok(())
+    } else {
+        Err(AppError::Validation(format!(
+            "Project key must be 1 to {} upper-case letters or digits, starting with a letter",
+            MAX_PROJECT_KEY_LEN
+        )))
+    }
+}
+
+/// The first of `base`, `base2`, `base3`, ... not in `taken`
+pub fn first_free_key(base: &str, taken: &[String]) -> String {
+    if !taken.iter().any(|k| k == base) {
+        return base.to_string();
+    }
+    (2..)
+        .map(|n| {
+            let suffix = n.to_string();
+            let prefix_len = base.len().min(MAX_PROJECT_KEY_LEN - suffix.len());
+            format!("{}{}", &base[..prefix_len], suffix)
+        })
+        .find(|key| !taken.contains(key))
+        .expect("some numbered key is free")
+}
+
+pub fn format_task_key(project_key: &str, number: i32) -> String {
+    format!("{}-{}", project_key, number)
+}
+
+/// Split `WEB-123` into its project key and task number. Keys are matched
+/// case-insensitively, so `web-123` works too.
+pub fn parse_task_key(key: &str) -> Result<(String, i32), AppError> {
+    let invalid = || AppError::Validation(format!("Invalid task key: {}", key));
+    let (project_key, number) = key.rsplit_once('-').ok_or_else(invalid)?;
+    let project_key = project_key.to_ascii_uppercase();
+    validate_project_key(&project_key).map_err(|_| invalid())?;
+    let number: i32 = number.parse().map_err(|_| invalid())?;
+    if number < 1 {
+        return Err(invalid());
+    }
+    Ok((project_key, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_project_key() {
+        assert_eq!(derive_project_key("Website"), "WEB");
+        assert_eq!(derive_project_key("a b"), "AB");
+        assert_eq!(derive_project_key("2024 Q1 plan"), "QPL");
+        assert_eq!(derive_project_key("42"), "PRJ");
+        assert!(validate_project_key("WEB2").is_ok());
+        assert!(validate_project_key("2WEB").is_err());
+        assert!(validate_project_key("web").is_err());
+        assert!(validate_project_key("").is_err());
+        assert!(validate_project_key("ABCDEFGHIJK").is_err());
+    }
+
+    #[test]
+    fn test_first_free_key() {
+        let taken = vec!["WEB".to_string(), "WEB2".to_string()];
+        assert_eq!(first_free_key("APP", &taken), "APP");
+        assert_eq!(first_free_key("WEB", &taken), "WEB3");
+        let taken = vec!["ABCDEFGHIJ".to_string()];
+        assert_eq!(first_free_key("ABCDEFGHIJ", &taken), "ABCDEFGHI2");
+    }
+
+    #[test]
+    fn test_parse_task_key() {
+        assert_eq!(parse_task_key("WEB-123").unwrap(), ("WEB".to_string(), 123));
+        assert_eq!(parse_task_key("web-7").unwrap(), ("WEB".to_string(), 7));
+        assert_eq!(format_task_key("WEB", 123), "WEB-123");
+        assert!(parse_task_key("WEB123").is_err());
+        assert!(parse_task_key("WEB-0").is_err());
+        assert!(parse_task_key("WEB-x").is_err());
+        assert!(parse_task_key("-1").is_err());
+    }
+}
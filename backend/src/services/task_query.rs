@@ -0,0 +1,1166 @@
+//! A small query language for advanced task filters, e.g.
+//!
+//! ```text
+//! assignee = me AND priority in (high, urgent) AND due < +7d AND NOT label = "blocked"
+//! ```
+//!
+//! Queries are parsed into a typed AST and compiled into parameterized SQL on the
+//! `tasks t` alias. User input only ever reaches the database as bind parameters.
+
+use std::fmt;
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, TimeZone, Utc};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::utils::{escape_like, AppError};
+
+/// Longest accepted query, in characters
+pub const MAX_QUERY_LENGTH: usize = 2000;
+
+/// Deepest accepted nesting of parentheses and `NOT`
+const MAX_DEPTH: usize = 32;
+
+/// Largest accepted relative date offset, in units
+const MAX_RELATIVE_AMOUNT: i64 = 10_000;
+
+/// SQL ranking tasks from most (`0`) to least urgent
+pub const PRIORITY_RANK_SQL: &str =
+    "CASE t.priority WHEN 'urgent' THEN 0 WHEN 'high' THEN 1 WHEN 'medium' THEN 2 WHEN 'low' THEN 3 ELSE 4 END";
+
+/// A syntax or type error, located by its 1-based character position
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    pub position: usize,
+    pub message: String,
+}
+
+impl QueryError {
+    fn new(position: usize, message: impl Into<String>) -> Self {
+        QueryError {
+            position,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl From<QueryError> for AppError {
+    fn from(err: QueryError) -> Self {
+        AppError::Validation(format!("Invalid query: {}", err))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// AST
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Condition(Condition),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub field: Field,
+    pub predicate: Predicate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    Compare(CompareOp, Value),
+    /// `~` and `!~`: case-insensitive substring match
+    Contains { negated: bool, text: String },
+    In { negated: bool, values: Vec<Value> },
+    IsEmpty { negated: bool },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CompareOp {
+    fn as_str(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "=",
+            CompareOp::Ne => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+        }
+    }
+
+    fn is_ordering(&self) -> bool {
+        !matches!(self, CompareOp::Eq | CompareOp::Ne)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Description,
+    Status,
+    Priority,
+    Assignee,
+    Creator,
+    Label,
+    Due,
+    Created,
+    Updated,
+    Completed,
+}
+
+/// How values of a field are typed and compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Text,
+    Status,
+    Priority,
+    User,
+    Label,
+    Date,
+}
+
+impl Field {
+    pub const ALL: [Field; 11] = [
+        Field::Title,
+        Field::Description,
+        Field::Status,
+        Field::Priority,
+        Field::Assignee,
+        Field::Creator,
+        Field::Label,
+        Field::Due,
+        Field::Created,
+        Field::Updated,
+        Field::Completed,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Field::Title => "title",
+            Field::Description => "description",
+            Field::Status => "status",
+            Field::Priority => "priority",
+            Field::Assignee => "assignee",
+            Field::Creator => "creator",
+            Field::Label => "label",
+            Field::Due => "due",
+            Field::Created => "created",
+            Field::Updated => "updated",
+            Field::Completed => "completed",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        Field::ALL.into_iter().find(|field| field.as_str() == name)
+    }
+
+    fn kind(&self) -> FieldKind {
+        match self {
+            Field::Title | Field::Description => FieldKind::Text,
+            Field::Status => FieldKind::Status,
+            Field::Priority => FieldKind::Priority,
+            Field::Assignee | Field::Creator => FieldKind::User,
+            Field::Label => FieldKind::Label,
+            Field::Due | Field::Created | Field::Updated | Field::Completed => FieldKind::Date,
+        }
+    }
+
+    /// Whether `is empty` makes sense for the field
+    fn is_optional(&self) -> bool {
+        matches!(
+            self,
+            Field::Description | Field::Assignee | Field::Label | Field::Due | Field::Completed
+        )
+    }
+
+    fn date_column(&self) -> &'static str {
+        match self {
+            Field::Due => "t.due_date",
+            Field::Created => "t.created_at",
+            Field::Updated => "t.updated_at",
+            Field::Completed => "t.completed_at",
+            _ => unreachable!("not a date field"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Priority(Priority),
+    User(UserRef),
+    Date(DateValue),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+    Urgent,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 4] = [Priority::Low, Priority::Medium, Priority::High, Priority::Urgent];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+            Priority::Urgent => "urgent",
+        }
+    }
+
+    /// Position in `PRIORITY_RANK_SQL`
+    fn rank(&self) -> i32 {
+        match self {
+            Priority::Urgent => 0,
+            Priority::High => 1,
+            Priority::Medium => 2,
+            Priority::Low => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserRef {
+    Me,
+    Id(Uuid),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateUnit {
+    Day,
+    Week,
+}
+
+/// A calendar day in the caller's timezone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateValue {
+    Today,
+    Absolute(NaiveDate),
+    /// Offset from today, e.g. `+7d` or `-2w`
+    Relative { amount: i64, unit: DateUnit },
+}
+
+// ---------------------------------------------------------------------------
+// Printing (the canonical form parses back to the same AST)
+// ---------------------------------------------------------------------------
+
+fn write_quoted(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in text.chars() {
+        if c == '"' || c == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "\"")
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => write_quoted(f, text),
+            Value::Priority(priority) => write!(f, "{}", priority.as_str()),
+            Value::User(UserRef::Me) => write!(f, "me"),
+            Value::User(UserRef::Id(id)) => write!(f, "{}", id),
+            Value::Date(DateValue::Today) => write!(f, "today"),
+            Value::Date(DateValue::Absolute(date)) => write!(f, "{}", date.format("%Y-%m-%d")),
+            Value::Date(DateValue::Relative { amount, unit }) => {
+                let unit = match unit {
+                    DateUnit::Day => "d",
+                    DateUnit::Week => "w",
+                };
+                write!(f, "{:+}{}", amount, unit)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::And(left, right) => write!(f, "({} AND {})", left, right),
+            Expr::Or(left, right) => write!(f, "({} OR {})", left, right),
+            Expr::Not(inner) => write!(f, "NOT {}", inner),
+            Expr::Condition(condition) => {
+                let field = condition.field.as_str();
+                match &condition.predicate {
+                    Predicate::Compare(op, value) => write!(f, "{} {} {}", field, op.as_str(), value),
+                    Predicate::Contains { negated, text } => {
+                        write!(f, "{} {} ", field, if *negated { "!~" } else { "~" })?;
+                        write_quoted(f, text)
+                    }
+                    Predicate::In { negated, values } => {
+                        write!(f, "{} {}(", field, if *negated { "not in " } else { "in " })?;
+                        for (i, value) in values.iter().enumerate() {
+                            if i > 0 {
+                                write!(f, ", ")?;
+                            }
+                            write!(f, "{}", value)?;
+                        }
+                        write!(f, ")")
+                    }
+                    Predicate::IsEmpty { negated } => {
+                        write!(f, "{} {}", field, if *negated { "is not empty" } else { "is empty" })
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Lexer
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    Comma,
+    Compare(CompareOp),
+    Tilde,
+    NotTilde,
+    Word(String),
+    Quoted(String),
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, '(' | ')' | ',' | '=' | '!' | '<' | '>' | '~' | '"')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, QueryError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let position = i + 1;
+        let next = chars.get(i + 1).copied();
+
+        let (kind, len) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (TokenKind::LParen, 1),
+            ')' => (TokenKind::RParen, 1),
+            ',' => (TokenKind::Comma, 1),
+            '~' => (TokenKind::Tilde, 1),
+            '=' => (TokenKind::Compare(CompareOp::Eq), 1),
+            '!' if next == Some('=') => (TokenKind::Compare(CompareOp::Ne), 2),
+            '!' if next == Some('~') => (TokenKind::NotTilde, 2),
+            '!' => return Err(QueryError::new(position, "Expected '!=' or '!~'")),
+            '<' if next == Some('=') => (TokenKind::Compare(CompareOp::Le), 2),
+            '<' => (TokenKind::Compare(CompareOp::Lt), 1),
+            '>' if next == Some('=') => (TokenKind::Compare(CompareOp::Ge), 2),
+            '>' => (TokenKind::Compare(CompareOp::Gt), 1),
+            '"' => {
+                let mut text = String::new();
+                let mut j = i + 1;
+                loop {
+                    match chars.get(j) {
+                        None => return Err(QueryError::new(position, "Unterminated string")),
+                        Some('"') => break,
+                        Some('\\') => match chars.get(j + 1) {
+                            Some(&escaped @ ('"' | '\\')) => {
+                                text.push(escaped);
+                                j += 2;
+                            }
+                            _ => {
+                                return Err(QueryError::new(
+                                    j + 1,
+                                    "Only \\\" and \\\\ escapes are supported",
+                                ))
+                            }
+                        },
+                        Some(&other) => {
+                            text.push(other);
+                            j += 1;
+                        }
+                    }
+                }
+                (TokenKind::Quoted(text), j + 1 - i)
+            }
+            _ => {
+                let len = chars[i..].iter().take_while(|c| is_word_char(**c)).count();
+                (TokenKind::Word(chars[i..i + len].iter().collect()), len)
+            }
+        };
+
+        tokens.push(Token { kind, position });
+        i += len;
+    }
+
+    tokens.push(Token {
+        kind: TokenKind::End,
+        position: chars.len() + 1,
+    });
+    Ok(tokens)
+}
+
+// ---------------------------------------------------------------------------
+// Parser
+// ---------------------------------------------------------------------------
+
+struct Parser {
+    tokens: Vec<Token>,
+    index: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.index]
+    }
+
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.index].clone();
+        if token.kind != TokenKind::End {
+            self.index += 1;
+        }
+        token
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.peek().kind, TokenKind::Word(w) if w.eq_ignore_ascii_case(keyword))
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), QueryError> {
+        if self.peek_keyword(keyword) {
+            self.advance();
+            Ok(())
+        } else {
+            Err(QueryError::new(
+                self.peek().position,
+                format!("Expected '{}'", keyword),
+            ))
+        }
+    }
+
+    fn parse_or(&mut self, depth: usize) -> Result<Expr, QueryError> {
+        let mut expr = self.parse_and(depth)?;
+        while self.peek_keyword("or") {
+            self.advance();
+            let right = self.parse_and(depth)?;
+            expr = Expr::Or(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self, depth: usize) -> Result<Expr, QueryError> {
+        let mut expr = self.parse_not(depth)?;
+        while self.peek_keyword("and") {
+            self.advance();
+            let right = self.parse_not(depth)?;
+            expr = Expr::And(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self, depth: usize) -> Result<Expr, QueryError> {
+        if depth > MAX_DEPTH {
+            return Err(QueryError::new(self.peek().position, "Query is nested too deeply"));
+        }
+        if self.peek_keyword("not") {
+            self.advance();
+            let inner = self.parse_not(depth + 1)?;
+            return Ok(Expr::Not(Box::new(inner)));
+        }
+        self.parse_primary(depth)
+    }
+
+    fn parse_primary(&mut self, depth: usize) -> Result<Expr, QueryError> {
+        let token = self.advance();
+        match token.kind {
+            TokenKind::LParen => {
+                let expr = self.parse_or(depth + 1)?;
+                let close = self.advance();
+                if close.kind != TokenKind::RParen {
+                    return Err(QueryError::new(close.position, "Expected ')'"));
+                }
+                Ok(expr)
+            }
+            TokenKind::Word(ref name) => {
+                let field = Field::parse(name).ok_or_else(|| {
+                    QueryError::new(token.position, format!("Unknown field '{}'", name))
+                })?;
+                let predicate = self.parse_predicate(field)?;
+                Ok(Expr::Condition(Condition { field, predicate }))
+            }
+            TokenKind::End => Err(QueryError::new(token.position, "Expected a condition")),
+            _ => Err(QueryError::new(token.position, "Expected a field name or '('")),
+        }
+    }
+
+    fn parse_predicate(&mut self, field: Field) -> Result<Predicate, QueryError> {
+        let token = self.advance();
+        let position = token.position;
+        let unsupported = |op: &str| {
+            QueryError::new(
+                position,
+                format!("Operator '{}' is not supported for field '{}'", op, field.as_str()),
+            )
+        };
+
+        match token.kind {
+            TokenKind::Compare(op) => {
+                let ordered = matches!(field.kind(), FieldKind::Priority | FieldKind::Date);
+                if op.is_ordering() && !ordered {
+                    return Err(unsupported(op.as_str()));
+                }
+                let value = self.parse_value(field)?;
+                Ok(Predicate::Compare(op, value))
+            }
+            TokenKind::Tilde | TokenKind::NotTilde => {
+                let negated = token.kind == TokenKind::NotTilde;
+                if field.kind() != FieldKind::Text {
+                    return Err(unsupported(if negated { "!~" } else { "~" }));
+                }
+                match self.parse_value(field)? {
+                    Value::Text(text) => Ok(Predicate::Contains { negated, text }),
+                    _ => unreachable!("text fields take text values"),
+                }
+            }
+            TokenKind::Word(ref word) if word.eq_ignore_ascii_case("in") => {
+                self.parse_in_list(field, false, position)
+            }
+            TokenKind::Word(ref word) if word.eq_ignore_ascii_case("not") => {
+                self.expect_keyword("in")?;
+                self.parse_in_list(field, true, position)
+            }
+            TokenKind::Word(ref word) if word.eq_ignore_ascii_case("is") => {
+                if !field.is_optional() {
+                    return Err(unsupported("is empty"));
+                }
+                let negated = self.peek_keyword("not");
+                if negated {
+                    self.advance();
+                }
+                self.expect_keyword("empty")?;
+                Ok(Predicate::IsEmpty { negated })
+            }
+            _ => Err(QueryError::new(position, "Expected an operator")),
+        }
+    }
+
+    fn parse_in_list(
+        &mut self,
+        field: Field,
+        negated: bool,
+        position: usize,
+    ) -> Result<Predicate, QueryError> {
+        if matches!(field.kind(), FieldKind::Text | FieldKind::Date) {
+            return Err(QueryError::new(
+                position,
+                format!("Operator 'in' is not supported for field '{}'", field.as_str()),
+            ));
+        }
+
+        let open = self.advance();
+        if open.kind != TokenKind::LParen {
+            return Err(QueryError::new(open.position, "Expected '('"));
+        }
+
+        let mut values = vec![self.parse_value(field)?];
+        loop {
+            let token = self.advance();
+            match token.kind {
+                TokenKind::Comma => values.push(self.parse_value(field)?),
+                TokenKind::RParen => break,
+                _ => return Err(QueryError::new(token.position, "Expected ',' or ')'")),
+            }
+        }
+
+        Ok(Predicate::In { negated, values })
+    }
+
+    fn parse_value(&mut self, field: Field) -> Result<Value, QueryError> {
+        let token = self.advance();
+        let position = token.position;
+        let (raw, quoted) = match token.kind {
+            TokenKind::Word(word) => (word, false),
+            TokenKind::Quoted(text) => (text, true),
+            _ => return Err(QueryError::new(position, "Expected a value")),
+        };
+        let invalid = |expected: &str| {
+            QueryError::new(
+                position,
+                format!("Invalid value for '{}': expected {}", field.as_str(), expected),
+            )
+        };
+
+        match field.kind() {
+            FieldKind::Text | FieldKind::Status | FieldKind::Label => Ok(Value::Text(raw)),
+            FieldKind::Priority => Priority::ALL
+                .into_iter()
+                .find(|p| p.as_str().eq_ignore_ascii_case(&raw))
+                .map(Value::Priority)
+                .ok_or_else(|| invalid("low, medium, high or urgent")),
+            FieldKind::User => {
+                if !quoted && raw.eq_ignore_ascii_case("me") {
+                    return Ok(Value::User(UserRef::Me));
+                }
+                Uuid::parse_str(&raw)
+                    .map(|id| Value::User(UserRef::Id(id)))
+                    .map_err(|_| invalid("'me' or a user ID"))
+            }
+            FieldKind::Date => parse_date(&raw)
+                .map(Value::Date)
+                .ok_or_else(|| invalid("today, YYYY-MM-DD or an offset like +7d")),
+        }
+    }
+}
+
+fn parse_date(raw: &str) -> Option<DateValue> {
+    if raw.eq_ignore_ascii_case("today") {
+        return Some(DateValue::Today);
+    }
+    if raw.starts_with('+') || raw.starts_with('-') {
+        let unit = match raw.chars().last()? {
+            'd' | 'D' => DateUnit::Day,
+            'w' | 'W' => DateUnit::Week,
+            _ => return None,
+        };
+        let digits = &raw[1..raw.len() - 1];
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let amount: i64 = digits.parse().ok()?;
+        if amount > MAX_RELATIVE_AMOUNT {
+            return None;
+        }
+        let amount = if raw.starts_with('-') { -amount } else { amount };
+        return Some(DateValue::Relative { amount, unit });
+    }
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d").ok().map(DateValue::Absolute)
+}
+
+/// Parse a query into its AST
+pub fn parse(input: &str) -> Result<Expr, QueryError> {
+    if input.chars().count() > MAX_QUERY_LENGTH {
+        return Err(QueryError::new(
+            MAX_QUERY_LENGTH + 1,
+            format!("Query is limited to {} characters", MAX_QUERY_LENGTH),
+        ));
+    }
+
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        index: 0,
+    };
+    let expr = parser.parse_or(0)?;
+
+    let trailing = parser.peek();
+    if trailing.kind != TokenKind::End {
+        return Err(QueryError::new(trailing.position, "Expected 'AND', 'OR' or end of query"));
+    }
+    Ok(expr)
+}
+
+// ---------------------------------------------------------------------------
+// SQL compilation
+// ---------------------------------------------------------------------------
+
+/// Values that relative parts of a query are resolved against
+#[derive(Debug, Clone, Copy)]
+pub struct CompileContext {
+    pub user_id: Uuid,
+    pub now: DateTime<Utc>,
+    pub offset: FixedOffset,
+}
+
+impl CompileContext {
+    fn user(&self, user: &UserRef) -> Uuid {
+        match user {
+            UserRef::Me => self.user_id,
+            UserRef::Id(id) => *id,
+        }
+    }
+
+    /// Start and end (exclusive) of a calendar day in the caller's timezone
+    fn day_range(&self, value: &DateValue) -> (DateTime<Utc>, DateTime<Utc>) {
+        let today = self.now.with_timezone(&self.offset).date_naive();
+        let day = match value {
+            DateValue::Today => today,
+            DateValue::Absolute(date) => *date,
+            DateValue::Relative { amount, unit } => {
+                let days = match unit {
+                    DateUnit::Day => *amount,
+                    DateUnit::Week => *amount * 7,
+                };
+                today + Duration::days(days)
+            }
+        };
+        let start = self
+            .offset
+            .from_local_datetime(&day.and_hms_opt(0, 0, 0).expect("midnight exists"))
+            .single()
+            .expect("fixed offsets are unambiguous")
+            .with_timezone(&Utc);
+        (start, start + Duration::days(1))
+    }
+}
+
+/// Append the query as a boolean SQL expression on the `tasks t` alias.
+///
+/// Every condition is NULL-safe, so `NOT` and `!=` include tasks where the
+/// field is empty.
+pub fn push_sql(expr: &Expr, ctx: &CompileContext, builder: &mut QueryBuilder<'_, Postgres>) {
+    match expr {
+        Expr::And(left, right) | Expr::Or(left, right) => {
+            let joiner = if matches!(expr, Expr::And(..)) { " AND " } else { " OR " };
+            builder.push("(");
+            push_sql(left, ctx, builder);
+            builder.push(joiner);
+            push_sql(right, ctx, builder);
+            builder.push(")");
+        }
+        Expr::Not(inner) => {
+            builder.push("NOT ");
+            push_sql(inner, ctx, builder);
+        }
+        Expr::Condition(condition) => push_condition(condition, ctx, builder),
+    }
+}
+
+fn push_condition(condition: &Condition, ctx: &CompileContext, builder: &mut QueryBuilder<'_, Postgres>) {
+    let field = condition.field;
+    let negated = match &condition.predicate {
+        Predicate::Compare(op, _) => *op == CompareOp::Ne,
+        Predicate::Contains { negated, .. } | Predicate::In { negated, .. } | Predicate::IsEmpty { negated } => *negated,
+    };
+
+    builder.push(if negated { "NOT COALESCE((" } else { "COALESCE((" });
+
+    match &condition.predicate {
+        Predicate::Compare(op, value) => match value {
+            Value::Text(text) => push_text_equals(field, text, builder),
+            Value::Priority(priority) if op.is_ordering() => {
+                // Higher priorities have lower ranks, so the comparison flips
+                let flipped = match op {
+                    CompareOp::Lt => ">",
+                    CompareOp::Le => ">=",
+                    CompareOp::Gt => "<",
+                    CompareOp::Ge => "<=",
+                    _ => unreachable!("ordering operator"),
+                };
+                builder
+                    .push(format!("({}) {} ", PRIORITY_RANK_SQL, flipped))
+                    .push_bind(priority.rank());
+            }
+            Value::Priority(priority) => {
+                builder.push("t.priority = ").push_bind(priority.as_str());
+            }
+            Value::User(user) => {
+                if field == Field::Assignee {
+                    builder
+                        .push(ASSIGNEE_MATCH_SQL)
+                        .push("= ")
+                        .push_bind(ctx.user(user))
+                        .push(")");
+                } else {
+                    builder.push("t.created_by = ").push_bind(ctx.user(user));
+                }
+            }
+            Value::Date(date) => {
+                let column = field.date_column();
+                let (start, end) = ctx.day_range(date);
+                match op {
+                    CompareOp::Eq | CompareOp::Ne => {
+                        builder
+                            .push(format!("{} >= ", column))
+                            .push_bind(start)
+                            .push(format!(" AND {} < ", column))
+                            .push_bind(end);
+                    }
+                    CompareOp::Lt => {
+                        builder.push(format!("{} < ", column)).push_bind(start);
+                    }
+                    CompareOp::Le => {
+                        builder.push(format!("{} < ", column)).push_bind(end);
+                    }
+                    CompareOp::Gt => {
+                        builder.push(format!("{} >= ", column)).push_bind(end);
+                    }
+                    CompareOp::Ge => {
+                        builder.push(format!("{} >= ", column)).push_bind(start);
+                    }
+                }
+            }
+        },
+        Predicate::Contains { text, .. } => {
+            builder
+                .push(format!("{} ILIKE ", text_column(field)))
+                .push_bind(format!("%{}%", escape_like(text)));
+        }
+        Predicate::In { values, .. } => push_in(field, values, ctx, builder),
+        Predicate::IsEmpty { .. } => match field {
+            Field::Label => {
+                builder.push("NOT EXISTS (SELECT 1 FROM task_labels tl WHERE tl.task_id = t.id)");
+            }
+            Field::Description => {
+                builder.push("COALESCE(t.description, '') = ''");
+            }
+            Field::Assignee => {
+                builder.push("NOT EXISTS (SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id)");
+            }
+            _ => {
+                builder.push(format!("{} IS NULL", field.date_column()));
+            }
+        },
+    }
+
+    builder.push("), false)");
+}
+
+fn text_column(field: Field) -> &'static str {
+    match field {
+        Field::Title => "t.title",
+        Field::Description => "t.description",
+        _ => unreachable!("not a text field"),
+    }
+}
+
+/// A task matches an assignee when any of its assignees does
+const ASSIGNEE_MATCH_SQL: &str = "EXISTS (SELECT 1 FROM task_assignees ta WHERE ta.task_id = t.id AND ta.user_id ";
+
+const LABEL_MATCH_SQL: &str = "EXISTS (SELECT 1 FROM task_labels tl INNER JOIN labels l ON l.id = tl.label_id WHERE tl.task_id = t.id AND LOWER(l.name) ";
+
+fn push_text_equals(field: Field, text: &str, builder: &mut QueryBuilder<'_, Postgres>) {
+    match field {
+        Field::Status => {
+            builder.push("t.status = ").push_bind(text.to_string());
+        }
+        Field::Label => {
+            builder
+                .push(LABEL_MATCH_SQL)
+                .push("= ")
+                .push_bind(text.to_lowercase())
+                .push(")");
+        }
+        _ => {
+            builder
+                .push(format!("LOWER({}) = ", text_column(field)))
+                .push_bind(text.to_lowercase());
+        }
+    }
+}
+
+fn push_in(field: Field, values: &[Value], ctx: &CompileContext, builder: &mut QueryBuilder<'_, Postgres>) {
+    match field.kind() {
+        FieldKind::Status | FieldKind::Label => {
+            let texts: Vec<String> = values
+                .iter()
+                .filter_map(|v| match v {
+                    Value::Text(text) if field == Field::Label => Some(text.to_lowercase()),
+                    Value::Text(text) => Some(text.clone()),
+                    _ => None,
+                })
+                .collect();
+            if field == Field::Label {
+                builder.push(LABEL_MATCH_SQL).push("= ANY(").push_bind(texts).push("))");
+            } else {
+                builder.push("t.status = ANY(").push_bind(texts).push(")");
+            }
+        }
+        FieldKind::Priority => {
+            let priorities: Vec<String> = values
+                .iter()
+                .filter_map(|v| match v {
+                    Value::Priority(p) => Some(p.as_str().to_string()),
+                    _ => None,
+                })
+                .collect();
+            builder.push("t.priority = ANY(").push_bind(priorities).push(")");
+        }
+        FieldKind::User => {
+            let users: Vec<Uuid> = values
+                .iter()
+                .filter_map(|v| match v {
+                    Value::User(user) => Some(ctx.user(user)),
+                    _ => None,
+                })
+                .collect();
+            if field == Field::Assignee {
+                builder.push(ASSIGNEE_MATCH_SQL).push("= ANY(").push_bind(users).push("))");
+            } else {
+                builder.push("t.created_by = ANY(").push_bind(users).push(")");
+            }
+        }
+        FieldKind::Text | FieldKind::Date => unreachable!("rejected by the parser"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn ctx() -> CompileContext {
+        CompileContext {
+            user_id: Uuid::nil(),
+            now: Utc.with_ymd_and_hms(2025, 11, 5, 12, 0, 0).unwrap(),
+            offset: FixedOffset::east_opt(0).unwrap(),
+        }
+    }
+
+    fn compile(input: &str) -> String {
+        let expr = parse(input).unwrap();
+        let mut builder = QueryBuilder::<Postgres>::new("");
+        push_sql(&expr, &ctx(), &mut builder);
+        builder.sql().to_string()
+    }
+
+    #[test]
+    fn test_parse_example() {
+        let expr = parse(
+            r#"assignee = me AND priority in (high, urgent) AND due < +7d AND NOT label = "blocked""#,
+        )
+        .unwrap();
+        assert_eq!(
+            expr.to_string(),
+            r#"(((assignee = me AND priority in (high, urgent)) AND due < +7d) AND NOT label = "blocked")"#
+        );
+
+        let expr = parse(r#"title ~ "login \"page\"" and description is not empty"#).unwrap();
+        assert_eq!(
+            expr.to_string(),
+            r#"(title ~ "login \"page\"" AND description is not empty)"#
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let expr = parse("status = a OR status = b AND NOT status = c").unwrap();
+        assert_eq!(
+            expr.to_string(),
+            r#"(status = "a" OR (status = "b" AND NOT status = "c"))"#
+        );
+        assert_eq!(
+            parse("(status = a OR status = b) and status = c").unwrap().to_string(),
+            r#"((status = "a" OR status = "b") AND status = "c")"#
+        );
+    }
+
+    #[test]
+    fn test_error_positions() {
+        let err = parse("status = done AND colour = red").unwrap_err();
+        assert_eq!(err.position, 19);
+        assert!(err.message.contains("Unknown field"));
+
+        let err = parse("status < done").unwrap_err();
+        assert_eq!(err.position, 8);
+
+        let err = parse("priority = critical").unwrap_err();
+        assert_eq!(err.position, 12);
+
+        let err = parse("(status = done").unwrap_err();
+        assert_eq!(err.position, 15);
+
+        let err = parse(r#"title ~ "open"#).unwrap_err();
+        assert_eq!(err.position, 9);
+
+        let err: AppError = parse("").unwrap_err().into();
+        assert!(matches!(err, AppError::Validation(m) if m.contains("position 1")));
+    }
+
+    #[test]
+    fn test_nesting_is_bounded() {
+        let deep = format!("{}status = a{}", "(".repeat(100), ")".repeat(100));
+        assert!(parse(&deep).is_err());
+        assert!(parse(&format!("{}status = a", "NOT ".repeat(100))).is_err());
+    }
+
+    #[test]
+    fn test_compiled_sql() {
+        assert_eq!(
+            compile("assignee = me AND priority > medium"),
+            format!(
+                "(COALESCE(({}= $1)), false) AND COALESCE((({}) < $2), false))",
+                ASSIGNEE_MATCH_SQL, PRIORITY_RANK_SQL
+            )
+        );
+        assert_eq!(
+            compile(r#"label != "blocked""#),
+            format!("NOT COALESCE(({}= $1)), false)", LABEL_MATCH_SQL)
+        );
+        assert_eq!(
+            compile("due is empty OR due <= today"),
+            "(COALESCE((t.due_date IS NULL), false) OR COALESCE((t.due_date < $1), false))"
+        );
+    }
+
+    #[test]
+    fn test_day_range_uses_offset() {
+        let mut ctx = ctx();
+        ctx.offset = FixedOffset::east_opt(-5 * 3600).unwrap();
+        let (start, end) = ctx.day_range(&DateValue::Relative { amount: 1, unit: DateUnit::Week });
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 11, 12, 5, 0, 0).unwrap());
+        assert_eq!(end - start, Duration::days(1));
+    }
+
+    // No spaces, so keyword rewrites in the tests below never touch values
+    fn arb_text() -> impl Strategy<Value = String> {
+        "[a-zA-Z0-9_'\"\\\\%;-]{0,12}"
+    }
+
+    fn arb_value(field: Field) -> BoxedStrategy<Value> {
+        match field.kind() {
+            FieldKind::Text | FieldKind::Status | FieldKind::Label => {
+                arb_text().prop_map(Value::Text).boxed()
+            }
+            FieldKind::Priority => proptest::sample::select(Priority::ALL.to_vec())
+                .prop_map(Value::Priority)
+                .boxed(),
+            FieldKind::User => prop_oneof![
+                Just(UserRef::Me),
+                any::<u128>().prop_map(|n| UserRef::Id(Uuid::from_u128(n))),
+            ]
+            .prop_map(Value::User)
+            .boxed(),
+            FieldKind::Date => prop_oneof![
+                Just(DateValue::Today),
+                (0i32..3000, 1u32..=12, 1u32..=28).prop_map(|(y, m, d)| {
+                    DateValue::Absolute(NaiveDate::from_ymd_opt(y, m, d).unwrap())
+                }),
+                (-MAX_RELATIVE_AMOUNT..=MAX_RELATIVE_AMOUNT, prop_oneof![Just(DateUnit::Day), Just(DateUnit::Week)])
+                    .prop_map(|(amount, unit)| DateValue::Relative { amount, unit }),
+            ]
+            .prop_map(Value::Date)
+            .boxed(),
+        }
+    }
+
+    fn arb_condition() -> impl Strategy<Value = Condition> {
+        proptest::sample::select(Field::ALL.to_vec()).prop_flat_map(|field| {
+            let mut predicates: Vec<BoxedStrategy<Predicate>> = vec![(
+                prop_oneof![Just(CompareOp::Eq), Just(CompareOp::Ne)],
+                arb_value(field),
+            )
+                .prop_map(|(op, value)| Predicate::Compare(op, value))
+                .boxed()];
+
+            match field.kind() {
+                FieldKind::Priority | FieldKind::Date => predicates.push(
+                    (
+                        proptest::sample::select(vec![CompareOp::Lt, CompareOp::Le, CompareOp::Gt, CompareOp::Ge]),
+                        arb_value(field),
+                    )
+                        .prop_map(|(op, value)| Predicate::Compare(op, value))
+                        .boxed(),
+                ),
+                FieldKind::Text => predicates.push(
+                    (any::<bool>(), arb_text())
+                        .prop_map(|(negated, text)| Predicate::Contains { negated, text })
+                        .boxed(),
+                ),
+                _ => {}
+            }
+            if !matches!(field.kind(), FieldKind::Text | FieldKind::Date) {
+                predicates.push(
+                    (any::<bool>(), proptest::collection::vec(arb_value(field), 1..4))
+                        .prop_map(|(negated, values)| Predicate::In { negated, values })
+                        .boxed(),
+                );
+            }
+            if field.is_optional() {
+                predicates.push(any::<bool>().prop_map(|negated| Predicate::IsEmpty { negated }).boxed());
+            }
+
+            proptest::strategy::Union::new(predicates)
+                .prop_map(move |predicate| Condition { field, predicate })
+        })
+    }
+
+    fn arb_expr() -> impl Strategy<Value = Expr> {
+        arb_condition()
+            .prop_map(Expr::Condition)
+            .prop_recursive(6, 32, 2, |inner| {
+                prop_oneof![
+                    (inner.clone(), inner.clone()).prop_map(|(l, r)| Expr::And(Box::new(l), Box::new(r))),
+                    (inner.clone(), inner.clone()).prop_map(|(l, r)| Expr::Or(Box::new(l), Box::new(r))),
+                    inner.prop_map(|e| Expr::Not(Box::new(e))),
+                ]
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_printed_ast_parses_back(expr in arb_expr()) {
+            let printed = expr.to_string();
+            prop_assert_eq!(parse(&printed), Ok(expr));
+        }
+
+        #[test]
+        fn prop_keywords_are_case_insensitive(expr in arb_expr()) {
+            let printed = expr.to_string();
+            let shouted = printed
+                .replace(" AND ", " and ")
+                .replace(" OR ", " oR ")
+                .replace("NOT ", "Not ")
+                .replace(" in (", " IN (")
+                .replace(" is ", " IS ");
+            prop_assert_eq!(parse(&shouted), parse(&printed));
+        }
+
+        #[test]
+        fn prop_parser_never_panics(input in "\\PC{0,64}") {
+            if let Err(err) = parse(&input) {
+                prop_assert!(err.position >= 1);
+                prop_assert!(err.position <= input.chars().count() + 1);
+            }
+        }
+
+        #[test]
+        fn prop_tokens_parse_without_panic(
+            tokens in proptest::collection::vec(
+                proptest::sample::select(vec![
+                    "(", ")", ",", "=", "!=", "<", ">=", "~", "!~", "AND", "or", "not", "in", "is",
+                    "empty", "status", "priority", "due", "label", "me", "high", "+3d", "\"x\"",
+                ]),
+                0..24,
+            )
+        ) {
+            let _ = parse(&tokens.join(" "));
+        }
+
+        #[test]
+        fn prop_values_are_always_bound(expr in arb_expr()) {
+            let mut builder = QueryBuilder::<Postgres>::new("");
+            push_sql(&expr, &ctx(), &mut builder);
+            let sql = builder.sql();
+            prop_assert!(!sql.contains('"'));
+            prop_assert!(!sql.contains(';'));
+            prop_assert_eq!(sql.matches('(').count(), sql.matches(')').count());
+        }
+    }
+}
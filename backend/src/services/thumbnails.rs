@@ -0,0 +1,141 @@
+use bytes::Bytes;
+use futures::TryStreamExt;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::{
+    config::StorageConfig,
+    services::{
+        blobs::{blob_key, thumbnail_key},
+        images::render_thumbnail,
+        storage::StorageBackend,
+    },
+    utils::AppError,
+};
+
+/// Thumbnails rendered per pass
+const BATCH_SIZE: usize = 20;
+
+/// Render thumbnails for pending image blobs that passed the malware scan.
+/// Returns the number processed.
+///
+/// Each blob stays locked while its thumbnail renders, so garbage collection
+/// can't remove it underneath. Images that fail to decode are marked
+/// `failed`; storage errors leave the blob pending for the next pass.
+pub async fn render_pending(
+    db: &PgPool,
+    storage: &dyn StorageBackend,
+    max_side: u32,
+) -> Result<u64, AppError> {
+    let mut processed = 0;
+    for _ in 0..BATCH_SIZE {
+        let mut tx = db.begin().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to start transaction".to_string())
+        })?;
+
+        let Some(sha256) = sqlx::query_scalar!(
+            r#"
+            SELECT sha256
+            FROM attachment_blobs
+            WHERE thumbnail_status = 'pending' AND scan_status = 'clean' AND stored
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+            "#
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to find pending thumbnails".to_string())
+        })?
+        else {
+            break;
+        };
+
+        let rendered = match storage.get(&blob_key(&sha256)).await {
+            Ok(original) => {
+                let data: Vec<u8> = original
+                    .body
+                    .try_fold(
+                        Vec::with_capacity(original.size as usize),
+                        |mut acc, chunk| async move {
+                            acc.extend_from_slice(&chunk);
+                            Ok(acc)
+                        },
+                    )
+                    .await
+                    .map_err(|e| {
+                        tracing::error!("Failed to read blob {}: {}", sha256, e);
+                        AppError::Internal("Failed to read attachment blob".to_string())
+                    })?;
+
+                // Decoding and scaling are CPU-bound
+                tokio::task::spawn_blocking(move || render_thumbnail(&data, max_side))
+                    .await
+                    .map_err(|e| AppError::Internal(format!("Thumbnail task failed: {}", e)))?
+            }
+            Err(AppError::NotFound(_)) => Err("the original is missing".to_string()),
+            Err(e) => return Err(e),
+        };
+
+        let (status, mime_type) = match rendered {
+            Ok(thumbnail) => {
+                let size = thumbnail.data.len() as u64;
+                let body = futures::stream::once(async move { Ok(Bytes::from(thumbnail.data)) });
+                storage
+                    .put(
+                        &thumbnail_key(&sha256),
+                        Box::pin(body),
+                        size,
+                        thumbnail.mime_type,
+                    )
+                    .await?;
+                ("ready", Some(thumbnail.mime_type))
+            }
+            Err(e) => {
+                tracing::warn!("Failed to render thumbnail of blob {}: {}", sha256, e);
+                ("failed", None)
+            }
+        };
+
+        sqlx::query!(
+            "UPDATE attachment_blobs SET thumbnail_status = $2, thumbnail_mime_type = $3 WHERE sha256 = $1",
+            sha256,
+            status,
+            mime_type
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to record thumbnail".to_string())
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            tracing::error!("Database error: {}", e);
+            AppError::DatabaseError("Failed to commit thumbnail".to_string())
+        })?;
+        processed += 1;
+    }
+
+    Ok(processed)
+}
+
+/// Run `render_pending` in the background every `thumbnail_interval` seconds
+pub fn spawn_thumbnail_job(db: PgPool, storage: Arc<dyn StorageBackend>, config: StorageConfig) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+            config.thumbnail_interval.max(1),
+        ));
+        loop {
+            interval.tick().await;
+            match render_pending(&db, storage.as_ref(), config.thumbnail_size.max(1)).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Rendered {} attachment thumbnails", count),
+                Err(e) => tracing::error!("Thumbnail rendering failed: {}", e),
+            }
+        }
+    });
+}
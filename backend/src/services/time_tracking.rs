@@ -0,0 +1,165 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+
+use crate::{models::TimesheetEntry, services::recurrence::resolve_local, utils::AppError};
+
+/// Longest single time log: one day
+pub const MAX_LOG_SECONDS: i32 = 24 * 60 * 60;
+
+/// Longest timesheet range, in days
+pub const MAX_TIMESHEET_DAYS: i64 = 366;
+
+/// Reject durations that cannot be a single stretch of work
+pub fn validate_duration(seconds: i32) -> Result<(), AppError> {
+    if seconds <= 0 || seconds > MAX_LOG_SECONDS {
+        return Err(AppError::Validation(format!(
+            "duration_seconds must be between 1 and {}",
+            MAX_LOG_SECONDS
+        )));
+    }
+    Ok(())
+}
+
+/// Estimates may be cleared but never negative
+pub fn validate_estimate(field: &str, seconds: Option<i32>) -> Result<(), AppError> {
+    match seconds {
+        Some(s) if s < 0 => Err(AppError::Validation(format!("{} cannot be negative", field))),
+        _ => Ok(()),
+    }
+}
+
+/// Whole seconds between the start and end of a timer
+pub fn elapsed_seconds(started_at: DateTime<Utc>, ended_at: DateTime<Utc>) -> i32 {
+    (ended_at - started_at).num_seconds().clamp(0, i32::MAX as i64) as i32
+}
+
+/// The instants bounding the days `from..=to` in `tz`: the start of `from`
+/// and the start of the day after `to`
+pub fn day_range(
+    from: NaiveDate,
+    to: NaiveDate,
+    tz: Tz,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), AppError> {
+    if to < from {
+        return Err(AppError::Validation("to must not be before from".to_string()));
+    }
+    if (to - from).num_days() >= MAX_TIMESHEET_DAYS {
+        return Err(AppError::Validation(format!(
+            "A timesheet covers at most {} days",
+            MAX_TIMESHEET_DAYS
+        )));
+    }
+
+    let end_day = to + Duration::days(1);
+    Ok((
+        resolve_local(tz, from.and_time(NaiveTime::MIN)),
+        resolve_local(tz, end_day.and_time(NaiveTime::MIN)),
+    ))
+}
+
+/// Hours with two decimals, as billed
+fn hours(seconds: i32) -> String {
+    format!("{:.2}", f64::from(seconds) / 3600.0)
+}
+
+/// Render timesheet entries as CSV with local dates and times in `tz`
+pub fn timesheet_csv(entries: &[TimesheetEntry], tz: Tz) -> Result<String, AppError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let to_csv_error = |e: csv::Error| AppError::Internal(format!("Failed to write CSV: {}", e));
+
+    writer
+        .write_record([
+            "date", "start", "end", "hours", "project", "task_key", "task", "description",
+        ])
+        .map_err(to_csv_error)?;
+
+    for entry in entries {
+        let started = entry.started_at.with_timezone(&tz);
+        let ended = entry.ended_at.with_timezone(&tz);
+        writer
+            .write_record([
+                started.format("%Y-%m-%d").to_string(),
+                started.format("%H:%M").to_string(),
+                ended.format("%H:%M").to_string(),
+                hours(entry.duration_seconds),
+                entry.project_name.clone(),
+                entry.task_key.clone(),
+                entry.task_title.clone(),
+                entry.description.clone().unwrap_or_default(),
+            ])
+            .map_err(to_csv_error)?;
+    }
+
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| AppError::Internal(format!("Failed to write CSV: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| AppError::Internal(format!("Failed to write CSV: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_validate_duration() {
+        assert!(validate_duration(90).is_ok());
+        assert!(validate_duration(MAX_LOG_SECONDS).is_ok());
+        assert!(validate_duration(0).is_err());
+        assert!(validate_duration(MAX_LOG_SECONDS + 1).is_err());
+        assert!(validate_estimate("original_estimate_seconds", None).is_ok());
+        assert!(validate_estimate("original_estimate_seconds", Some(-1)).is_err());
+    }
+
+    #[test]
+    fn test_day_range_follows_timezone() {
+        let berlin: Tz = "Europe/Berlin".parse().unwrap();
+
+        // The last Sunday of March 2025 has only 23 hours in Berlin
+        let (start, end) = day_range(date(2025, 3, 30), date(2025, 3, 30), berlin).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 3, 29, 23, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2025, 3, 30, 22, 0, 0).unwrap());
+
+        let (start, end) = day_range(date(2025, 1, 1), date(2025, 1, 31), Tz::UTC).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap());
+        assert_eq!(end, Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap());
+
+        assert!(day_range(date(2025, 2, 1), date(2025, 1, 1), Tz::UTC).is_err());
+        assert!(day_range(date(2024, 1, 1), date(2025, 1, 1), Tz::UTC).is_err());
+    }
+
+    #[test]
+    fn test_timesheet_csv() {
+        let berlin: Tz = "Europe/Berlin".parse().unwrap();
+        let started_at = Utc.with_ymd_and_hms(2025, 6, 2, 7, 30, 0).unwrap();
+        let entry = TimesheetEntry {
+            id: Uuid::new_v4(),
+            task_id: Uuid::new_v4(),
+            task_key: "WEB-7".to_string(),
+            task_title: "Fix login, again".to_string(),
+            project_id: Uuid::new_v4(),
+            project_name: "Website".to_string(),
+            description: Some("Pairing with \"QA\"".to_string()),
+            started_at,
+            ended_at: started_at + Duration::minutes(95),
+            duration_seconds: 95 * 60,
+        };
+
+        let csv = timesheet_csv(&[entry], berlin).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some("date,start,end,hours,project,task_key,task,description")
+        );
+        assert_eq!(
+            lines.next(),
+            Some("2025-06-02,09:30,11:05,1.58,Website,WEB-7,\"Fix login, again\",\"Pairing with \"\"QA\"\"\"")
+        );
+        assert_eq!(lines.next(), None);
+    }
+}
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+
+use crate::{config::TrashConfig, utils::AppError};
+
+/// When an item deleted at `deleted_at` is removed for good
+pub fn purge_at(deleted_at: DateTime<Utc>, retention_days: i64) -> DateTime<Utc> {
+    deleted_at + Duration::days(retention_days.max(0))
+}
+
+/// Items deleted before this instant are past their retention period
+pub fn purge_cutoff(now: DateTime<Utc>, retention_days: i64) -> DateTime<Utc> {
+    now - Duration::days(retention_days.max(0))
+}
+
+/// Permanently delete trashed tasks and comments past the retention period.
+/// Returns the number of tasks and comments removed.
+pub async fn purge_expired(db: &PgPool, retention_days: i64) -> Result<(u64, u64), AppError> {
+    let cutoff = purge_cutoff(Utc::now(), retention_days);
+
+    let tasks = sqlx::query!(
+        "DELETE FROM tasks WHERE deleted_at IS NOT NULL AND deleted_at < $1",
+        cutoff
+    )
+    .execute(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to purge tasks".to_string())
+    })?;
+
+    let comments = sqlx::query!(
+        "DELETE FROM task_comments WHERE deleted_at IS NOT NULL AND deleted_at < $1",
+        cutoff
+    )
+    .execute(db)
+    .await
+    .map_err(|e| {
+        tracing::error!("Database error: {}", e);
+        AppError::DatabaseError("Failed to purge comments".to_string())
+    })?;
+
+    Ok((tasks.rows_affected(), comments.rows_affected()))
+}
+
+/// Run `purge_expired` in the background every `purge_interval` seconds
+pub fn spawn_purge_job(db: PgPool, config: TrashConfig) {
+    tokio::spawn(async move {
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_secs(config.purge_interval.max(1)));
+        loop {
+            interval.tick().await;
+            match purge_expired(&db, config.retention_days).await {
+                Ok((0, 0)) => {}
+                Ok((tasks, comments)) => {
+                    tracing::info!("Purged {} tasks and {} comments from the trash", tasks, comments);
+                }
+                Err(e) => tracing::error!("Trash purge failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_retention_window() {
+        let deleted_at = Utc.with_ymd_and_hms(2025, 11, 1, 12, 0, 0).unwrap();
+        assert_eq!(purge_at(deleted_at, 30), Utc.with_ymd_and_hms(2025, 12, 1, 12, 0, 0).unwrap());
+        assert_eq!(purge_cutoff(purge_at(deleted_at, 30), 30), deleted_at);
+        // A negative retention purges immediately rather than never
+        assert_eq!(purge_at(deleted_at, -5), deleted_at);
+    }
+}
@@ -30,6 +30,12 @@ pub enum AppError {
     #[error("Conflict: {0}")]
     Conflict(String),
 
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
     #[error("Internal server error: {0}")]
     InternalServerError(String),
 
@@ -62,6 +68,8 @@ impl IntoResponse for AppError {
             AppError::Authorization(ref msg) => (StatusCode::FORBIDDEN, msg.as_str()),
             AppError::NotFound(ref msg) => (StatusCode::NOT_FOUND, msg.as_str()),
             AppError::Conflict(ref msg) => (StatusCode::CONFLICT, msg.as_str()),
+            AppError::PreconditionFailed(ref msg) => (StatusCode::PRECONDITION_FAILED, msg.as_str()),
+            AppError::PayloadTooLarge(ref msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg.as_str()),
             AppError::BadRequest(ref msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
             AppError::ExternalService(ref msg) => {
                 tracing::error!("External service error: {}", msg);
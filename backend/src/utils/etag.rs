@@ -0,0 +1,57 @@
+use axum::http::{header, HeaderMap};
+
+use crate::utils::AppError;
+
+/// Strong ETag for a row version
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Versions the client's `If-Match` header accepts; `None` when the header is
+/// absent or `*`. Weak and malformed tags never match, as required for `If-Match`.
+pub fn if_match_versions(headers: &HeaderMap) -> Result<Option<Vec<i32>>, AppError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value
+        .to_str()
+        .map_err(|_| AppError::BadRequest("Invalid If-Match header".to_string()))?
+        .trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        value
+            .split(',')
+            .filter_map(|tag| {
+                tag.trim()
+                    .strip_prefix('"')
+                    .and_then(|t| t.strip_suffix('"'))
+                    .and_then(|t| t.parse().ok())
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn parse(value: &str) -> Option<Vec<i32>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_str(value).unwrap());
+        if_match_versions(&headers).unwrap()
+    }
+
+    #[test]
+    fn test_if_match_versions() {
+        assert_eq!(if_match_versions(&HeaderMap::new()).unwrap(), None);
+        assert_eq!(parse("*"), None);
+        assert_eq!(parse(&etag(3)), Some(vec![3]));
+        assert_eq!(parse(r#""3", "4""#), Some(vec![3, 4]));
+        assert_eq!(parse(r#"W/"3""#), Some(vec![]));
+        assert_eq!(parse("3"), Some(vec![]));
+    }
+}
@@ -1,5 +1,11 @@
 pub mod error;
 pub mod auth;
+pub mod etag;
+pub mod patch;
+pub mod query;
 
 pub use error::{AppError, Result};
 pub use auth::{hash_password, verify_password, generate_token, validate_token, extract_token_from_header, Claims};
+pub use etag::{etag, if_match_versions};
+pub use patch::Patch;
+pub use query::{escape_like, split_list};
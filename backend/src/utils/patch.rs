@@ -0,0 +1,94 @@
+use serde::{Deserialize, Deserializer};
+
+use crate::utils::AppError;
+
+/// A field of a JSON merge patch: absent leaves the value unchanged, `null`
+/// clears it and anything else replaces it. Fields must be `#[serde(default)]`
+/// so that absent keys deserialize to `Missing`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Patch<T> {
+    #[default]
+    Missing,
+    Null,
+    Value(T),
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+impl<T> Patch<T> {
+    /// Whether the patch touches the field at all
+    pub fn is_set(&self) -> bool {
+        !matches!(self, Patch::Missing)
+    }
+
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The new value, `None` both when clearing and when unchanged
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Patch::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The value after applying the patch to `current`
+    pub fn apply(self, current: Option<T>) -> Option<T> {
+        match self {
+            Patch::Missing => current,
+            Patch::Null => None,
+            Patch::Value(value) => Some(value),
+        }
+    }
+
+    /// For columns that cannot be cleared: reject `null`, otherwise the new value if any
+    pub fn required(self, field: &str) -> Result<Option<T>, AppError> {
+        match self {
+            Patch::Null => Err(AppError::Validation(format!("{} cannot be null", field))),
+            patch => Ok(patch.into_option()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct Request {
+        #[serde(default)]
+        description: Patch<String>,
+    }
+
+    fn parse(json: &str) -> Patch<String> {
+        serde_json::from_str::<Request>(json).unwrap().description
+    }
+
+    #[test]
+    fn test_deserialize_tri_state() {
+        assert_eq!(parse("{}"), Patch::Missing);
+        assert_eq!(parse(r#"{"description": null}"#), Patch::Null);
+        assert_eq!(parse(r#"{"description": "x"}"#), Patch::Value("x".to_string()));
+    }
+
+    #[test]
+    fn test_apply() {
+        let current = Some("old".to_string());
+        assert_eq!(Patch::Missing.apply(current.clone()), current);
+        assert_eq!(Patch::<String>::Null.apply(current.clone()), None);
+        assert_eq!(Patch::Value("new".to_string()).apply(current), Some("new".to_string()));
+        assert!(Patch::<String>::Null.required("title").is_err());
+        assert_eq!(Patch::<String>::Missing.required("title").unwrap(), None);
+    }
+}
@@ -0,0 +1,36 @@
+/// Split a comma-separated query parameter into trimmed, non-empty items
+pub fn split_list(value: Option<&str>) -> Option<Vec<String>> {
+    value.map(|v| {
+        v.split(',')
+            .map(|item| item.trim().to_string())
+            .filter(|item| !item.is_empty())
+            .collect()
+    })
+}
+
+/// Escape `%`, `_` and `\` so user input is matched literally by `LIKE`/`ILIKE`
+pub fn escape_like(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list(Some("todo, done,,")),
+            Some(vec!["todo".to_string(), "done".to_string()])
+        );
+        assert_eq!(split_list(None), None);
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+    }
+}
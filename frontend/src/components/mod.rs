@@ -1,7 +1,8 @@
 // Components module
 pub mod auth_context;
 pub mod organization_context;
+pub mod search_box;
 
 pub use auth_context::{provide_auth_context, use_auth_context};
 pub use organization_context::{provide_organization_context, use_organization_context};
-
+pub use search_box::SearchBox;
@@ -0,0 +1,40 @@
+use leptos::*;
+use leptos_router::use_navigate;
+
+/// Search input that opens the organization's search results page
+#[component]
+pub fn SearchBox(
+    #[prop(into)] org_id: String,
+    #[prop(optional, into)] initial: String,
+) -> impl IntoView {
+    let navigate = use_navigate();
+    let (query, set_query) = create_signal(initial);
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let q = query.get();
+        if q.trim().is_empty() {
+            return;
+        }
+        let q = String::from(js_sys::encode_uri_component(q.trim()));
+        navigate(&format!("/organizations/{}/search?q={}", org_id, q), Default::default());
+    };
+
+    view! {
+        <form on:submit=on_submit class="flex">
+            <input
+                type="search"
+                placeholder="Search tasks, comments and projects"
+                prop:value=query
+                on:input=move |ev| set_query.set(event_target_value(&ev))
+                class="w-72 px-3 py-2 border border-gray-300 rounded-l-md text-sm focus:outline-none focus:ring-indigo-500 focus:border-indigo-500"
+            />
+            <button
+                type="submit"
+                class="px-4 py-2 bg-indigo-600 text-white text-sm rounded-r-md hover:bg-indigo-700"
+            >
+                "Search"
+            </button>
+        </form>
+    }
+}
@@ -7,9 +7,10 @@ mod pages;
 mod services;
 
 use pages::{
-    dashboard::Dashboard, home::Home, login::Login, not_found::NotFound,
+    dashboard::Dashboard, home::Home, login::Login, my_tasks::MyTasksPage, not_found::NotFound,
     organization_detail::OrganizationDetail, organizations::Organizations,
-    project_detail::ProjectDetail, projects::Projects,
+    project_detail::ProjectDetail, projects::Projects, search::Search, task_detail::TaskDetail,
+    timesheet::TimesheetPage, trash::Trash,
 };
 use components::{provide_auth_context, provide_organization_context};
 
@@ -34,10 +35,15 @@ fn App() -> impl IntoView {
                     <Route path="/" view=Home/>
                     <Route path="/login" view=Login/>
                     <Route path="/dashboard" view=Dashboard/>
+                    <Route path="/my-tasks" view=MyTasksPage/>
+                    <Route path="/timesheet" view=TimesheetPage/>
                     <Route path="/organizations" view=Organizations/>
                     <Route path="/organizations/:org_id" view=OrganizationDetail/>
                     <Route path="/organizations/:org_id/projects" view=Projects/>
+                    <Route path="/organizations/:org_id/search" view=Search/>
                     <Route path="/organizations/:org_id/projects/:project_id" view=ProjectDetail/>
+                    <Route path="/organizations/:org_id/projects/:project_id/tasks/:task_id" view=TaskDetail/>
+                    <Route path="/organizations/:org_id/projects/:project_id/trash" view=Trash/>
                     <Route path="/*any" view=NotFound/>
                 </Routes>
             </main>
@@ -1,8 +1,13 @@
 pub mod dashboard;
 pub mod home;
 pub mod login;
+pub mod my_tasks;
 pub mod not_found;
 pub mod organization_detail;
 pub mod organizations;
 pub mod project_detail;
 pub mod projects;
+pub mod search;
+pub mod task_detail;
+pub mod timesheet;
+pub mod trash;
@@ -0,0 +1,147 @@
+use leptos::*;
+
+use crate::components::use_organization_context;
+use crate::services::tasks::{self, MyTask, MyTasks};
+
+#[component]
+pub fn MyTasksPage() -> impl IntoView {
+    let org_ctx = use_organization_context();
+
+    let (my_tasks, set_my_tasks) = create_signal(MyTasks::default());
+    let (loading, set_loading) = create_signal(true);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+    let (org_filter, set_org_filter) = create_signal(String::new());
+
+    // Load organizations for the filter
+    create_effect(move |_| {
+        if org_ctx.organizations.get_untracked().is_empty() {
+            spawn_local(async move {
+                org_ctx.load_organizations().await;
+            });
+        }
+    });
+
+    // Load tasks whenever the organization filter changes
+    create_effect(move |_| {
+        let org_id = org_filter.get();
+        spawn_local(async move {
+            set_loading.set(true);
+            let org_id = if org_id.is_empty() { None } else { Some(org_id.as_str()) };
+            match tasks::get_my_tasks(org_id).await {
+                Ok(result) => {
+                    set_my_tasks.set(result);
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Failed to load tasks: {}", e);
+                    set_error.set(Some(format!("Failed to load tasks: {}", e)));
+                }
+            }
+            set_loading.set(false);
+        });
+    });
+
+    view! {
+        <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8">
+            <div class="flex justify-between items-center mb-8">
+                <div>
+                    <h1 class="text-3xl font-bold text-gray-900">"My Tasks"</h1>
+                    <p class="mt-2 text-gray-600">"Everything assigned to you across your organizations"</p>
+                </div>
+                <select
+                    on:change=move |ev| set_org_filter.set(event_target_value(&ev))
+                    class="px-3 py-2 border border-gray-300 rounded-md text-sm"
+                >
+                    <option value="">"All organizations"</option>
+                    <For
+                        each=move || org_ctx.organizations.get()
+                        key=|org| org.id
+                        children=move |org| {
+                            view! { <option value=org.id.to_string()>{org.name.clone()}</option> }
+                        }
+                    />
+                </select>
+            </div>
+
+            {move || {
+                if loading.get() {
+                    view! {
+                        <div class="flex justify-center items-center py-12">
+                            <div class="animate-spin rounded-full h-12 w-12 border-b-2 border-blue-600"></div>
+                        </div>
+                    }.into_view()
+                } else if let Some(err) = error.get() {
+                    view! {
+                        <div class="bg-red-50 border border-red-200 rounded-lg p-4">
+                            <p class="text-red-800">{err}</p>
+                        </div>
+                    }.into_view()
+                } else {
+                    let groups = my_tasks.get();
+                    view! {
+                        <div class="space-y-6">
+                            <TaskBucket title="Overdue" accent="text-red-700" tasks=groups.overdue/>
+                            <TaskBucket title="Today" accent="text-blue-700" tasks=groups.today/>
+                            <TaskBucket title="This Week" accent="text-gray-900" tasks=groups.this_week/>
+                            <TaskBucket title="Later" accent="text-gray-900" tasks=groups.later/>
+                            <TaskBucket title="No Due Date" accent="text-gray-500" tasks=groups.no_due_date/>
+                        </div>
+                    }.into_view()
+                }
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn TaskBucket(title: &'static str, accent: &'static str, tasks: Vec<MyTask>) -> impl IntoView {
+    let count = tasks.len();
+
+    view! {
+        <div class="bg-white shadow rounded-lg">
+            <div class="px-6 py-4 border-b border-gray-200 flex items-center justify-between">
+                <h2 class=format!("text-lg font-semibold {}", accent)>{title}</h2>
+                <span class="text-sm text-gray-500">{count}</span>
+            </div>
+            {if tasks.is_empty() {
+                view! { <p class="px-6 py-4 text-sm text-gray-400">"Nothing here"</p> }.into_view()
+            } else {
+                view! {
+                    <ul class="divide-y divide-gray-100">
+                        {tasks.into_iter().map(|item| {
+                            let href = format!(
+                                "/organizations/{}/projects/{}",
+                                item.organization_id, item.task.project_id
+                            );
+                            view! {
+                                <li class="px-6 py-3">
+                                    <a href=href class="flex items-center justify-between hover:bg-gray-50">
+                                        <div>
+                                            <p class="text-sm font-medium text-gray-900">{item.task.title.clone()}</p>
+                                            <p class="text-xs text-gray-500">
+                                                {format!("{} / {}", item.organization_name, item.project_name)}
+                                            </p>
+                                        </div>
+                                        <div class="flex items-center space-x-3">
+                                            {item.task.is_blocked.then(|| view! {
+                                                <span class="px-2 py-0.5 text-xs rounded bg-red-100 text-red-800">"Blocked"</span>
+                                            })}
+                                            <span class="px-2 py-0.5 text-xs rounded bg-gray-100 text-gray-700">
+                                                {item.task.priority.clone()}
+                                            </span>
+                                            {item.task.due_date.map(|due| view! {
+                                                <span class="text-xs text-gray-500">
+                                                    {due.with_timezone(&chrono::Local).format("%b %d, %H:%M").to_string()}
+                                                </span>
+                                            })}
+                                        </div>
+                                    </a>
+                                </li>
+                            }
+                        }).collect_view()}
+                    </ul>
+                }.into_view()
+            }}
+        </div>
+    }
+}
@@ -1,7 +1,16 @@
 use leptos::*;
 use leptos_router::*;
+use uuid::Uuid;
 
-use crate::services::tasks::{self, CreateTaskRequest, Task, UpdateTaskRequest};
+use crate::components::use_auth_context;
+use crate::services::labels::{self, Label};
+use crate::services::projects::{self, Project};
+use crate::services::tasks::{
+    self, BulkTaskChanges, BulkTaskRequest, CreateTaskRequest, MoveTaskRequest, SaveOutcome,
+    Task, TaskListParams, UpdateTaskRequest,
+};
+use crate::services::time_tracking::{self, format_duration, ProjectTimeSummary};
+use crate::services::views::{self, CreateSavedViewRequest, SavedView};
 
 #[component]
 pub fn ProjectDetail() -> impl IntoView {
@@ -17,6 +26,37 @@ pub fn ProjectDetail() -> impl IntoView {
     let (loading, set_loading) = create_signal(true);
     let (error, set_error) = create_signal::<Option<String>>(None);
     let (show_create_modal, set_show_create_modal) = create_signal(false);
+    let (project_labels, set_project_labels) = create_signal::<Vec<Label>>(vec![]);
+    let (label_filter, set_label_filter) = create_signal::<Option<String>>(None);
+    let auth = use_auth_context();
+
+    // View state, restored from saved views
+    let (saved_views, set_saved_views) = create_signal::<Vec<SavedView>>(vec![]);
+    let (active_view, set_active_view) = create_signal::<Option<SavedView>>(None);
+    let (filter_input, set_filter_input) = create_signal(String::new());
+    let (applied_filter, set_applied_filter) = create_signal::<Option<String>>(None);
+    let (sort, set_sort) = create_signal(String::from("rank"));
+    let (order, set_order) = create_signal(String::from("asc"));
+    let (group_by, set_group_by) = create_signal(String::new());
+    let (layout, set_layout) = create_signal(String::from("board"));
+    let (show_save_view, set_show_save_view) = create_signal(false);
+    let (view_name, set_view_name) = create_signal(String::new());
+    let (view_shared, set_view_shared) = create_signal(false);
+    let (view_error, set_view_error) = create_signal::<Option<String>>(None);
+
+    // Multi-select for bulk changes
+    let selected = create_rw_signal::<Vec<Uuid>>(vec![]);
+    let (org_projects, set_org_projects) = create_signal::<Vec<Project>>(vec![]);
+    let (bulk_error, set_bulk_error) = create_signal::<Option<String>>(None);
+    let (reload, set_reload) = create_signal(0u32);
+    let (time_summary, set_time_summary) = create_signal::<Option<ProjectTimeSummary>>(None);
+
+    // Drag-and-drop keeps the manual order, so it is only offered when sorting by rank
+    let (dragging, set_dragging) = create_signal::<Option<Uuid>>(None);
+    let can_drag = move || sort.get() == "rank" && order.get() == "asc";
+
+    // An edit rejected because someone else changed the task first: their copy and our change
+    let (conflict, set_conflict) = create_signal::<Option<(Task, UpdateTaskRequest)>>(None);
 
     // Form state
     let (title, set_title) = create_signal(String::new());
@@ -24,13 +64,73 @@ pub fn ProjectDetail() -> impl IntoView {
     let (priority, set_priority) = create_signal(String::from("medium"));
     let (create_error, set_create_error) = create_signal::<Option<String>>(None);
 
+    // Load labels available in this project
+    create_effect(move |_| {
+        let proj_id = project_id();
+        if !proj_id.is_empty() {
+            spawn_local(async move {
+                match labels::get_project_labels(&proj_id).await {
+                    Ok(label_list) => set_project_labels.set(label_list),
+                    Err(e) => log::error!("Failed to load labels: {}", e),
+                }
+            });
+        }
+    });
+
+    // Load estimate and logged time totals
+    create_effect(move |_| {
+        let proj_id = project_id();
+        reload.track();
+        if !proj_id.is_empty() {
+            spawn_local(async move {
+                match time_tracking::get_project_time_summary(&proj_id).await {
+                    Ok(summary) => set_time_summary.set(Some(summary)),
+                    Err(e) => log::error!("Failed to load time summary: {}", e),
+                }
+            });
+        }
+    });
+
+    // Load projects a selection can be moved to
+    create_effect(move |_| {
+        let org = org_id();
+        if !org.is_empty() {
+            spawn_local(async move {
+                match projects::get_organization_projects(&org).await {
+                    Ok(project_list) => set_org_projects.set(project_list),
+                    Err(e) => log::error!("Failed to load projects: {}", e),
+                }
+            });
+        }
+    });
+
+    // Load saved views
+    create_effect(move |_| {
+        let proj_id = project_id();
+        if !proj_id.is_empty() {
+            spawn_local(async move {
+                match views::get_project_views(&proj_id).await {
+                    Ok(view_list) => set_saved_views.set(view_list),
+                    Err(e) => log::error!("Failed to load views: {}", e),
+                }
+            });
+        }
+    });
+
     // Load tasks
     create_effect(move |_| {
         let proj_id = project_id();
+        reload.track();
+        let params = TaskListParams {
+            label_id: label_filter.get(),
+            filter: applied_filter.get(),
+            sort: Some(sort.get()),
+            order: Some(order.get()),
+        };
         if !proj_id.is_empty() {
             spawn_local(async move {
                 set_loading.set(true);
-                match tasks::get_project_tasks(&proj_id).await {
+                match tasks::get_project_tasks(&proj_id, &params).await {
                     Ok(task_list) => {
                         set_tasks.set(task_list);
                         set_error.set(None);
@@ -66,6 +166,8 @@ pub fn ProjectDetail() -> impl IntoView {
                 priority: Some(priority.get()),
                 assigned_to: None,
                 due_date: None,
+                original_estimate_seconds: None,
+                custom_fields: None,
             };
 
             match tasks::create_task(&proj_id, request).await {
@@ -86,28 +188,202 @@ pub fn ProjectDetail() -> impl IntoView {
         });
     };
 
-    let handle_status_change = move |task_id: String, new_status: String| {
+    let run_bulk = move |changes: BulkTaskChanges| {
+        let request = BulkTaskRequest {
+            task_ids: selected.get(),
+            changes,
+        };
+        if request.task_ids.is_empty() {
+            return;
+        }
         spawn_local(async move {
-            let request = UpdateTaskRequest {
-                title: None,
-                description: None,
-                status: Some(new_status),
-                priority: None,
-                assigned_to: None,
-                due_date: None,
-                position: None,
-            };
+            match tasks::bulk_update_tasks(request).await {
+                Ok(response) if response.applied => {
+                    selected.set(vec![]);
+                    set_bulk_error.set(None);
+                    set_reload.update(|n| *n += 1);
+                }
+                Ok(response) => {
+                    let titles = tasks.get();
+                    let failures = response
+                        .results
+                        .into_iter()
+                        .filter_map(|r| {
+                            let title = titles
+                                .iter()
+                                .find(|t| t.id == r.task_id)
+                                .map(|t| t.title.clone())
+                                .unwrap_or_else(|| r.task_id.to_string());
+                            r.error.map(|e| format!("{}: {}", title, e))
+                        })
+                        .collect::<Vec<_>>();
+                    set_bulk_error.set(Some(format!(
+                        "No tasks were changed. {}",
+                        failures.join("; ")
+                    )));
+                }
+                Err(e) => set_bulk_error.set(Some(format!("Bulk update failed: {}", e))),
+            }
+        });
+    };
 
-            match tasks::update_task(&task_id, request).await {
-                Ok(updated_task) => {
+    let apply_view = move |view: Option<SavedView>| {
+        match view {
+            Some(ref v) => {
+                set_filter_input.set(v.filter.clone().unwrap_or_default());
+                set_applied_filter.set(v.filter.clone());
+                // Views saved before rank keys still say `position`
+                set_sort.set(
+                    v.sort
+                        .clone()
+                        .filter(|s| s != "position")
+                        .unwrap_or_else(|| "rank".to_string()),
+                );
+                set_order.set(v.sort_order.clone());
+                set_group_by.set(v.group_by.clone().unwrap_or_default());
+                set_layout.set(v.layout.clone());
+            }
+            None => {
+                set_filter_input.set(String::new());
+                set_applied_filter.set(None);
+                set_sort.set("rank".to_string());
+                set_order.set("asc".to_string());
+                set_group_by.set(String::new());
+                set_layout.set("board".to_string());
+            }
+        }
+        set_active_view.set(view);
+    };
+
+    let handle_save_view = move |_| {
+        let name_val = view_name.get();
+        if name_val.trim().is_empty() {
+            set_view_error.set(Some("View name is required".to_string()));
+            return;
+        }
+        let proj_id = project_id();
+        let non_empty = |v: String| if v.is_empty() { None } else { Some(v) };
+        let request = CreateSavedViewRequest {
+            name: name_val,
+            filter: applied_filter.get(),
+            sort: Some(sort.get()),
+            sort_order: Some(order.get()),
+            group_by: non_empty(group_by.get()),
+            layout: Some(layout.get()),
+            is_shared: Some(view_shared.get()),
+        };
+
+        spawn_local(async move {
+            match views::create_view(&proj_id, request).await {
+                Ok(view) => {
+                    set_saved_views.update(|v| v.push(view.clone()));
+                    set_active_view.set(Some(view));
+                    set_show_save_view.set(false);
+                    set_view_name.set(String::new());
+                    set_view_shared.set(false);
+                    set_view_error.set(None);
+                }
+                Err(e) => set_view_error.set(Some(format!("Failed to save view: {}", e))),
+            }
+        });
+    };
+
+    let handle_delete_view = move |_| {
+        let Some(view) = active_view.get() else {
+            return;
+        };
+        spawn_local(async move {
+            match views::delete_view(&view.id.to_string()).await {
+                Ok(()) => {
+                    set_saved_views.update(|v| v.retain(|existing| existing.id != view.id));
+                    apply_view(None);
+                }
+                Err(e) => log::error!("Failed to delete view: {}", e),
+            }
+        });
+    };
+
+    let owns_active_view = move || {
+        let user_id = auth.user.get().map(|u| u.id);
+        active_view
+            .get()
+            .map(|v| Some(v.owner_id.to_string()) == user_id)
+            .unwrap_or(false)
+    };
+
+    let replace_task = move |updated_task: Task| {
+        set_tasks.update(|tasks| {
+            if let Some(task) = tasks.iter_mut().find(|t| t.id == updated_task.id) {
+                *task = updated_task;
+            }
+        });
+    };
+
+    // Save an edit made against `version`; a conflict opens the dialog instead
+    let save_task = move |task_id: Uuid, version: i32, request: UpdateTaskRequest| {
+        spawn_local(async move {
+            match tasks::update_task_if_unchanged(&task_id.to_string(), version, request.clone()).await {
+                Ok(SaveOutcome::Saved(updated_task)) => replace_task(updated_task),
+                Ok(SaveOutcome::Conflict(latest)) => set_conflict.set(Some((latest, request))),
+                Err(e) => {
+                    log::error!("Failed to update task: {}", e);
+                }
+            }
+        });
+    };
+
+    let handle_status_change = move |task_id: String, new_status: String| {
+        let Some(task) = tasks.get().into_iter().find(|t| t.id.to_string() == task_id) else {
+            return;
+        };
+        let request = UpdateTaskRequest {
+            status: Some(new_status),
+            ..Default::default()
+        };
+        save_task(task.id, task.version, request);
+    };
+
+    // Drop the dragged task above `target`, or at the end of the column
+    let handle_drop = move |status: &'static str, target: Option<Uuid>| {
+        let Some(task_id) = dragging.get() else {
+            return;
+        };
+        set_dragging.set(None);
+        if target == Some(task_id) {
+            return;
+        }
+
+        let column: Vec<Task> = tasks
+            .get()
+            .into_iter()
+            .filter(|t| t.status == status && t.id != task_id)
+            .collect();
+        let (before_id, after_id) = match target {
+            Some(target) => match column.iter().position(|t| t.id == target) {
+                Some(i) => (i.checked_sub(1).map(|j| column[j].id), Some(target)),
+                None => return,
+            },
+            None => (column.last().map(|t| t.id), None),
+        };
+
+        let request = MoveTaskRequest {
+            status: Some(status.to_string()),
+            before_id,
+            after_id,
+        };
+        spawn_local(async move {
+            match tasks::move_task(&task_id.to_string(), request).await {
+                Ok(moved) => {
                     set_tasks.update(|tasks| {
-                        if let Some(task) = tasks.iter_mut().find(|t| t.id.to_string() == task_id) {
-                            *task = updated_task;
+                        if let Some(task) = tasks.iter_mut().find(|t| t.id == moved.id) {
+                            *task = moved;
                         }
+                        tasks.sort_by(|a, b| a.rank.cmp(&b.rank));
                     });
                 }
                 Err(e) => {
-                    log::error!("Failed to update task: {}", e);
+                    log::error!("Failed to move task: {}", e);
+                    set_bulk_error.set(Some(format!("Failed to move task: {}", e)));
                 }
             }
         });
@@ -135,15 +411,281 @@ pub fn ProjectDetail() -> impl IntoView {
                     </A>
                     <h1 class="text-3xl font-bold text-gray-900">"Task Board"</h1>
                     <p class="mt-2 text-gray-600">"Manage your tasks with a Kanban board"</p>
+                    {move || time_summary.get().map(|summary| view! {
+                        <p class="mt-1 text-sm text-gray-500">
+                            {format!(
+                                "{} logged of {} estimated, {} remaining",
+                                format_duration(summary.time_spent_seconds),
+                                format_duration(summary.original_estimate_seconds),
+                                format_duration(summary.remaining_estimate_seconds),
+                            )}
+                        </p>
+                    })}
+                </div>
+                <div class="flex items-center space-x-3">
+                    <A href=format!("/organizations/{}/projects/{}/trash", org_id(), project_id()) class="px-4 py-2 text-gray-700 border border-gray-300 rounded-lg hover:bg-gray-50">
+                        "Trash"
+                    </A>
+                    <button
+                        on:click=move |_| set_show_create_modal.set(true)
+                        class="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors"
+                    >
+                        "New Task"
+                    </button>
                 </div>
+            </div>
+
+            <div class="flex flex-wrap items-center gap-3 mb-4">
+                <label class="text-sm font-medium text-gray-700">"View"</label>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    prop:value=move || active_view.get().map(|v| v.id.to_string()).unwrap_or_default()
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        let view = saved_views.get().into_iter().find(|v| v.id.to_string() == value);
+                        apply_view(view);
+                    }
+                >
+                    <option value="">"Default"</option>
+                    <For
+                        each=move || saved_views.get()
+                        key=|view| view.id
+                        children=move |view: SavedView| {
+                            let name = if view.is_shared { format!("{} (shared)", view.name) } else { view.name.clone() };
+                            view! { <option value=view.id.to_string()>{name}</option> }
+                        }
+                    />
+                </select>
+                <button
+                    on:click=move |_| set_show_save_view.set(true)
+                    class="px-3 py-1 text-sm border border-gray-300 rounded-lg hover:bg-gray-50"
+                >
+                    "Save view"
+                </button>
+                {move || owns_active_view().then(|| view! {
+                    <button
+                        on:click=handle_delete_view
+                        class="px-3 py-1 text-sm text-red-600 border border-red-200 rounded-lg hover:bg-red-50"
+                    >
+                        "Delete view"
+                    </button>
+                })}
+
+                <label class="text-sm font-medium text-gray-700 ml-4">"Layout"</label>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm"
+                    prop:value=move || layout.get()
+                    on:change=move |ev| set_layout.set(event_target_value(&ev))
+                >
+                    <option value="board">"Board"</option>
+                    <option value="list">"List"</option>
+                    <option value="calendar">"Calendar"</option>
+                </select>
+
+                <label class="text-sm font-medium text-gray-700">"Group by"</label>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm"
+                    prop:value=move || group_by.get()
+                    on:change=move |ev| set_group_by.set(event_target_value(&ev))
+                >
+                    <option value="">"None"</option>
+                    <option value="status">"Status"</option>
+                    <option value="priority">"Priority"</option>
+                    <option value="assignee">"Assignee"</option>
+                    <option value="label">"Label"</option>
+                    <option value="due_date">"Due date"</option>
+                </select>
+
+                <label class="text-sm font-medium text-gray-700">"Sort"</label>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm"
+                    prop:value=move || sort.get()
+                    on:change=move |ev| set_sort.set(event_target_value(&ev))
+                >
+                    <option value="rank">"Manual"</option>
+                    <option value="priority">"Priority"</option>
+                    <option value="due_date">"Due date"</option>
+                    <option value="created_at">"Created"</option>
+                    <option value="updated_at">"Updated"</option>
+                    <option value="title">"Title"</option>
+                </select>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm"
+                    prop:value=move || order.get()
+                    on:change=move |ev| set_order.set(event_target_value(&ev))
+                >
+                    <option value="asc">"Asc"</option>
+                    <option value="desc">"Desc"</option>
+                </select>
+            </div>
+
+            <div class="flex items-center space-x-2 mb-4">
+                <input
+                    type="text"
+                    placeholder="Filter, e.g. assignee = me AND due < +7d"
+                    class="flex-1 px-3 py-1 border border-gray-300 rounded-lg text-sm font-mono focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    prop:value=move || filter_input.get()
+                    on:input=move |ev| set_filter_input.set(event_target_value(&ev))
+                    on:keydown=move |ev| {
+                        if ev.key() == "Enter" {
+                            let value = filter_input.get();
+                            set_applied_filter.set(if value.trim().is_empty() { None } else { Some(value) });
+                        }
+                    }
+                />
                 <button
-                    on:click=move |_| set_show_create_modal.set(true)
-                    class="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors"
+                    on:click=move |_| {
+                        let value = filter_input.get();
+                        set_applied_filter.set(if value.trim().is_empty() { None } else { Some(value) });
+                    }
+                    class="px-3 py-1 text-sm bg-gray-100 rounded-lg hover:bg-gray-200"
                 >
-                    "New Task"
+                    "Apply"
                 </button>
             </div>
 
+            {move || show_save_view.get().then(|| view! {
+                <div class="flex items-center space-x-3 mb-4 p-3 bg-blue-50 border border-blue-200 rounded-lg">
+                    <input
+                        type="text"
+                        placeholder="View name"
+                        class="px-3 py-1 border border-gray-300 rounded-lg text-sm"
+                        prop:value=move || view_name.get()
+                        on:input=move |ev| set_view_name.set(event_target_value(&ev))
+                    />
+                    <label class="flex items-center space-x-1 text-sm text-gray-700">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || view_shared.get()
+                            on:change=move |ev| set_view_shared.set(event_target_checked(&ev))
+                        />
+                        <span>"Share with project"</span>
+                    </label>
+                    <button on:click=handle_save_view class="px-3 py-1 text-sm bg-blue-600 text-white rounded-lg hover:bg-blue-700">
+                        "Save"
+                    </button>
+                    <button
+                        on:click=move |_| {
+                            set_show_save_view.set(false);
+                            set_view_error.set(None);
+                        }
+                        class="px-3 py-1 text-sm text-gray-700 hover:bg-gray-100 rounded-lg"
+                    >
+                        "Cancel"
+                    </button>
+                    {move || view_error.get().map(|err| view! { <span class="text-sm text-red-700">{err}</span> })}
+                </div>
+            })}
+
+            {move || (!selected.get().is_empty()).then(|| view! {
+                <div class="flex flex-wrap items-center gap-3 mb-4 p-3 bg-gray-900 text-white rounded-lg">
+                    <span class="text-sm font-medium">{move || format!("{} selected", selected.get().len())}</span>
+                    <select
+                        class="px-2 py-1 rounded text-sm text-gray-900"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            if !value.is_empty() {
+                                run_bulk(BulkTaskChanges { status: Some(value), ..Default::default() });
+                            }
+                        }
+                    >
+                        <option value="">"Set status…"</option>
+                        <option value="todo">"To Do"</option>
+                        <option value="in_progress">"In Progress"</option>
+                        <option value="done">"Done"</option>
+                    </select>
+                    <select
+                        class="px-2 py-1 rounded text-sm text-gray-900"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            if !value.is_empty() {
+                                run_bulk(BulkTaskChanges { priority: Some(value), ..Default::default() });
+                            }
+                        }
+                    >
+                        <option value="">"Set priority…"</option>
+                        <option value="low">"Low"</option>
+                        <option value="medium">"Medium"</option>
+                        <option value="high">"High"</option>
+                    </select>
+                    <select
+                        class="px-2 py-1 rounded text-sm text-gray-900"
+                        on:change=move |ev| {
+                            if let Ok(label_id) = Uuid::parse_str(&event_target_value(&ev)) {
+                                run_bulk(BulkTaskChanges { add_label_ids: vec![label_id], ..Default::default() });
+                            }
+                        }
+                    >
+                        <option value="">"Add label…"</option>
+                        {move || project_labels.get().into_iter().map(|label| view! {
+                            <option value=label.id.to_string()>{label.name}</option>
+                        }).collect_view()}
+                    </select>
+                    <select
+                        class="px-2 py-1 rounded text-sm text-gray-900"
+                        on:change=move |ev| {
+                            if let Ok(target) = Uuid::parse_str(&event_target_value(&ev)) {
+                                run_bulk(BulkTaskChanges { project_id: Some(target), ..Default::default() });
+                            }
+                        }
+                    >
+                        <option value="">"Move to project…"</option>
+                        {move || org_projects.get().into_iter()
+                            .filter(|p| p.id.to_string() != project_id())
+                            .map(|p| view! { <option value=p.id.to_string()>{p.name}</option> })
+                            .collect_view()}
+                    </select>
+                    <button
+                        on:click=move |_| {
+                            let confirmed = window()
+                                .confirm_with_message(&format!("Delete {} tasks?", selected.get().len()))
+                                .unwrap_or(false);
+                            if confirmed {
+                                run_bulk(BulkTaskChanges { delete: true, ..Default::default() });
+                            }
+                        }
+                        class="px-3 py-1 text-sm bg-red-600 rounded hover:bg-red-700"
+                    >
+                        "Delete"
+                    </button>
+                    <button
+                        on:click=move |_| {
+                            selected.set(vec![]);
+                            set_bulk_error.set(None);
+                        }
+                        class="px-3 py-1 text-sm text-gray-300 hover:text-white"
+                    >
+                        "Clear"
+                    </button>
+                </div>
+            })}
+
+            {move || bulk_error.get().map(|err| view! {
+                <div class="bg-red-50 border border-red-200 rounded-lg p-3 mb-4">
+                    <p class="text-sm text-red-800">{err}</p>
+                </div>
+            })}
+
+            <div class="flex items-center space-x-2 mb-6">
+                <label class="text-sm font-medium text-gray-700">"Label"</label>
+                <select
+                    class="px-3 py-1 border border-gray-300 rounded-lg text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        set_label_filter.set(if value.is_empty() { None } else { Some(value) });
+                    }
+                >
+                    <option value="">"All labels"</option>
+                    <For
+                        each=move || project_labels.get()
+                        key=|label| label.id
+                        children=move |label: Label| {
+                            view! { <option value=label.id.to_string()>{label.name}</option> }
+                        }
+                    />
+                </select>
+            </div>
+
             {move || {
                 if loading.get() {
                     view! {
@@ -157,6 +699,10 @@ pub fn ProjectDetail() -> impl IntoView {
                             <p class="text-red-800">{err}</p>
                         </div>
                     }.into_view()
+                } else if layout.get() == "list" {
+                    view! { <TaskList tasks=tasks.get() group_by=group_by.get() selected=selected/> }.into_view()
+                } else if layout.get() == "calendar" {
+                    view! { <TaskCalendar tasks=tasks.get()/> }.into_view()
                 } else {
                     view! {
                         <div class="grid grid-cols-1 md:grid-cols-3 gap-6">
@@ -166,11 +712,19 @@ pub fn ProjectDetail() -> impl IntoView {
                                     <h2 class="font-semibold text-gray-900">"To Do"</h2>
                                     <span class="text-sm text-gray-500">{move || todo_tasks().len()}</span>
                                 </div>
-                                <div class="space-y-3">
+                                <div
+                                    class="space-y-3 min-h-[4rem]"
+                                    on:dragover=move |ev| ev.prevent_default()
+                                    on:drop=move |ev| {
+                                        ev.prevent_default();
+                                        handle_drop("todo", None);
+                                    }
+                                >
                                     <For
                                         each=todo_tasks
                                         key=|task| task.id
                                         children=move |task: Task| {
+                                            let task_key = task.id;
                                             let task_id = task.id.to_string();
                                             let task_id_for_progress = task_id.clone();
                                             let task_id_for_done = task_id.clone();
@@ -182,13 +736,51 @@ pub fn ProjectDetail() -> impl IntoView {
                                             };
                                             
                                             view! {
-                                                <div class=format!("bg-white rounded-lg p-4 shadow-sm {}", priority_color)>
-                                                    <h3 class="font-medium text-gray-900 mb-2">{task.title.clone()}</h3>
+                                                <div
+                                                    class=format!("bg-white rounded-lg p-4 shadow-smNone {}", priority_color)
+                                                    draggable=move || if can_drag() { "true" } else { "false" }
+                                                    on:dragstart=move |ev| {
+                                                        if let Some(data) = ev.data_transfer() {
+                                                            let _ = data.set_data("text/plain", &task_key.to_string());
+                                                        }
+                                                        set_dragging.set(Some(task_key));
+                                                    }
+                                                    on:dragend=move |_| set_dragging.set(None)
+                                                    on:dragover=move |ev| ev.prevent_default()
+                                                    on:drop=move |ev| {
+                                                        ev.prevent_default();
+                                                        ev.stop_propagation();
+                                                        handle_drop("todo", Some(task_key));
+                                                    }
+                                                >
+                                                    <div class="flex items-start space-x-2 mb-2">
+                                                        <SelectBox task_id=task.id selected=selected/>
+                                                        <A href=format!("/organizations/{}/projects/{}/tasks/{}", org_id(), project_id(), task.id) class="hover:underline">
+                                                            <span class="text-xs text-gray-400 mr-1">{task.key.clone()}</span>
+                                                            <h3 class="font-medium text-gray-900">{task.title.clone()}</h3>
+                                                        </A>
+                                                    </div>
+                                                    {task.is_blocked.then(|| view! {
+                                                        <span class="inline-block mb-2 text-xs px-2 py-1 rounded-full bg-red-100 text-red-800">
+                                                            "Blocked"
+                                                        </span>
+                                                    })}
+                                                    {task.recurrence.clone().map(|rule| view! {
+                                                        <span title=rule class="inline-block mb-2 text-xs px-2 py-1 rounded-full bg-purple-100 text-purple-800">
+                                                            "Repeats"
+                                                        </span>
+                                                    })}
+                                                    {task.checklist_progress.map(|progress| view! {
+                                                        <span class="inline-block mb-2 mr-1 text-xs px-2 py-1 rounded-full bg-gray-100 text-gray-700">
+                                                            {format!("☑ {}/{}", progress.checked, progress.total)}
+                                                        </span>
+                                                    })}
                                                     {task.description.clone().map(|desc| {
                                                         view! {
                                                             <p class="text-sm text-gray-600 mb-3">{desc}</p>
                                                         }
                                                     })}
+                                                    <LabelChips labels=task.labels.clone()/>
                                                     <div class="flex items-center justify-between">
                                                         <span class="text-xs px-2 py-1 rounded-full bg-blue-100 text-blue-800">
                                                             {task.priority.clone()}
@@ -221,11 +813,19 @@ pub fn ProjectDetail() -> impl IntoView {
                                     <h2 class="font-semibold text-gray-900">"In Progress"</h2>
                                     <span class="text-sm text-gray-500">{move || in_progress_tasks().len()}</span>
                                 </div>
-                                <div class="space-y-3">
+                                <div
+                                    class="space-y-3 min-h-[4rem]"
+                                    on:dragover=move |ev| ev.prevent_default()
+                                    on:drop=move |ev| {
+                                        ev.prevent_default();
+                                        handle_drop("in_progress", None);
+                                    }
+                                >
                                     <For
                                         each=in_progress_tasks
                                         key=|task| task.id
                                         children=move |task: Task| {
+                                            let task_key = task.id;
                                             let task_id = task.id.to_string();
                                             let task_id_for_todo = task_id.clone();
                                             let task_id_for_done = task_id.clone();
@@ -237,13 +837,51 @@ pub fn ProjectDetail() -> impl IntoView {
                                             };
                                             
                                             view! {
-                                                <div class=format!("bg-white rounded-lg p-4 shadow-sm {}", priority_color)>
-                                                    <h3 class="font-medium text-gray-900 mb-2">{task.title.clone()}</h3>
+                                                <div
+                                                    class=format!("bg-white rounded-lg p-4 shadow-smNone {}", priority_color)
+                                                    draggable=move || if can_drag() { "true" } else { "false" }
+                                                    on:dragstart=move |ev| {
+                                                        if let Some(data) = ev.data_transfer() {
+                                                            let _ = data.set_data("text/plain", &task_key.to_string());
+                                                        }
+                                                        set_dragging.set(Some(task_key));
+                                                    }
+                                                    on:dragend=move |_| set_dragging.set(None)
+                                                    on:dragover=move |ev| ev.prevent_default()
+                                                    on:drop=move |ev| {
+                                                        ev.prevent_default();
+                                                        ev.stop_propagation();
+                                                        handle_drop("in_progress", Some(task_key));
+                                                    }
+                                                >
+                                                    <div class="flex items-start space-x-2 mb-2">
+                                                        <SelectBox task_id=task.id selected=selected/>
+                                                        <A href=format!("/organizations/{}/projects/{}/tasks/{}", org_id(), project_id(), task.id) class="hover:underline">
+                                                            <span class="text-xs text-gray-400 mr-1">{task.key.clone()}</span>
+                                                            <h3 class="font-medium text-gray-900">{task.title.clone()}</h3>
+                                                        </A>
+                                                    </div>
+                                                    {task.is_blocked.then(|| view! {
+                                                        <span class="inline-block mb-2 text-xs px-2 py-1 rounded-full bg-red-100 text-red-800">
+                                                            "Blocked"
+                                                        </span>
+                                                    })}
+                                                    {task.recurrence.clone().map(|rule| view! {
+                                                        <span title=rule class="inline-block mb-2 text-xs px-2 py-1 rounded-full bg-purple-100 text-purple-800">
+                                                            "Repeats"
+                                                        </span>
+                                                    })}
+                                                    {task.checklist_progress.map(|progress| view! {
+                                                        <span class="inline-block mb-2 mr-1 text-xs px-2 py-1 rounded-full bg-gray-100 text-gray-700">
+                                                            {format!("☑ {}/{}", progress.checked, progress.total)}
+                                                        </span>
+                                                    })}
                                                     {task.description.clone().map(|desc| {
                                                         view! {
                                                             <p class="text-sm text-gray-600 mb-3">{desc}</p>
                                                         }
                                                     })}
+                                                    <LabelChips labels=task.labels.clone()/>
                                                     <div class="flex items-center justify-between">
                                                         <span class="text-xs px-2 py-1 rounded-full bg-yellow-100 text-yellow-800">
                                                             {task.priority.clone()}
@@ -276,11 +914,19 @@ pub fn ProjectDetail() -> impl IntoView {
                                     <h2 class="font-semibold text-gray-900">"Done"</h2>
                                     <span class="text-sm text-gray-500">{move || done_tasks().len()}</span>
                                 </div>
-                                <div class="space-y-3">
+                                <div
+                                    class="space-y-3 min-h-[4rem]"
+                                    on:dragover=move |ev| ev.prevent_default()
+                                    on:drop=move |ev| {
+                                        ev.prevent_default();
+                                        handle_drop("done", None);
+                                    }
+                                >
                                     <For
                                         each=done_tasks
                                         key=|task| task.id
                                         children=move |task: Task| {
+                                            let task_key = task.id;
                                             let task_id = task.id.to_string();
                                             let task_id_for_progress = task_id.clone();
                                             let priority_color = match task.priority.as_str() {
@@ -291,13 +937,41 @@ pub fn ProjectDetail() -> impl IntoView {
                                             };
                                             
                                             view! {
-                                                <div class=format!("bg-white rounded-lg p-4 shadow-sm opacity-75 {}", priority_color)>
-                                                    <h3 class="font-medium text-gray-900 mb-2 line-through">{task.title.clone()}</h3>
+                                                <div
+                                                    class=format!("bg-white rounded-lg p-4 shadow-sm opacity-75 {}", priority_color)
+                                                    draggable=move || if can_drag() { "true" } else { "false" }
+                                                    on:dragstart=move |ev| {
+                                                        if let Some(data) = ev.data_transfer() {
+                                                            let _ = data.set_data("text/plain", &task_key.to_string());
+                                                        }
+                                                        set_dragging.set(Some(task_key));
+                                                    }
+                                                    on:dragend=move |_| set_dragging.set(None)
+                                                    on:dragover=move |ev| ev.prevent_default()
+                                                    on:drop=move |ev| {
+                                                        ev.prevent_default();
+                                                        ev.stop_propagation();
+                                                        handle_drop("done", Some(task_key));
+                                                    }
+                                                >
+                                                    <div class="flex items-start space-x-2 mb-2">
+                                                        <SelectBox task_id=task.id selected=selected/>
+                                                        <A href=format!("/organizations/{}/projects/{}/tasks/{}", org_id(), project_id(), task.id) class="hover:underline">
+                                                            <span class="text-xs text-gray-400 mr-1">{task.key.clone()}</span>
+                                                            <h3 class="font-medium text-gray-900 line-through">{task.title.clone()}</h3>
+                                                        </A>
+                                                    </div>
+                                                    {task.checklist_progress.map(|progress| view! {
+                                                        <span class="inline-block mb-2 mr-1 text-xs px-2 py-1 rounded-full bg-gray-100 text-gray-700">
+                                                            {format!("☑ {}/{}", progress.checked, progress.total)}
+                                                        </span>
+                                                    })}
                                                     {task.description.clone().map(|desc| {
                                                         view! {
                                                             <p class="text-sm text-gray-600 mb-3">{desc}</p>
                                                         }
                                                     })}
+                                                    <LabelChips labels=task.labels.clone()/>
                                                     <div class="flex items-center justify-between">
                                                         <span class="text-xs px-2 py-1 rounded-full bg-green-100 text-green-800">
                                                             {task.priority.clone()}
@@ -320,6 +994,62 @@ pub fn ProjectDetail() -> impl IntoView {
                 }
             }}
 
+            // Edit conflict dialog
+            {move || conflict.get().map(|(latest, mine)| {
+                let latest_for_keep = latest.clone();
+                let (latest_id, latest_version) = (latest.id, latest.version);
+                let mine_summary = mine
+                    .status
+                    .clone()
+                    .map(|status| format!("Move to \"{}\"", status))
+                    .unwrap_or_else(|| "Your edit".to_string());
+                view! {
+                    <div class="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center p-4 z-50">
+                        <div class="bg-white rounded-lg max-w-md w-full p-6">
+                            <h2 class="text-xl font-bold mb-2">"This task was changed by someone else"</h2>
+                            <p class="text-sm text-gray-600 mb-4">
+                                "Your change was not saved. This is the latest version on the server:"
+                            </p>
+                            <dl class="text-sm bg-gray-50 rounded-lg p-3 mb-4 grid grid-cols-3 gap-y-1">
+                                <dt class="text-gray-500">"Title"</dt>
+                                <dd class="col-span-2 text-gray-900">{latest.title.clone()}</dd>
+                                <dt class="text-gray-500">"Status"</dt>
+                                <dd class="col-span-2 text-gray-900">{latest.status.clone()}</dd>
+                                <dt class="text-gray-500">"Priority"</dt>
+                                <dd class="col-span-2 text-gray-900">{latest.priority.clone()}</dd>
+                                <dt class="text-gray-500">"Description"</dt>
+                                <dd class="col-span-2 text-gray-900">{latest.description.clone().unwrap_or_default()}</dd>
+                                <dt class="text-gray-500">"Updated"</dt>
+                                <dd class="col-span-2 text-gray-900">
+                                    {latest.updated_at.with_timezone(&chrono::Local).format("%b %d %H:%M").to_string()}
+                                </dd>
+                            </dl>
+                            <p class="text-sm text-gray-700 mb-4">"Your change: " {mine_summary}</p>
+                            <div class="flex justify-end space-x-3">
+                                <button
+                                    on:click=move |_| {
+                                        replace_task(latest_for_keep.clone());
+                                        set_conflict.set(None);
+                                    }
+                                    class="px-4 py-2 text-gray-700 hover:bg-gray-100 rounded-lg transition-colors"
+                                >
+                                    "Keep theirs"
+                                </button>
+                                <button
+                                    on:click=move |_| {
+                                        set_conflict.set(None);
+                                        save_task(latest_id, latest_version, mine.clone());
+                                    }
+                                    class="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors"
+                                >
+                                    "Apply mine"
+                                </button>
+                            </div>
+                        </div>
+                    </div>
+                }
+            })}
+
             // Create Task Modal
             {move || if show_create_modal.get() {
                 view! {
@@ -403,3 +1133,133 @@ pub fn ProjectDetail() -> impl IntoView {
         </div>
     }
 }
+
+#[component]
+fn LabelChips(labels: Vec<Label>) -> impl IntoView {
+    view! {
+        <div class="flex flex-wrap gap-1 mb-2">
+            {labels
+                .into_iter()
+                .map(|label| {
+                    view! {
+                        <span
+                            class="text-xs px-2 py-0.5 rounded-full text-white"
+                            style=format!("background-color: {}", label.color)
+                        >
+                            {label.name}
+                        </span>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
+
+/// Split tasks into named groups, keeping the order tasks arrived in
+fn group_tasks(tasks: Vec<Task>, group_by: &str) -> Vec<(String, Vec<Task>)> {
+    let mut groups: Vec<(String, Vec<Task>)> = Vec::new();
+    for task in tasks {
+        let keys = match group_by {
+            "status" => vec![task.status.clone()],
+            "priority" => vec![task.priority.clone()],
+            "assignee" if task.assignees.is_empty() => vec!["Unassigned".to_string()],
+            "assignee" => task.assignees.iter().map(|id| id.to_string()).collect(),
+            "label" if task.labels.is_empty() => vec!["No label".to_string()],
+            "label" => task.labels.iter().map(|l| l.name.clone()).collect(),
+            "due_date" => vec![task
+                .due_date
+                .map(|d| d.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "No due date".to_string())],
+            _ => vec!["All tasks".to_string()],
+        };
+        for key in keys {
+            match groups.iter_mut().find(|(name, _)| *name == key) {
+                Some((_, group)) => group.push(task.clone()),
+                None => groups.push((key, vec![task.clone()])),
+            }
+        }
+    }
+    groups
+}
+
+/// Checkbox adding a task to the bulk selection
+#[component]
+fn SelectBox(task_id: Uuid, selected: RwSignal<Vec<Uuid>>) -> impl IntoView {
+    view! {
+        <input
+            type="checkbox"
+            class="mt-1"
+            prop:checked=move || selected.with(|s| s.contains(&task_id))
+            on:change=move |ev| {
+                let checked = event_target_checked(&ev);
+                selected.update(|s| {
+                    s.retain(|id| *id != task_id);
+                    if checked {
+                        s.push(task_id);
+                    }
+                });
+            }
+        />
+    }
+}
+
+#[component]
+fn TaskList(tasks: Vec<Task>, group_by: String, selected: RwSignal<Vec<Uuid>>) -> impl IntoView {
+    view! {
+        <div class="space-y-6">
+            {group_tasks(tasks, &group_by).into_iter().map(|(name, group)| {
+                view! {
+                    <div class="bg-white shadow rounded-lg">
+                        <div class="px-4 py-2 border-b border-gray-200 flex justify-between">
+                            <h2 class="font-semibold text-gray-900">{name}</h2>
+                            <span class="text-sm text-gray-500">{group.len()}</span>
+                        </div>
+                        <table class="min-w-full text-sm">
+                            <tbody class="divide-y divide-gray-100">
+                                {group.into_iter().map(|task| view! {
+                                    <tr>
+                                        <td class="px-4 py-2 w-8"><SelectBox task_id=task.id selected=selected/></td>
+                                        <td class="px-4 py-2 text-gray-900">{task.title.clone()}</td>
+                                        <td class="px-4 py-2 text-gray-600">{task.status.clone()}</td>
+                                        <td class="px-4 py-2 text-gray-600">{task.priority.clone()}</td>
+                                        <td class="px-4 py-2 text-gray-500">
+                                            {task.due_date.map(|d| d.with_timezone(&chrono::Local).format("%b %d").to_string())}
+                                        </td>
+                                        <td class="px-4 py-2"><LabelChips labels=task.labels.clone()/></td>
+                                    </tr>
+                                }).collect_view()}
+                            </tbody>
+                        </table>
+                    </div>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
+
+/// Tasks laid out by due day; tasks without a due date come last
+#[component]
+fn TaskCalendar(tasks: Vec<Task>) -> impl IntoView {
+    let mut tasks = tasks;
+    tasks.sort_by_key(|t| (t.due_date.is_none(), t.due_date));
+
+    view! {
+        <div class="grid grid-cols-1 md:grid-cols-4 gap-4">
+            {group_tasks(tasks, "due_date").into_iter().map(|(day, group)| {
+                view! {
+                    <div class="bg-gray-50 rounded-lg p-3">
+                        <h2 class="font-semibold text-gray-900 mb-2">{day}</h2>
+                        <ul class="space-y-2">
+                            {group.into_iter().map(|task| view! {
+                                <li class="bg-white rounded p-2 shadow-sm text-sm">
+                                    <p class="text-gray-900">{task.title.clone()}</p>
+                                    <p class="text-xs text-gray-500">{format!("{} · {}", task.status, task.priority)}</p>
+                                </li>
+                            }).collect_view()}
+                        </ul>
+                    </div>
+                }
+            }).collect_view()}
+        </div>
+    }
+}
@@ -1,6 +1,7 @@
 use leptos::*;
 use leptos_router::*;
 
+use crate::components::SearchBox;
 use crate::services::projects::{self, CreateProjectRequest, Project};
 
 #[component]
@@ -18,6 +19,7 @@ pub fn Projects() -> impl IntoView {
     // Form state
     let (name, set_name) = create_signal(String::new());
     let (slug, set_slug) = create_signal(String::new());
+    let (key, set_key) = create_signal(String::new());
     let (description, set_description) = create_signal(String::new());
     let (status, set_status) = create_signal(String::from("planning"));
     let (color, set_color) = create_signal(String::from("#3B82F6"));
@@ -77,6 +79,11 @@ pub fn Projects() -> impl IntoView {
             let request = CreateProjectRequest {
                 name: name_val.clone(),
                 slug: slug_val.clone(),
+                key: if key.get().is_empty() {
+                    None
+                } else {
+                    Some(key.get().to_uppercase())
+                },
                 description: if description.get().is_empty() {
                     None
                 } else {
@@ -84,6 +91,7 @@ pub fn Projects() -> impl IntoView {
                 },
                 status: Some(status.get()),
                 color: Some(color.get()),
+                enforce_dependencies: None,
             };
 
             match projects::create_project(&org_id_val, request).await {
@@ -93,6 +101,7 @@ pub fn Projects() -> impl IntoView {
                     set_show_create_modal.set(false);
                     set_name.set(String::new());
                     set_slug.set(String::new());
+                    set_key.set(String::new());
                     set_description.set(String::new());
                     set_status.set(String::from("planning"));
                     set_color.set(String::from("#3B82F6"));
@@ -113,12 +122,15 @@ pub fn Projects() -> impl IntoView {
                     <h1 class="text-3xl font-bold text-gray-900">"Projects"</h1>
                     <p class="mt-2 text-gray-600">"Organize your work into projects"</p>
                 </div>
-                <button
-                    on:click=move |_| set_show_create_modal.set(true)
-                    class="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors"
-                >
-                    "Create Project"
-                </button>
+                <div class="flex items-center space-x-4">
+                    {move || view! { <SearchBox org_id=org_id()/> }}
+                    <button
+                        on:click=move |_| set_show_create_modal.set(true)
+                        class="px-4 py-2 bg-blue-600 text-white rounded-lg hover:bg-blue-700 transition-colors"
+                    >
+                        "Create Project"
+                    </button>
+                </div>
             </div>
 
             {move || {
@@ -170,7 +182,7 @@ pub fn Projects() -> impl IntoView {
                                                     <h3 class="text-xl font-semibold text-gray-900">{project.name.clone()}</h3>
                                                 </div>
                                                 <p class="text-sm text-gray-500 mb-4">
-                                                    {project.slug.clone()}
+                                                    {format!("{} · {}", project.key, project.slug)}
                                                 </p>
                                                 {project.description.clone().map(|desc| {
                                                     view! {
@@ -234,6 +246,20 @@ pub fn Projects() -> impl IntoView {
                                     />
                                 </div>
 
+                                <div>
+                                    <label class="block text-sm font-medium text-gray-700 mb-1">
+                                        "Task Key Prefix"
+                                    </label>
+                                    <input
+                                        type="text"
+                                        placeholder="Derived from the name, e.g. WEB"
+                                        maxlength="10"
+                                        class="w-full px-3 py-2 border border-gray-300 rounded-lg focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                        prop:value=move || key.get()
+                                        on:input=move |ev| set_key.set(event_target_value(&ev))
+                                    />
+                                </div>
+
                                 <div>
                                     <label class="block text-sm font-medium text-gray-700 mb-1">
                                         "Description"
@@ -0,0 +1,143 @@
+use leptos::*;
+use leptos_router::*;
+
+use crate::components::SearchBox;
+use crate::services::search::{self, snippet_parts, SearchResult};
+
+#[component]
+pub fn Search() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let org_id = move || params.with(|p| p.get("org_id").cloned().unwrap_or_default());
+    let q = move || query.with(|q| q.get("q").cloned().unwrap_or_default());
+
+    let (results, set_results) = create_signal::<Vec<SearchResult>>(vec![]);
+    let (next_cursor, set_next_cursor) = create_signal::<Option<String>>(None);
+    let (loading, set_loading) = create_signal(false);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+
+    // Run the search whenever the query changes
+    create_effect(move |_| {
+        let org_id_val = org_id();
+        let q_val = q();
+        set_results.set(vec![]);
+        set_next_cursor.set(None);
+        if org_id_val.is_empty() || q_val.trim().is_empty() {
+            return;
+        }
+        spawn_local(async move {
+            set_loading.set(true);
+            match search::search(&org_id_val, &q_val, None).await {
+                Ok(page) => {
+                    set_results.set(page.items);
+                    set_next_cursor.set(page.next_cursor);
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Search failed: {}", e);
+                    set_error.set(Some(format!("Search failed: {}", e)));
+                }
+            }
+            set_loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let Some(cursor) = next_cursor.get() else {
+            return;
+        };
+        let org_id_val = org_id();
+        let q_val = q();
+        spawn_local(async move {
+            set_loading.set(true);
+            match search::search(&org_id_val, &q_val, Some(&cursor)).await {
+                Ok(page) => {
+                    set_results.update(|r| r.extend(page.items));
+                    set_next_cursor.set(page.next_cursor);
+                }
+                Err(e) => set_error.set(Some(format!("Search failed: {}", e))),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="max-w-5xl mx-auto px-4 sm:px-6 lg:px-8 py-8">
+            <div class="mb-6">
+                <A href=move || format!("/organizations/{}/projects", org_id()) class="text-sm text-indigo-600 hover:text-indigo-500">
+                    "← Back to Projects"
+                </A>
+            </div>
+
+            <div class="flex justify-between items-center mb-8">
+                <h1 class="text-3xl font-bold text-gray-900">"Search"</h1>
+                {move || view! { <SearchBox org_id=org_id() initial=q()/> }}
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="bg-red-50 border border-red-200 rounded-lg p-4 mb-6">
+                    <p class="text-red-800">{err}</p>
+                </div>
+            })}
+
+            {move || {
+                if !loading.get() && results.get().is_empty() && !q().trim().is_empty() && error.get().is_none() {
+                    view! {
+                        <p class="text-center text-gray-500 py-12">"No results for \"" {q()} "\""</p>
+                    }.into_view()
+                } else {
+                    view! {
+                        <ul class="space-y-3">
+                            <For
+                                each=move || results.get()
+                                key=|result| (result.kind.clone(), result.id)
+                                children=move |result: SearchResult| {
+                                    let href = format!("/organizations/{}/projects/{}", org_id(), result.project_id);
+                                    view! {
+                                        <li class="bg-white shadow rounded-lg p-4">
+                                            <a href=href class="block">
+                                                <div class="flex items-center space-x-2 mb-1">
+                                                    <span class="px-2 py-0.5 text-xs rounded bg-gray-100 text-gray-700 uppercase">
+                                                        {result.kind.clone()}
+                                                    </span>
+                                                    <span class="text-sm font-medium text-gray-900">{result.title.clone()}</span>
+                                                    <span class="text-xs text-gray-500">{format!("in {}", result.project_name)}</span>
+                                                </div>
+                                                <p class="text-sm text-gray-600">
+                                                    {snippet_parts(&result.snippet).into_iter().map(|part| {
+                                                        if part.highlighted {
+                                                            view! { <mark class="bg-yellow-200">{part.text}</mark> }.into_view()
+                                                        } else {
+                                                            part.text.into_view()
+                                                        }
+                                                    }).collect_view()}
+                                                </p>
+                                            </a>
+                                        </li>
+                                    }
+                                }
+                            />
+                        </ul>
+                    }.into_view()
+                }
+            }}
+
+            {move || loading.get().then(|| view! {
+                <div class="flex justify-center items-center py-6">
+                    <div class="animate-spin rounded-full h-8 w-8 border-b-2 border-blue-600"></div>
+                </div>
+            })}
+
+            {move || (next_cursor.get().is_some() && !loading.get()).then(|| view! {
+                <div class="flex justify-center mt-6">
+                    <button
+                        on:click=load_more
+                        class="px-4 py-2 border border-gray-300 rounded-md text-sm text-gray-700 bg-white hover:bg-gray-50"
+                    >
+                        "Load more"
+                    </button>
+                </div>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,1205 @@
+use chrono::{DateTime, Utc};
+use leptos::*;
+use leptos_router::*;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::services::attachments::{self, format_size, Attachment};
+use crate::services::checklists::{
+    self, ChecklistItem, CreateChecklistItemRequest, MoveChecklistItemRequest,
+    UpdateChecklistItemRequest,
+};
+use crate::components::auth_context::use_auth_context;
+use crate::services::organizations;
+use crate::services::projects::{self, Project};
+use crate::services::tasks::{
+    self, Comment, CreateCommentRequest, HistoryEntry, Recurrence, SetRecurrenceRequest, Task,
+    TransferTaskRequest, UpdateTaskRequest, Watcher,
+};
+use crate::services::time_tracking::{
+    self, format_duration, CreateTimeLogRequest, StartTimerRequest, TimeLog,
+};
+
+/// One item of the activity timeline
+#[derive(Debug, Clone)]
+enum Activity {
+    Change(HistoryEntry),
+    Comment(Comment),
+}
+
+impl Activity {
+    fn at(&self) -> DateTime<Utc> {
+        match self {
+            Activity::Change(entry) => entry.created_at,
+            Activity::Comment(comment) => comment.created_at,
+        }
+    }
+
+    fn key(&self) -> Uuid {
+        match self {
+            Activity::Change(entry) => entry.id,
+            Activity::Comment(comment) => comment.id,
+        }
+    }
+}
+
+/// Hours typed by the user, as whole seconds; empty means no estimate
+fn hours_to_seconds(value: &str) -> Result<Option<i32>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<f64>() {
+        Ok(hours) if hours >= 0.0 => Ok(Some((hours * 3600.0).round() as i32)),
+        _ => Err(format!("\"{}\" is not a number of hours", value)),
+    }
+}
+
+fn seconds_to_hours(seconds: Option<i32>) -> String {
+    seconds
+        .map(|s| format!("{}", (f64::from(s) / 36.0).round() / 100.0))
+        .unwrap_or_default()
+}
+
+/// History entries and comments in chronological order
+fn timeline(history: Vec<HistoryEntry>, comments: Vec<Comment>) -> Vec<Activity> {
+    let mut items: Vec<Activity> = history
+        .into_iter()
+        .map(Activity::Change)
+        .chain(comments.into_iter().map(Activity::Comment))
+        .collect();
+    items.sort_by_key(|item| item.at());
+    items
+}
+
+fn field_label(field: &str) -> &str {
+    match field {
+        "assigned_to" => "primary assignee",
+        "due_date" => "due date",
+        "project_id" => "project",
+        "original_estimate_seconds" => "estimate (seconds)",
+        "remaining_estimate_seconds" => "remaining estimate (seconds)",
+        other => other,
+    }
+}
+
+fn display_value(value: &Option<serde_json::Value>, names: &HashMap<Uuid, String>) -> String {
+    match value {
+        None | Some(serde_json::Value::Null) => "none".to_string(),
+        Some(serde_json::Value::String(s)) => s
+            .parse::<Uuid>()
+            .ok()
+            .and_then(|id| names.get(&id).cloned())
+            .or_else(|| {
+                s.parse::<DateTime<Utc>>()
+                    .ok()
+                    .map(|d| d.format("%b %d, %Y").to_string())
+            })
+            .unwrap_or_else(|| s.clone()),
+        Some(serde_json::Value::Array(items)) => items
+            .iter()
+            .map(|item| display_value(&Some(item.clone()), names))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[component]
+pub fn TaskDetail() -> impl IntoView {
+    let params = use_params_map();
+    let org_id = move || params.with(|p| p.get("org_id").cloned().unwrap_or_default());
+    let project_id = move || params.with(|p| p.get("project_id").cloned().unwrap_or_default());
+    let task_id = move || params.with(|p| p.get("task_id").cloned().unwrap_or_default());
+
+    let (task, set_task) = create_signal::<Option<Task>>(None);
+    let (activity, set_activity) = create_signal::<Vec<Activity>>(Vec::new());
+    let (names, set_names) = create_signal::<HashMap<Uuid, String>>(HashMap::new());
+    let (loading, set_loading) = create_signal(true);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+    let (comment_input, set_comment_input) = create_signal(String::new());
+    let (posting, set_posting) = create_signal(false);
+    let (projects_list, set_projects_list) = create_signal::<Vec<Project>>(Vec::new());
+    let (target_project, set_target_project) = create_signal(String::new());
+    let (recurrence, set_recurrence) = create_signal::<Option<Recurrence>>(None);
+    let (rule_input, set_rule_input) = create_signal(String::new());
+    let (timezone_input, set_timezone_input) = create_signal("UTC".to_string());
+    let (time_logs, set_time_logs) = create_signal::<Vec<TimeLog>>(Vec::new());
+    let (running_timer, set_running_timer) = create_signal::<Option<TimeLog>>(None);
+    let (estimate_input, set_estimate_input) = create_signal(String::new());
+    let (remaining_input, set_remaining_input) = create_signal(String::new());
+    let (log_hours, set_log_hours) = create_signal(String::new());
+    let (log_note, set_log_note) = create_signal(String::new());
+    let (checklist, set_checklist) = create_signal::<Vec<ChecklistItem>>(Vec::new());
+    let (new_item, set_new_item) = create_signal(String::new());
+    let (editing_item, set_editing_item) = create_signal::<Option<Uuid>>(None);
+    let (edit_input, set_edit_input) = create_signal(String::new());
+    let (watchers, set_watchers) = create_signal::<Vec<Watcher>>(Vec::new());
+    let (attachment_list, set_attachment_list) = create_signal::<Vec<Attachment>>(Vec::new());
+    let (uploading, set_uploading) = create_signal(false);
+    let (strip_metadata, set_strip_metadata) = create_signal(false);
+    let (drag_over, set_drag_over) = create_signal(false);
+    let auth = use_auth_context();
+    let current_user = move || auth.user.get().and_then(|u| u.id.parse::<Uuid>().ok());
+    let navigate = use_navigate();
+
+    let load_activity = move || {
+        let id = task_id();
+        spawn_local(async move {
+            let history = tasks::get_task_history(&id).await;
+            let comments = tasks::get_task_comments(&id).await;
+            match (history, comments) {
+                (Ok(history), Ok(comments)) => set_activity.set(timeline(history, comments)),
+                (Err(e), _) | (_, Err(e)) => {
+                    log::error!("Failed to load activity: {}", e);
+                    set_error.set(Some(format!("Failed to load activity: {}", e)));
+                }
+            }
+        });
+    };
+
+    // Logged totals live on the task, so it is reloaded along with the logs
+    let load_time = move || {
+        let id = task_id();
+        spawn_local(async move {
+            match time_tracking::get_task_time_logs(&id).await {
+                Ok(logs) => set_time_logs.set(logs),
+                Err(e) => log::error!("Failed to load time logs: {}", e),
+            }
+            if let Ok(timer) = time_tracking::get_running_timer().await {
+                set_running_timer.set(timer);
+            }
+            if let Ok(t) = tasks::get_task(&id).await {
+                set_task.set(Some(t));
+            }
+        });
+    };
+
+    let load_checklist = move || {
+        let id = task_id();
+        spawn_local(async move {
+            match checklists::get_checklist(&id).await {
+                Ok(items) => set_checklist.set(items),
+                Err(e) => log::error!("Failed to load checklist: {}", e),
+            }
+        });
+    };
+
+    let load_attachments = move || {
+        let id = task_id();
+        spawn_local(async move {
+            match attachments::get_task_attachments(&id).await {
+                Ok(list) => set_attachment_list.set(list),
+                Err(e) => log::error!("Failed to load attachments: {}", e),
+            }
+        });
+    };
+
+    create_effect(move |_| {
+        let id = task_id();
+        let org = org_id();
+        spawn_local(async move {
+            set_loading.set(true);
+            match tasks::get_task(&id).await {
+                Ok(t) => {
+                    set_estimate_input.set(seconds_to_hours(t.original_estimate_seconds));
+                    set_remaining_input.set(seconds_to_hours(t.remaining_estimate_seconds));
+                    set_task.set(Some(t));
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Failed to load task: {}", e);
+                    set_error.set(Some(format!("Failed to load task: {}", e)));
+                }
+            }
+            set_loading.set(false);
+
+            // Tasks that do not recur answer with 404
+            match tasks::get_task_recurrence(&id).await {
+                Ok(r) => {
+                    set_rule_input.set(r.rule.clone());
+                    set_timezone_input.set(r.timezone.clone());
+                    set_recurrence.set(Some(r));
+                }
+                Err(_) => set_recurrence.set(None),
+            }
+
+            if let Ok(list) = tasks::get_task_watchers(&id).await {
+                set_watchers.set(list);
+            }
+
+            if let Ok(list) = projects::get_organization_projects(&org).await {
+                set_projects_list.set(list);
+            }
+
+            // Member names are only a nicety for the timeline
+            if let Ok(org_uuid) = org.parse::<Uuid>() {
+                if let Ok(members) = organizations::get_organization_members(org_uuid).await {
+                    set_names.set(
+                        members
+                            .into_iter()
+                            .map(|m| {
+                                let name = match (m.user_first_name, m.user_last_name) {
+                                    (Some(first), Some(last)) => format!("{} {}", first, last),
+                                    (Some(first), None) => first,
+                                    _ => m.user_email,
+                                };
+                                (m.user_id, name)
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        });
+        load_activity();
+        load_time();
+        load_checklist();
+        load_attachments();
+    });
+
+    // Uploads are scanned for malware in the background; check back until they're done
+    create_effect(move |_| {
+        if attachment_list.with(|list| list.iter().any(|a| a.scan_status == "pending")) {
+            set_timeout(load_attachments, std::time::Duration::from_secs(5));
+        }
+    });
+
+    let handle_comment = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let content = comment_input.get().trim().to_string();
+        if content.is_empty() {
+            return;
+        }
+        let id = task_id();
+        spawn_local(async move {
+            set_posting.set(true);
+            match tasks::create_comment(&id, CreateCommentRequest { content }).await {
+                Ok(_) => {
+                    set_comment_input.set(String::new());
+                    load_activity();
+                }
+                Err(e) => {
+                    log::error!("Failed to add comment: {}", e);
+                    set_error.set(Some(format!("Failed to add comment: {}", e)));
+                }
+            }
+            set_posting.set(false);
+        });
+    };
+
+    // Deleted comments go to the project trash and can be restored from there
+    let handle_delete_comment = move |comment_id: Uuid| {
+        spawn_local(async move {
+            match tasks::delete_comment(comment_id).await {
+                Ok(()) => load_activity(),
+                Err(e) => {
+                    log::error!("Failed to delete comment: {}", e);
+                    set_error.set(Some(format!("Failed to delete comment: {}", e)));
+                }
+            }
+        });
+    };
+
+    // The task keeps its comments and history but gets a key in the new project
+    let handle_transfer = move |_| {
+        let Some(t) = task.get() else { return };
+        let Ok(project_id) = target_project.get().parse::<Uuid>() else { return };
+        let org = org_id();
+        let navigate = navigate.clone();
+        spawn_local(async move {
+            let request = TransferTaskRequest { project_id, status: None };
+            match tasks::transfer_task(t.id, request).await {
+                Ok(moved) => {
+                    set_target_project.set(String::new());
+                    navigate(
+                        &format!("/organizations/{}/projects/{}/tasks/{}", org, moved.project_id, moved.id),
+                        Default::default(),
+                    );
+                }
+                Err(e) => {
+                    log::error!("Failed to move task: {}", e);
+                    set_error.set(Some(format!("Failed to move task: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_save_recurrence = move |_| {
+        let id = task_id();
+        let request = SetRecurrenceRequest {
+            rule: rule_input.get().trim().to_string(),
+            timezone: timezone_input.get().trim().to_string(),
+        };
+        spawn_local(async move {
+            match tasks::set_task_recurrence(&id, request).await {
+                Ok(r) => {
+                    set_rule_input.set(r.rule.clone());
+                    set_recurrence.set(Some(r));
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Failed to save recurrence: {}", e);
+                    set_error.set(Some(format!("Failed to save recurrence: {}", e)));
+                }
+            }
+        });
+    };
+
+    // Occurrences created so far are kept
+    let handle_stop_recurrence = move |_| {
+        let id = task_id();
+        spawn_local(async move {
+            match tasks::delete_task_recurrence(&id).await {
+                Ok(()) => set_recurrence.set(None),
+                Err(e) => {
+                    log::error!("Failed to stop recurrence: {}", e);
+                    set_error.set(Some(format!("Failed to stop recurrence: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_save_estimates = move |_| {
+        let id = task_id();
+        let (original, remaining) =
+            match (hours_to_seconds(&estimate_input.get()), hours_to_seconds(&remaining_input.get())) {
+                (Ok(original), Ok(remaining)) => (original, remaining),
+                (Err(e), _) | (_, Err(e)) => {
+                    set_error.set(Some(e));
+                    return;
+                }
+            };
+        let request = UpdateTaskRequest {
+            original_estimate_seconds: Some(original),
+            remaining_estimate_seconds: Some(remaining),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match tasks::update_task(&id, request).await {
+                Ok(t) => {
+                    set_task.set(Some(t));
+                    set_error.set(None);
+                    load_activity();
+                }
+                Err(e) => {
+                    log::error!("Failed to save estimates: {}", e);
+                    set_error.set(Some(format!("Failed to save estimates: {}", e)));
+                }
+            }
+        });
+    };
+
+    let timer_on_this_task = move || {
+        let current = task.get().map(|t| t.id);
+        running_timer.get().is_some_and(|timer| Some(timer.task_id) == current)
+    };
+
+    let handle_timer = move |_| {
+        let Some(t) = task.get() else { return };
+        let stop = timer_on_this_task();
+        spawn_local(async move {
+            let result = if stop {
+                time_tracking::stop_timer().await
+            } else {
+                time_tracking::start_timer(t.id, StartTimerRequest::default()).await
+            };
+            match result {
+                Ok(_) => {
+                    set_error.set(None);
+                    load_time();
+                }
+                Err(e) => {
+                    log::error!("Failed to update timer: {}", e);
+                    set_error.set(Some(format!("Failed to update timer: {}", e)));
+                }
+            }
+        });
+    };
+
+    // Manual entries end now and reach back by the logged duration
+    let handle_log_time = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let Some(t) = task.get() else { return };
+        let seconds = match hours_to_seconds(&log_hours.get()) {
+            Ok(Some(seconds)) if seconds > 0 => seconds,
+            Ok(_) => return,
+            Err(e) => {
+                set_error.set(Some(e));
+                return;
+            }
+        };
+        let note = log_note.get().trim().to_string();
+        let request = CreateTimeLogRequest {
+            started_at: Utc::now() - chrono::Duration::seconds(i64::from(seconds)),
+            duration_seconds: seconds,
+            description: if note.is_empty() { None } else { Some(note) },
+        };
+        spawn_local(async move {
+            match time_tracking::create_time_log(t.id, request).await {
+                Ok(_) => {
+                    set_log_hours.set(String::new());
+                    set_log_note.set(String::new());
+                    set_error.set(None);
+                    load_time();
+                }
+                Err(e) => {
+                    log::error!("Failed to log time: {}", e);
+                    set_error.set(Some(format!("Failed to log time: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_delete_log = move |log_id: Uuid| {
+        spawn_local(async move {
+            match time_tracking::delete_time_log(log_id).await {
+                Ok(()) => load_time(),
+                Err(e) => {
+                    log::error!("Failed to delete time log: {}", e);
+                    set_error.set(Some(format!("Failed to delete time log: {}", e)));
+                }
+            }
+        });
+    };
+
+    // The first assignee in the list becomes the primary one
+    let set_assignees = move |assignee_ids: Vec<Uuid>| {
+        let id = task_id();
+        let request = UpdateTaskRequest {
+            assignee_ids: Some(assignee_ids),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match tasks::update_task(&id, request).await {
+                Ok(t) => {
+                    set_task.set(Some(t));
+                    set_error.set(None);
+                    load_activity();
+                    if let Ok(list) = tasks::get_task_watchers(&id).await {
+                        set_watchers.set(list);
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to update assignees: {}", e);
+                    set_error.set(Some(format!("Failed to update assignees: {}", e)));
+                }
+            }
+        });
+    };
+
+    let upload_files = move |files: Vec<web_sys::File>| {
+        if files.is_empty() {
+            return;
+        }
+        let id = task_id();
+        let strip = strip_metadata.get_untracked();
+        spawn_local(async move {
+            set_uploading.set(true);
+            match attachments::upload_attachments(&id, files, strip).await {
+                Ok(uploaded) => {
+                    set_error.set(None);
+                    load_attachments();
+                    // Thumbnails are rendered in the background shortly after upload
+                    if uploaded.iter().any(|a| a.mime_type.starts_with("image/")) {
+                        set_timeout(load_attachments, std::time::Duration::from_secs(5));
+                    }
+                }
+                Err(e) => {
+                    log::error!("Failed to upload attachments: {}", e);
+                    set_error.set(Some(format!("Failed to upload attachments: {}", e)));
+                }
+            }
+            set_uploading.set(false);
+        });
+    };
+
+    let handle_download = move |attachment: Attachment| {
+        spawn_local(async move {
+            if let Err(e) = attachments::download_attachment(&attachment).await {
+                log::error!("Failed to download attachment: {}", e);
+                set_error.set(Some(format!("Failed to download attachment: {}", e)));
+            }
+        });
+    };
+
+    let handle_delete_attachment = move |attachment_id: Uuid| {
+        spawn_local(async move {
+            match attachments::delete_attachment(attachment_id).await {
+                Ok(()) => load_attachments(),
+                Err(e) => {
+                    log::error!("Failed to delete attachment: {}", e);
+                    set_error.set(Some(format!("Failed to delete attachment: {}", e)));
+                }
+            }
+        });
+    };
+
+    let is_watching = move || {
+        let me = current_user();
+        watchers.with(|list| list.iter().any(|w| Some(w.user_id) == me))
+    };
+
+    let handle_toggle_watch = move |_| {
+        let id = task_id();
+        let unwatch = is_watching();
+        spawn_local(async move {
+            let result = if unwatch {
+                match tasks::unwatch_task(&id).await {
+                    Ok(()) => tasks::get_task_watchers(&id).await,
+                    Err(e) => Err(e),
+                }
+            } else {
+                tasks::watch_task(&id).await
+            };
+            match result {
+                Ok(list) => set_watchers.set(list),
+                Err(e) => {
+                    log::error!("Failed to update watching: {}", e);
+                    set_error.set(Some(format!("Failed to update watching: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_add_item = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let content = new_item.get().trim().to_string();
+        if content.is_empty() {
+            return;
+        }
+        let id = task_id();
+        spawn_local(async move {
+            let request = CreateChecklistItemRequest { content, assigned_to: None };
+            match checklists::create_checklist_item(&id, request).await {
+                Ok(_) => {
+                    set_new_item.set(String::new());
+                    load_checklist();
+                }
+                Err(e) => {
+                    log::error!("Failed to add checklist item: {}", e);
+                    set_error.set(Some(format!("Failed to add checklist item: {}", e)));
+                }
+            }
+        });
+    };
+
+    let update_item = move |item_id: Uuid, request: UpdateChecklistItemRequest| {
+        spawn_local(async move {
+            match checklists::update_checklist_item(item_id, request).await {
+                Ok(_) => {
+                    set_editing_item.set(None);
+                    load_checklist();
+                }
+                Err(e) => {
+                    log::error!("Failed to update checklist item: {}", e);
+                    set_error.set(Some(format!("Failed to update checklist item: {}", e)));
+                }
+            }
+        });
+    };
+
+    // Swap with the neighbor above (-1) or below (1)
+    let handle_move_item = move |item_id: Uuid, offset: isize| {
+        let items = checklist.get();
+        let Some(index) = items.iter().position(|item| item.id == item_id) else { return };
+        let target = index as isize + offset;
+        if target < 0 || target as usize >= items.len() {
+            return;
+        }
+        let id_at = |i: isize| usize::try_from(i).ok().and_then(|i| items.get(i)).map(|item| item.id);
+        let request = if offset < 0 {
+            MoveChecklistItemRequest { before_id: id_at(target - 1), after_id: id_at(target) }
+        } else {
+            MoveChecklistItemRequest { before_id: id_at(target), after_id: id_at(target + 1) }
+        };
+        spawn_local(async move {
+            match checklists::move_checklist_item(item_id, request).await {
+                Ok(_) => load_checklist(),
+                Err(e) => {
+                    log::error!("Failed to move checklist item: {}", e);
+                    set_error.set(Some(format!("Failed to move checklist item: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_delete_item = move |item_id: Uuid| {
+        spawn_local(async move {
+            match checklists::delete_checklist_item(item_id).await {
+                Ok(()) => load_checklist(),
+                Err(e) => {
+                    log::error!("Failed to delete checklist item: {}", e);
+                    set_error.set(Some(format!("Failed to delete checklist item: {}", e)));
+                }
+            }
+        });
+    };
+
+    let actor_name = move |id: Option<Uuid>| match id {
+        Some(id) => names.with(|n| n.get(&id).cloned()).unwrap_or_else(|| "Someone".to_string()),
+        None => "A removed user".to_string(),
+    };
+
+    view! {
+        <div class="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8">
+            <A href=move || format!("/organizations/{}/projects/{}", org_id(), project_id()) class="text-sm text-blue-600 hover:text-blue-500 mb-2 inline-block">
+                "← Back to board"
+            </A>
+
+            {move || error.get().map(|err| view! {
+                <div class="bg-red-50 border border-red-200 rounded-lg p-4 mb-6">
+                    <p class="text-red-800">{err}</p>
+                </div>
+            })}
+
+            {move || {
+                if loading.get() {
+                    view! {
+                        <div class="flex justify-center items-center py-12">
+                            <div class="animate-spin rounded-full h-12 w-12 border-b-2 border-blue-600"></div>
+                        </div>
+                    }.into_view()
+                } else if let Some(t) = task.get() {
+                    view! {
+                        <div class="bg-white shadow rounded-lg p-6 mb-6">
+                            <p class="text-sm text-gray-500">{t.key.clone()}</p>
+                            <h1 class="text-2xl font-bold text-gray-900">{t.title.clone()}</h1>
+                            <div class="mt-3 flex flex-wrap gap-2 text-xs">
+                                <span class="px-2 py-1 rounded-full bg-gray-100 text-gray-800">{t.status.clone()}</span>
+                                <span class="px-2 py-1 rounded-full bg-gray-100 text-gray-800">{t.priority.clone()}</span>
+                                {t.due_date.map(|d| view! {
+                                    <span class="px-2 py-1 rounded-full bg-gray-100 text-gray-800">
+                                        {format!("Due {}", d.format("%b %d, %Y"))}
+                                    </span>
+                                })}
+                            </div>
+                            <div class="mt-3 flex flex-wrap items-center gap-2 text-sm">
+                                <span class="text-gray-500">"Assignees"</span>
+                                {t.assignees.iter().map(|&assignee| {
+                                    let remaining: Vec<Uuid> = t.assignees.iter().copied().filter(|&id| id != assignee).collect();
+                                    view! {
+                                        <span class="px-2 py-1 rounded-full bg-blue-50 text-blue-800 text-xs">
+                                            {actor_name(Some(assignee))}
+                                            <button
+                                                on:click=move |_| set_assignees(remaining.clone())
+                                                class="ml-1 text-blue-400 hover:text-red-600"
+                                            >
+                                                "×"
+                                            </button>
+                                        </span>
+                                    }
+                                }).collect_view()}
+                                {
+                                    let current = t.assignees.clone();
+                                    let options: Vec<(Uuid, String)> = names
+                                        .get()
+                                        .into_iter()
+                                        .filter(|(id, _)| !current.contains(id))
+                                        .collect();
+                                    view! {
+                                        <select
+                                            on:change=move |ev| {
+                                                if let Ok(id) = event_target_value(&ev).parse::<Uuid>() {
+                                                    let mut ids = current.clone();
+                                                    ids.push(id);
+                                                    set_assignees(ids);
+                                                }
+                                            }
+                                            class="px-2 py-1 border border-gray-200 rounded-md text-xs text-gray-600"
+                                        >
+                                            <option value="" selected=true>"Add assignee…"</option>
+                                            {options
+                                                .into_iter()
+                                                .map(|(id, name)| view! { <option value=id.to_string()>{name}</option> })
+                                                .collect_view()}
+                                        </select>
+                                    }
+                                }
+                                <button
+                                    on:click=handle_toggle_watch
+                                    class="ml-auto px-3 py-1 text-xs text-gray-600 border border-gray-200 rounded-md hover:bg-gray-50"
+                                >
+                                    {move || format!(
+                                        "{} · {} watching",
+                                        if is_watching() { "Unwatch" } else { "Watch" },
+                                        watchers.with(Vec::len)
+                                    )}
+                                </button>
+                            </div>
+                            {t.description.clone().map(|desc| view! {
+                                <p class="mt-4 text-gray-700 whitespace-pre-wrap">{desc}</p>
+                            })}
+                            <div class="mt-4 pt-4 border-t border-gray-100 flex items-center space-x-2">
+                                <select
+                                    prop:value=target_project
+                                    on:change=move |ev| set_target_project.set(event_target_value(&ev))
+                                    class="px-3 py-1 border border-gray-300 rounded-md text-sm"
+                                >
+                                    <option value="">"Move to project…"</option>
+                                    {projects_list
+                                        .get()
+                                        .into_iter()
+                                        .filter(|p| p.id != t.project_id)
+                                        .map(|p| view! { <option value=p.id.to_string()>{p.name}</option> })
+                                        .collect_view()}
+                                </select>
+                                <button
+                                    on:click=handle_transfer.clone()
+                                    disabled=move || target_project.get().is_empty()
+                                    class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50 disabled:opacity-50"
+                                >
+                                    "Move"
+                                </button>
+                            </div>
+                            <div class="mt-4 pt-4 border-t border-gray-100">
+                                <h3 class="text-sm font-medium text-gray-900">"Repeat"</h3>
+                                <p class="text-xs text-gray-500 mb-2">
+                                    {move || match recurrence.get() {
+                                        Some(r) => match r.next_due_at {
+                                            Some(next) => format!("Next occurrence due {}", next.format("%b %d, %Y %H:%M UTC")),
+                                            None => "The series has ended".to_string(),
+                                        },
+                                        None => "e.g. FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;COUNT=10. Starts at the due date.".to_string(),
+                                    }}
+                                </p>
+                                <div class="flex items-center space-x-2">
+                                    <input
+                                        type="text"
+                                        prop:value=rule_input
+                                        on:input=move |ev| set_rule_input.set(event_target_value(&ev))
+                                        placeholder="FREQ=DAILY"
+                                        class="flex-1 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                                    />
+                                    <input
+                                        type="text"
+                                        prop:value=timezone_input
+                                        on:input=move |ev| set_timezone_input.set(event_target_value(&ev))
+                                        placeholder="Europe/Berlin"
+                                        class="w-40 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                                    />
+                                    <button
+                                        on:click=handle_save_recurrence
+                                        disabled=move || rule_input.get().trim().is_empty()
+                                        class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50 disabled:opacity-50"
+                                    >
+                                        "Save"
+                                    </button>
+                                    <Show when=move || recurrence.get().is_some()>
+                                        <button
+                                            on:click=handle_stop_recurrence
+                                            class="px-3 py-1 text-sm text-red-600 border border-red-200 rounded-md hover:bg-red-50"
+                                        >
+                                            "Stop"
+                                        </button>
+                                    </Show>
+                                </div>
+                            </div>
+                        </div>
+                    }.into_view()
+                } else {
+                    view! { <div></div> }.into_view()
+                }
+            }}
+
+            <div class="bg-white shadow rounded-lg mb-6">
+                <div class="px-6 py-4 border-b border-gray-200 flex items-center justify-between">
+                    <h2 class="text-lg font-semibold text-gray-900">"Checklist"</h2>
+                    <span class="text-sm text-gray-600">
+                        {move || checklist.with(|items| {
+                            let done = items.iter().filter(|item| item.checked).count();
+                            if items.is_empty() { String::new() } else { format!("{}/{} done", done, items.len()) }
+                        })}
+                    </span>
+                </div>
+                <ul class="divide-y divide-gray-100">
+                    <For
+                        each=move || checklist.get()
+                        key=|item| (item.id, item.updated_at)
+                        children=move |item: ChecklistItem| {
+                            let item_id = item.id;
+                            let checked = item.checked;
+                            let content = item.content.clone();
+                            let assignee = item.assigned_to.map(|id| actor_name(Some(id)));
+                            view! {
+                                <li class="px-6 py-2 text-sm flex items-center space-x-2">
+                                    <input
+                                        type="checkbox"
+                                        prop:checked=checked
+                                        on:change=move |_| update_item(item_id, UpdateChecklistItemRequest {
+                                            checked: Some(!checked),
+                                            ..Default::default()
+                                        })
+                                    />
+                                    {move || if editing_item.get() == Some(item_id) {
+                                        view! {
+                                            <input
+                                                type="text"
+                                                prop:value=edit_input
+                                                on:input=move |ev| set_edit_input.set(event_target_value(&ev))
+                                                on:keydown=move |ev| match ev.key().as_str() {
+                                                    "Enter" => update_item(item_id, UpdateChecklistItemRequest {
+                                                        content: Some(edit_input.get().trim().to_string()),
+                                                        ..Default::default()
+                                                    }),
+                                                    "Escape" => set_editing_item.set(None),
+                                                    _ => {}
+                                                }
+                                                class="flex-1 px-2 py-1 border border-gray-300 rounded-md text-sm"
+                                            />
+                                        }.into_view()
+                                    } else {
+                                        let content = content.clone();
+                                        let text = content.clone();
+                                        view! {
+                                            <span
+                                                on:click=move |_| {
+                                                    set_edit_input.set(content.clone());
+                                                    set_editing_item.set(Some(item_id));
+                                                }
+                                                class=if checked { "flex-1 cursor-text text-gray-400 line-through" } else { "flex-1 cursor-text text-gray-900" }
+                                            >
+                                                {text}
+                                            </span>
+                                        }.into_view()
+                                    }}
+                                    <select
+                                        on:change=move |ev| update_item(item_id, UpdateChecklistItemRequest {
+                                            assigned_to: Some(event_target_value(&ev).parse::<Uuid>().ok()),
+                                            ..Default::default()
+                                        })
+                                        class="px-2 py-1 border border-gray-200 rounded-md text-xs text-gray-600"
+                                    >
+                                        <option value="" selected=assignee.is_none()>"Unassigned"</option>
+                                        {names
+                                            .get()
+                                            .into_iter()
+                                            .map(|(id, name)| view! {
+                                                <option value=id.to_string() selected=item.assigned_to == Some(id)>{name}</option>
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                    <button on:click=move |_| handle_move_item(item_id, -1) class="text-xs text-gray-400 hover:text-gray-700">"↑"</button>
+                                    <button on:click=move |_| handle_move_item(item_id, 1) class="text-xs text-gray-400 hover:text-gray-700">"↓"</button>
+                                    <button
+                                        on:click=move |_| handle_delete_item(item_id)
+                                        class="text-xs text-gray-400 hover:text-red-600"
+                                    >
+                                        "Delete"
+                                    </button>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+                <form on:submit=handle_add_item class="px-6 py-4 border-t border-gray-200 flex items-center space-x-2">
+                    <input
+                        type="text"
+                        prop:value=new_item
+                        on:input=move |ev| set_new_item.set(event_target_value(&ev))
+                        placeholder="Add an item"
+                        class="flex-1 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                    <button
+                        type="submit"
+                        class="px-3 py-1 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700"
+                    >
+                        "Add"
+                    </button>
+                </form>
+            </div>
+
+            <div class="bg-white shadow rounded-lg mb-6">
+                <div class="px-6 py-4 border-b border-gray-200 flex items-center justify-between">
+                    <h2 class="text-lg font-semibold text-gray-900">"Attachments"</h2>
+                    <span class="text-sm text-gray-600">
+                        {move || attachment_list.with(|list| if list.is_empty() { String::new() } else { list.len().to_string() })}
+                    </span>
+                </div>
+                <ul class="divide-y divide-gray-100">
+                    <For
+                        each=move || attachment_list.get()
+                        key=|attachment| (attachment.id, attachment.thumbnail_url.is_some(), attachment.scan_status.clone())
+                        children=move |attachment: Attachment| {
+                            let attachment_id = attachment.id;
+                            let (thumbnail, set_thumbnail) = create_signal::<Option<String>>(None);
+                            if attachment.thumbnail_url.is_some() {
+                                let attachment = attachment.clone();
+                                spawn_local(async move {
+                                    set_thumbnail.set(attachments::fetch_thumbnail(&attachment).await);
+                                });
+                            }
+                            on_cleanup(move || {
+                                if let Some(url) = thumbnail.try_get_untracked().flatten() {
+                                    let _ = web_sys::Url::revoke_object_url(&url);
+                                }
+                            });
+                            let preview = attachment.clone();
+                            let uploaded_by = actor_name(Some(attachment.user_id));
+                            let details = format!(
+                                "{} · {} · {}",
+                                format_size(attachment.file_size),
+                                uploaded_by,
+                                attachment.created_at.format("%b %d, %Y")
+                            );
+                            let filename = attachment.filename.clone();
+                            let clean = attachment.scan_status == "clean";
+                            let scan_note = match attachment.scan_status.as_str() {
+                                "clean" => None,
+                                "infected" => Some(("Blocked: malware found", "text-xs text-red-600")),
+                                _ => Some(("Scanning…", "text-xs text-gray-500 italic")),
+                            };
+                            view! {
+                                <li class="px-6 py-2 text-sm flex items-center space-x-2">
+                                    {move || thumbnail.get().map(|src| {
+                                        let preview = preview.clone();
+                                        view! {
+                                            <img
+                                                src=src
+                                                alt=""
+                                                on:click=move |_| handle_download(preview.clone())
+                                                class="h-12 w-12 object-cover rounded border border-gray-200 cursor-pointer"
+                                            />
+                                        }
+                                    })}
+                                    <button
+                                        on:click=move |_| handle_download(attachment.clone())
+                                        disabled=!clean
+                                        class="flex-1 text-left text-blue-600 hover:text-blue-500 truncate disabled:text-gray-500 disabled:cursor-not-allowed"
+                                    >
+                                        {filename}
+                                    </button>
+                                    {scan_note.map(|(note, class)| view! { <span class=class>{note}</span> })}
+                                    <span class="text-xs text-gray-500">{details}</span>
+                                    <button
+                                        on:click=move |_| handle_delete_attachment(attachment_id)
+                                        class="text-xs text-gray-400 hover:text-red-600"
+                                    >
+                                        "Delete"
+                                    </button>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+                <div
+                    on:dragover=move |ev| {
+                        ev.prevent_default();
+                        set_drag_over.set(true);
+                    }
+                    on:dragleave=move |_| set_drag_over.set(false)
+                    on:drop=move |ev| {
+                        ev.prevent_default();
+                        set_drag_over.set(false);
+                        upload_files(attachments::files_from_list(ev.data_transfer().and_then(|data| data.files())));
+                    }
+                    class=move || if drag_over.get() {
+                        "m-4 p-6 border-2 border-dashed border-blue-400 bg-blue-50 rounded-lg text-center text-sm text-gray-600"
+                    } else {
+                        "m-4 p-6 border-2 border-dashed border-gray-300 rounded-lg text-center text-sm text-gray-600"
+                    }
+                >
+                    {move || if uploading.get() {
+                        view! { <span>"Uploading…"</span> }.into_view()
+                    } else {
+                        view! {
+                            <label class="cursor-pointer">
+                                "Drop files here or "
+                                <span class="text-blue-600 hover:text-blue-500 underline">"browse"</span>
+                                <input
+                                    type="file"
+                                    multiple=true
+                                    on:change=move |ev| {
+                                        let input = event_target::<web_sys::HtmlInputElement>(&ev);
+                                        upload_files(attachments::files_from_list(input.files()));
+                                        input.set_value("");
+                                    }
+                                    class="hidden"
+                                />
+                            </label>
+                        }.into_view()
+                    }}
+                    <label class="mt-2 flex items-center justify-center space-x-1 text-xs text-gray-500">
+                        <input
+                            type="checkbox"
+                            prop:checked=strip_metadata
+                            on:change=move |ev| set_strip_metadata.set(event_target_checked(&ev))
+                        />
+                        <span>"Remove photo metadata such as location"</span>
+                    </label>
+                </div>
+            </div>
+
+            <div class="bg-white shadow rounded-lg mb-6">
+                <div class="px-6 py-4 border-b border-gray-200 flex items-center justify-between">
+                    <h2 class="text-lg font-semibold text-gray-900">"Time"</h2>
+                    <button
+                        on:click=handle_timer
+                        class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50"
+                    >
+                        {move || if timer_on_this_task() { "Stop timer" } else { "Start timer" }}
+                    </button>
+                </div>
+                <div class="px-6 py-4 border-b border-gray-100">
+                    <p class="text-sm text-gray-600 mb-3">
+                        {move || task.get().map(|t| {
+                            let estimate = t
+                                .original_estimate_seconds
+                                .map(|s| format!(" of {} estimated", format_duration(i64::from(s))))
+                                .unwrap_or_default();
+                            let remaining = t
+                                .remaining_estimate_seconds
+                                .map(|s| format!(", {} remaining", format_duration(i64::from(s))))
+                                .unwrap_or_default();
+                            format!("{} logged{}{}", format_duration(t.time_spent_seconds), estimate, remaining)
+                        })}
+                    </p>
+                    <div class="flex items-center space-x-2">
+                        <input
+                            type="text"
+                            prop:value=estimate_input
+                            on:input=move |ev| set_estimate_input.set(event_target_value(&ev))
+                            placeholder="Estimate (h)"
+                            class="w-32 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                        />
+                        <input
+                            type="text"
+                            prop:value=remaining_input
+                            on:input=move |ev| set_remaining_input.set(event_target_value(&ev))
+                            placeholder="Remaining (h)"
+                            class="w-32 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                        />
+                        <button
+                            on:click=handle_save_estimates
+                            class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50"
+                        >
+                            "Save estimates"
+                        </button>
+                    </div>
+                </div>
+                <ul class="divide-y divide-gray-100">
+                    <For
+                        each=move || time_logs.get()
+                        key=|log| (log.id, log.ended_at)
+                        children=move |log: TimeLog| {
+                            let log_id = log.id;
+                            let duration = match log.duration_seconds {
+                                Some(seconds) => format_duration(i64::from(seconds)),
+                                None => "running".to_string(),
+                            };
+                            view! {
+                                <li class="px-6 py-2 text-sm flex items-center justify-between">
+                                    <span>
+                                        <span class="font-medium text-gray-900">{actor_name(Some(log.user_id))}</span>
+                                        <span class="ml-2 text-gray-600">{duration}</span>
+                                        <span class="ml-2 text-xs text-gray-400">
+                                            {log.started_at.format("%b %d, %Y %H:%M").to_string()}
+                                        </span>
+                                        {log.description.map(|note| view! {
+                                            <span class="ml-2 text-gray-600">{note}</span>
+                                        })}
+                                    </span>
+                                    <button
+                                        on:click=move |_| handle_delete_log(log_id)
+                                        class="text-xs text-gray-400 hover:text-red-600"
+                                    >
+                                        "Delete"
+                                    </button>
+                                </li>
+                            }
+                        }
+                    />
+                </ul>
+                <form on:submit=handle_log_time class="px-6 py-4 border-t border-gray-200 flex items-center space-x-2">
+                    <input
+                        type="text"
+                        prop:value=log_hours
+                        on:input=move |ev| set_log_hours.set(event_target_value(&ev))
+                        placeholder="Hours"
+                        class="w-24 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                    <input
+                        type="text"
+                        prop:value=log_note
+                        on:input=move |ev| set_log_note.set(event_target_value(&ev))
+                        placeholder="What did you work on?"
+                        class="flex-1 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                    <button
+                        type="submit"
+                        class="px-3 py-1 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700"
+                    >
+                        "Log time"
+                    </button>
+                </form>
+            </div>
+
+            <div class="bg-white shadow rounded-lg">
+                <div class="px-6 py-4 border-b border-gray-200">
+                    <h2 class="text-lg font-semibold text-gray-900">"Activity"</h2>
+                </div>
+                <ul class="divide-y divide-gray-100">
+                    <For
+                        each=move || activity.get()
+                        key=|item| item.key()
+                        children=move |item| {
+                            let at = item.at().format("%b %d, %Y %H:%M").to_string();
+                            match item {
+                                Activity::Change(entry) => {
+                                    let text = names.with(|n| format!(
+                                        "changed {} from {} to {}",
+                                        field_label(&entry.field),
+                                        display_value(&entry.old_value, n),
+                                        display_value(&entry.new_value, n),
+                                    ));
+                                    view! {
+                                        <li class="px-6 py-3 text-sm text-gray-600">
+                                            <span class="font-medium text-gray-900">{actor_name(entry.actor_id)}</span>
+                                            " " {text}
+                                            <span class="ml-2 text-xs text-gray-400">{at}</span>
+                                        </li>
+                                    }
+                                }
+                                Activity::Comment(comment) => {
+                                    let comment_id = comment.id;
+                                    view! {
+                                        <li class="px-6 py-3">
+                                            <p class="text-sm">
+                                                <span class="font-medium text-gray-900">{actor_name(Some(comment.user_id))}</span>
+                                                <span class="ml-2 text-xs text-gray-400">{at}</span>
+                                                <button
+                                                    on:click=move |_| handle_delete_comment(comment_id)
+                                                    class="ml-2 text-xs text-gray-400 hover:text-red-600"
+                                                >
+                                                    "Delete"
+                                                </button>
+                                            </p>
+                                            <p class="mt-1 text-gray-700 whitespace-pre-wrap">{comment.content}</p>
+                                        </li>
+                                    }
+                                }
+                            }
+                        }
+                    />
+                </ul>
+                <form on:submit=handle_comment class="px-6 py-4 border-t border-gray-200">
+                    <textarea
+                        prop:value=comment_input
+                        on:input=move |ev| set_comment_input.set(event_target_value(&ev))
+                        rows="3"
+                        placeholder="Add a comment"
+                        class="w-full px-3 py-2 border border-gray-300 rounded-md text-sm"
+                    ></textarea>
+                    <div class="mt-2 flex justify-end">
+                        <button
+                            type="submit"
+                            disabled=posting
+                            class="px-4 py-2 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700 disabled:opacity-50"
+                        >
+                            "Comment"
+                        </button>
+                    </div>
+                </form>
+            </div>
+        </div>
+    }
+}
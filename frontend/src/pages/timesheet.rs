@@ -0,0 +1,190 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use leptos::*;
+
+use crate::services::time_tracking::{self, format_duration, Timesheet};
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// The signed-in user's logged time for a range of days, with CSV export
+#[component]
+pub fn TimesheetPage() -> impl IntoView {
+    // Default to the current week, Monday through today
+    let today = Local::now().date_naive();
+    let monday = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+
+    let (from, set_from) = create_signal(monday.to_string());
+    let (to, set_to) = create_signal(today.to_string());
+    let (timezone, set_timezone) = create_signal("UTC".to_string());
+    let (timesheet, set_timesheet) = create_signal::<Option<Timesheet>>(None);
+    let (csv_url, set_csv_url) = create_signal::<Option<String>>(None);
+    let (loading, set_loading) = create_signal(false);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+
+    let range = move || -> Option<(NaiveDate, NaiveDate)> {
+        Some((parse_date(&from.get())?, parse_date(&to.get())?))
+    };
+
+    let load_timesheet = move || {
+        let Some((from, to)) = range() else {
+            set_error.set(Some("Pick a start and end date".to_string()));
+            return;
+        };
+        let tz = timezone.get();
+        set_csv_url.set(None);
+        spawn_local(async move {
+            set_loading.set(true);
+            match time_tracking::get_timesheet(from, to, &tz).await {
+                Ok(sheet) => {
+                    set_timesheet.set(Some(sheet));
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Failed to load timesheet: {}", e);
+                    set_error.set(Some(format!("Failed to load timesheet: {}", e)));
+                }
+            }
+            set_loading.set(false);
+        });
+    };
+
+    create_effect(move |_| load_timesheet());
+
+    // The export needs the auth header, so it is fetched and offered as a data URL
+    let handle_export = move |_| {
+        let Some((from, to)) = range() else { return };
+        let tz = timezone.get();
+        spawn_local(async move {
+            match time_tracking::get_timesheet_csv(from, to, &tz).await {
+                Ok(csv) => set_csv_url.set(Some(format!(
+                    "data:text/csv;charset=utf-8,{}",
+                    js_sys::encode_uri_component(&csv)
+                ))),
+                Err(e) => {
+                    log::error!("Failed to export timesheet: {}", e);
+                    set_error.set(Some(format!("Failed to export timesheet: {}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="max-w-5xl mx-auto px-4 sm:px-6 lg:px-8 py-8">
+            <h1 class="text-3xl font-bold text-gray-900">"Timesheet"</h1>
+            <p class="mt-2 mb-8 text-gray-600">"Time you logged, by day"</p>
+
+            <div class="bg-white shadow rounded-lg p-4 mb-6 flex flex-wrap items-end gap-3">
+                <label class="text-sm text-gray-700">
+                    "From"
+                    <input
+                        type="date"
+                        prop:value=from
+                        on:change=move |ev| set_from.set(event_target_value(&ev))
+                        class="block mt-1 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                </label>
+                <label class="text-sm text-gray-700">
+                    "To"
+                    <input
+                        type="date"
+                        prop:value=to
+                        on:change=move |ev| set_to.set(event_target_value(&ev))
+                        class="block mt-1 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                </label>
+                <label class="text-sm text-gray-700">
+                    "Timezone"
+                    <input
+                        type="text"
+                        prop:value=timezone
+                        on:input=move |ev| set_timezone.set(event_target_value(&ev))
+                        class="block mt-1 w-40 px-3 py-1 border border-gray-300 rounded-md text-sm"
+                    />
+                </label>
+                <button
+                    on:click=move |_| load_timesheet()
+                    class="px-4 py-2 bg-blue-600 text-white text-sm rounded-md hover:bg-blue-700"
+                >
+                    "Show"
+                </button>
+                <button
+                    on:click=handle_export
+                    class="px-4 py-2 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50"
+                >
+                    "Export CSV"
+                </button>
+                {move || csv_url.get().map(|url| view! {
+                    <a
+                        href=url
+                        download=move || format!("timesheet-{}-{}.csv", from.get(), to.get())
+                        class="text-sm text-blue-600 hover:text-blue-500 underline"
+                    >
+                        "Download CSV"
+                    </a>
+                })}
+            </div>
+
+            {move || error.get().map(|err| view! {
+                <div class="bg-red-50 border border-red-200 rounded-lg p-4 mb-6">
+                    <p class="text-red-800">{err}</p>
+                </div>
+            })}
+
+            {move || {
+                if loading.get() {
+                    view! {
+                        <div class="flex justify-center items-center py-12">
+                            <div class="animate-spin rounded-full h-12 w-12 border-b-2 border-blue-600"></div>
+                        </div>
+                    }.into_view()
+                } else if let Some(sheet) = timesheet.get() {
+                    let total = format_duration(sheet.total_seconds);
+                    view! {
+                        <div class="bg-white shadow rounded-lg">
+                            <div class="px-6 py-4 border-b border-gray-200 flex justify-between">
+                                <h2 class="text-lg font-semibold text-gray-900">"Entries"</h2>
+                                <span class="text-sm text-gray-600">{format!("Total {}", total)}</span>
+                            </div>
+                            {if sheet.entries.is_empty() {
+                                view! { <p class="px-6 py-4 text-sm text-gray-500">"No time logged in this range"</p> }.into_view()
+                            } else {
+                                view! {
+                                    <table class="min-w-full divide-y divide-gray-200 text-sm">
+                                        <thead class="bg-gray-50 text-left text-xs text-gray-500 uppercase">
+                                            <tr>
+                                                <th class="px-6 py-2">"Started"</th>
+                                                <th class="px-6 py-2">"Task"</th>
+                                                <th class="px-6 py-2">"Project"</th>
+                                                <th class="px-6 py-2">"Note"</th>
+                                                <th class="px-6 py-2 text-right">"Time"</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody class="divide-y divide-gray-100">
+                                            {sheet.entries.into_iter().map(|entry| view! {
+                                                <tr>
+                                                    <td class="px-6 py-2 text-gray-600">
+                                                        {entry.started_at.with_timezone(&Local).format("%b %d %H:%M").to_string()}
+                                                    </td>
+                                                    <td class="px-6 py-2">
+                                                        <span class="text-xs text-gray-400 mr-1">{entry.task_key}</span>
+                                                        {entry.task_title}
+                                                    </td>
+                                                    <td class="px-6 py-2 text-gray-600">{entry.project_name}</td>
+                                                    <td class="px-6 py-2 text-gray-600">{entry.description.unwrap_or_default()}</td>
+                                                    <td class="px-6 py-2 text-right">{format_duration(i64::from(entry.duration_seconds))}</td>
+                                                </tr>
+                                            }).collect_view()}
+                                        </tbody>
+                                    </table>
+                                }.into_view()
+                            }}
+                        </div>
+                    }.into_view()
+                } else {
+                    view! { <div></div> }.into_view()
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,165 @@
+use leptos::*;
+use leptos_router::*;
+use uuid::Uuid;
+
+use crate::services::tasks::{self, Trash as TrashContents};
+
+#[component]
+pub fn Trash() -> impl IntoView {
+    let params = use_params_map();
+    let org_id = move || params.with(|p| p.get("org_id").cloned().unwrap_or_default());
+    let project_id = move || params.with(|p| p.get("project_id").cloned().unwrap_or_default());
+
+    let (trash, set_trash) = create_signal(TrashContents::default());
+    let (loading, set_loading) = create_signal(true);
+    let (error, set_error) = create_signal::<Option<String>>(None);
+
+    let load_trash = move || {
+        let id = project_id();
+        spawn_local(async move {
+            set_loading.set(true);
+            match tasks::get_project_trash(&id).await {
+                Ok(result) => {
+                    set_trash.set(result);
+                    set_error.set(None);
+                }
+                Err(e) => {
+                    log::error!("Failed to load trash: {}", e);
+                    set_error.set(Some(format!("Failed to load trash: {}", e)));
+                }
+            }
+            set_loading.set(false);
+        });
+    };
+
+    create_effect(move |_| load_trash());
+
+    let handle_restore_task = move |task_id: Uuid| {
+        spawn_local(async move {
+            match tasks::restore_task(task_id).await {
+                Ok(_) => load_trash(),
+                Err(e) => {
+                    log::error!("Failed to restore task: {}", e);
+                    set_error.set(Some(format!("Failed to restore task: {}", e)));
+                }
+            }
+        });
+    };
+
+    let handle_restore_comment = move |comment_id: Uuid| {
+        spawn_local(async move {
+            match tasks::restore_comment(comment_id).await {
+                Ok(_) => load_trash(),
+                Err(e) => {
+                    log::error!("Failed to restore comment: {}", e);
+                    set_error.set(Some(format!("Failed to restore comment: {}", e)));
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-8">
+            <A href=move || format!("/organizations/{}/projects/{}", org_id(), project_id()) class="text-sm text-blue-600 hover:text-blue-500 mb-2 inline-block">
+                "← Back to board"
+            </A>
+            <h1 class="text-3xl font-bold text-gray-900">"Trash"</h1>
+            <p class="mt-2 mb-8 text-gray-600">"Deleted tasks and comments are removed for good after their purge date"</p>
+
+            {move || error.get().map(|err| view! {
+                <div class="bg-red-50 border border-red-200 rounded-lg p-4 mb-6">
+                    <p class="text-red-800">{err}</p>
+                </div>
+            })}
+
+            {move || {
+                if loading.get() {
+                    view! {
+                        <div class="flex justify-center items-center py-12">
+                            <div class="animate-spin rounded-full h-12 w-12 border-b-2 border-blue-600"></div>
+                        </div>
+                    }.into_view()
+                } else {
+                    let contents = trash.get();
+                    view! {
+                        <div class="space-y-6">
+                            <div class="bg-white shadow rounded-lg">
+                                <div class="px-6 py-4 border-b border-gray-200">
+                                    <h2 class="text-lg font-semibold text-gray-900">"Tasks"</h2>
+                                </div>
+                                {if contents.tasks.is_empty() {
+                                    view! { <p class="px-6 py-4 text-sm text-gray-400">"Nothing here"</p> }.into_view()
+                                } else {
+                                    view! {
+                                        <ul class="divide-y divide-gray-100">
+                                            {contents.tasks.into_iter().map(|task| {
+                                                let task_id = task.id;
+                                                view! {
+                                                    <li class="px-6 py-3 flex items-center justify-between">
+                                                        <div>
+                                                            <p class="text-sm font-medium text-gray-900">{task.title}</p>
+                                                            <p class="text-xs text-gray-500">
+                                                                {format!(
+                                                                    "Deleted {} · purged {}",
+                                                                    task.deleted_at.format("%b %d, %Y"),
+                                                                    task.purge_at.format("%b %d, %Y"),
+                                                                )}
+                                                            </p>
+                                                        </div>
+                                                        <button
+                                                            on:click=move |_| handle_restore_task(task_id)
+                                                            class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50"
+                                                        >
+                                                            "Restore"
+                                                        </button>
+                                                    </li>
+                                                }
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_view()
+                                }}
+                            </div>
+
+                            <div class="bg-white shadow rounded-lg">
+                                <div class="px-6 py-4 border-b border-gray-200">
+                                    <h2 class="text-lg font-semibold text-gray-900">"Comments"</h2>
+                                </div>
+                                {if contents.comments.is_empty() {
+                                    view! { <p class="px-6 py-4 text-sm text-gray-400">"Nothing here"</p> }.into_view()
+                                } else {
+                                    view! {
+                                        <ul class="divide-y divide-gray-100">
+                                            {contents.comments.into_iter().map(|comment| {
+                                                let comment_id = comment.id;
+                                                view! {
+                                                    <li class="px-6 py-3 flex items-center justify-between">
+                                                        <div>
+                                                            <p class="text-sm text-gray-900">{comment.content}</p>
+                                                            <p class="text-xs text-gray-500">
+                                                                {format!(
+                                                                    "On {} · purged {}",
+                                                                    comment.task_title,
+                                                                    comment.purge_at.format("%b %d, %Y"),
+                                                                )}
+                                                            </p>
+                                                        </div>
+                                                        <button
+                                                            on:click=move |_| handle_restore_comment(comment_id)
+                                                            class="px-3 py-1 text-sm text-blue-600 border border-blue-200 rounded-md hover:bg-blue-50"
+                                                        >
+                                                            "Restore"
+                                                        </button>
+                                                    </li>
+                                                }
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_view()
+                                }}
+                            </div>
+                        </div>
+                    }.into_view()
+                }
+            }}
+        </div>
+    }
+}
@@ -35,6 +35,13 @@ impl From<serde_json::Error> for ApiError {
     }
 }
 
+/// A page of results from a paginated listing endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
 /// Get JWT token from localStorage
 pub fn get_token() -> Option<String> {
     window()?
@@ -89,6 +96,105 @@ pub async fn get<T: for<'de> Deserialize<'de>>(endpoint: &str) -> Result<T, ApiE
     }
 }
 
+/// Make a GET request for a plain-text body such as a CSV export
+pub async fn get_text(endpoint: &str) -> Result<String, ApiError> {
+    let url = format!("{}{}", API_BASE_URL, endpoint);
+    let mut request = Request::get(&url);
+
+    // Add Authorization header if token exists
+    if let Some(token) = get_token() {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+
+    let response = request.send().await?;
+
+    if response.ok() {
+        Ok(response.text().await?)
+    } else {
+        let error_text = response.text().await.unwrap_or_else(|_| {
+            format!("HTTP error: {}", response.status())
+        });
+        Err(ApiError {
+            message: error_text,
+        })
+    }
+}
+
+/// Make a GET request for a binary body such as an attachment download
+pub async fn get_bytes(endpoint: &str) -> Result<Vec<u8>, ApiError> {
+    let url = format!("{}{}", API_BASE_URL, endpoint);
+    let mut request = Request::get(&url);
+
+    // Add Authorization header if token exists
+    if let Some(token) = get_token() {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+
+    let response = request.send().await?;
+
+    if response.ok() {
+        Ok(response.binary().await?)
+    } else {
+        let error_text = response.text().await.unwrap_or_else(|_| {
+            format!("HTTP error: {}", response.status())
+        });
+        Err(ApiError {
+            message: error_text,
+        })
+    }
+}
+
+/// Make a multipart POST request. The browser sets the Content-Type with its boundary.
+pub async fn post_form<R: for<'de> Deserialize<'de>>(
+    endpoint: &str,
+    form: web_sys::FormData,
+) -> Result<R, ApiError> {
+    let url = format!("{}{}", API_BASE_URL, endpoint);
+    let mut request = Request::post(&url);
+
+    // Add Authorization header if token exists
+    if let Some(token) = get_token() {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+
+    let response = request.body(form)?.send().await?;
+
+    if response.ok() {
+        let data = response.json::<R>().await?;
+        Ok(data)
+    } else {
+        let error_text = response.text().await.unwrap_or_else(|_| {
+            format!("HTTP error: {}", response.status())
+        });
+        log::error!("API error for {}: {}", endpoint, error_text);
+        Err(ApiError {
+            message: error_text,
+        })
+    }
+}
+
+/// Fetch every page of a paginated listing endpoint.
+///
+/// `endpoint` may already carry query parameters.
+pub async fn get_all_pages<T: for<'de> Deserialize<'de>>(endpoint: &str) -> Result<Vec<T>, ApiError> {
+    let separator = if endpoint.contains('?') { '&' } else { '?' };
+    let mut items = Vec::new();
+    let mut cursor: Option<String> = None;
+
+    loop {
+        let url = match cursor {
+            Some(ref c) => format!("{}{}limit=200&cursor={}", endpoint, separator, c),
+            None => format!("{}{}limit=200", endpoint, separator),
+        };
+        let page = get::<Page<T>>(&url).await?;
+        items.extend(page.items);
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return Ok(items),
+        }
+    }
+}
+
 /// Make a POST request
 pub async fn post<T: Serialize, R: for<'de> Deserialize<'de>>(
     endpoint: &str,
@@ -155,6 +261,79 @@ pub async fn put<T: Serialize, R: for<'de> Deserialize<'de>>(
     }
 }
 
+/// Make a PATCH request; absent fields are left unchanged by the server
+pub async fn patch<T: Serialize, R: for<'de> Deserialize<'de>>(
+    endpoint: &str,
+    body: &T,
+) -> Result<R, ApiError> {
+    let url = format!("{}{}", API_BASE_URL, endpoint);
+    let body_json = serde_json::to_string(body)?;
+    
+    let mut request = Request::patch(&url)
+        .header("Content-Type", "application/json");
+
+    // Add Authorization header if token exists
+    if let Some(token) = get_token() {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+
+    let response = request.body(body_json)?.send().await?;
+    
+    if response.ok() {
+        let data = response.json::<R>().await?;
+        Ok(data)
+    } else {
+        let error_text = response.text().await.unwrap_or_else(|_| {
+            format!("HTTP error: {}", response.status())
+        });
+        Err(ApiError {
+            message: error_text,
+        })
+    }
+}
+
+/// Outcome of a conditional write
+pub enum Conditional<R> {
+    Done(R),
+    /// The resource changed since the given ETag was read (HTTP 412)
+    PreconditionFailed,
+}
+
+/// Make a PATCH request that only applies while the resource still has `etag`
+pub async fn patch_if_match<T: Serialize, R: for<'de> Deserialize<'de>>(
+    endpoint: &str,
+    body: &T,
+    etag: &str,
+) -> Result<Conditional<R>, ApiError> {
+    let url = format!("{}{}", API_BASE_URL, endpoint);
+    let body_json = serde_json::to_string(body)?;
+
+    let mut request = Request::patch(&url)
+        .header("Content-Type", "application/json")
+        .header("If-Match", etag);
+
+    // Add Authorization header if token exists
+    if let Some(token) = get_token() {
+        request = request.header("Authorization", &format!("Bearer {}", token));
+    }
+
+    let response = request.body(body_json)?.send().await?;
+
+    if response.ok() {
+        let data = response.json::<R>().await?;
+        Ok(Conditional::Done(data))
+    } else if response.status() == 412 {
+        Ok(Conditional::PreconditionFailed)
+    } else {
+        let error_text = response.text().await.unwrap_or_else(|_| {
+            format!("HTTP error: {}", response.status())
+        });
+        Err(ApiError {
+            message: error_text,
+        })
+    }
+}
+
 /// Make a DELETE request
 pub async fn delete(endpoint: &str) -> Result<(), String> {
     let url = format!("{}{}", API_BASE_URL, endpoint);
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+
+use super::api;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub filename: String,
+    pub file_size: i64,
+    pub mime_type: String,
+    pub thumbnail_url: Option<String>,
+    /// "pending", "clean" or "infected"; only clean files can be downloaded
+    pub scan_status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Human-readable file size, e.g. "12.3 KB"
+pub fn format_size(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+pub async fn get_task_attachments(task_id: &str) -> Result<Vec<Attachment>, String> {
+    let endpoint = format!("/api/tasks/{}/attachments", task_id);
+    api::get::<Vec<Attachment>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Upload files picked from an input or dropped on the page, optionally
+/// removing photo metadata such as location first
+pub async fn upload_attachments(
+    task_id: &str,
+    files: Vec<web_sys::File>,
+    strip_metadata: bool,
+) -> Result<Vec<Attachment>, String> {
+    let form = web_sys::FormData::new().map_err(|_| "Could not build the upload".to_string())?;
+    for file in &files {
+        form.append_with_blob_and_filename("file", file, &file.name())
+            .map_err(|_| format!("Could not add {} to the upload", file.name()))?;
+    }
+    let endpoint = format!("/api/tasks/{}/attachments?strip_metadata={}", task_id, strip_metadata);
+    api::post_form::<Vec<Attachment>>(&endpoint, form)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn delete_attachment(attachment_id: Uuid) -> Result<(), String> {
+    let endpoint = format!("/api/attachments/{}", attachment_id);
+    api::delete(&endpoint).await
+}
+
+/// Fetch a protected resource into an object URL the page can display or save.
+/// Release it with `web_sys::Url::revoke_object_url` once done.
+pub async fn fetch_object_url(endpoint: &str, mime_type: &str) -> Result<String, String> {
+    let bytes = api::get_bytes(endpoint).await.map_err(|e| e.to_string())?;
+
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes.as_slice()));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|_| "Could not read the file".to_string())?;
+    web_sys::Url::create_object_url_with_blob(&blob).map_err(|_| "Could not open the file".to_string())
+}
+
+/// Thumbnails need the auth header too, so they are shown through object URLs
+pub async fn fetch_thumbnail(attachment: &Attachment) -> Option<String> {
+    let endpoint = attachment.thumbnail_url.as_deref()?;
+    match fetch_object_url(endpoint, "image/*").await {
+        Ok(url) => Some(url),
+        Err(e) => {
+            log::error!("Failed to load thumbnail: {}", e);
+            None
+        }
+    }
+}
+
+/// Download needs the auth header, so the file is fetched and saved through an object URL
+pub async fn download_attachment(attachment: &Attachment) -> Result<(), String> {
+    let endpoint = format!("/api/attachments/{}/download", attachment.id);
+    let url = fetch_object_url(&endpoint, &attachment.mime_type).await?;
+
+    let anchor = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.create_element("a").ok())
+        .and_then(|el| el.dyn_into::<web_sys::HtmlAnchorElement>().ok())
+        .ok_or_else(|| "Could not save the download".to_string())?;
+    anchor.set_href(&url);
+    anchor.set_download(&attachment.filename);
+    anchor.click();
+
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
+
+/// Collect a `FileList` from an input or a drop into owned files
+pub fn files_from_list(list: Option<web_sys::FileList>) -> Vec<web_sys::File> {
+    let Some(list) = list else { return Vec::new() };
+    (0..list.length()).filter_map(|i| list.get(i)).collect()
+}
@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::api;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub content: String,
+    pub checked: bool,
+    pub checked_at: Option<DateTime<Utc>>,
+    pub checked_by: Option<Uuid>,
+    pub assigned_to: Option<Uuid>,
+    pub rank: String,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateChecklistItemRequest {
+    pub content: String,
+    pub assigned_to: Option<Uuid>,
+}
+
+/// Merge patch: `None` leaves a field alone, `Some(None)` clears it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateChecklistItemRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<Option<Uuid>>,
+}
+
+/// Where to put an item: between two neighbors of its checklist
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveChecklistItemRequest {
+    pub before_id: Option<Uuid>,
+    pub after_id: Option<Uuid>,
+}
+
+pub async fn get_checklist(task_id: &str) -> Result<Vec<ChecklistItem>, String> {
+    let endpoint = format!("/api/tasks/{}/checklist", task_id);
+    api::get::<Vec<ChecklistItem>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn create_checklist_item(
+    task_id: &str,
+    request: CreateChecklistItemRequest,
+) -> Result<ChecklistItem, String> {
+    let endpoint = format!("/api/tasks/{}/checklist", task_id);
+    api::post::<CreateChecklistItemRequest, ChecklistItem>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn update_checklist_item(
+    item_id: Uuid,
+    request: UpdateChecklistItemRequest,
+) -> Result<ChecklistItem, String> {
+    let endpoint = format!("/api/checklist-items/{}", item_id);
+    api::patch::<UpdateChecklistItemRequest, ChecklistItem>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn move_checklist_item(
+    item_id: Uuid,
+    request: MoveChecklistItemRequest,
+) -> Result<ChecklistItem, String> {
+    let endpoint = format!("/api/checklist-items/{}/move", item_id);
+    api::post::<MoveChecklistItemRequest, ChecklistItem>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn delete_checklist_item(item_id: Uuid) -> Result<(), String> {
+    let endpoint = format!("/api/checklist-items/{}", item_id);
+    api::delete(&endpoint).await
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::api;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub project_id: Option<Uuid>,
+    pub name: String,
+    pub color: String,
+}
+
+pub async fn get_project_labels(project_id: &str) -> Result<Vec<Label>, String> {
+    let endpoint = format!("/api/projects/{}/labels", project_id);
+    api::get::<Vec<Label>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,7 +1,13 @@
 // Services module
 pub mod api;
+pub mod attachments;
 pub mod auth;
+pub mod checklists;
+pub mod labels;
 pub mod organizations;
 pub mod projects;
+pub mod search;
 pub mod tasks;
+pub mod time_tracking;
+pub mod views;
 
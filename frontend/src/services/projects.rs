@@ -10,9 +10,12 @@ pub struct Project {
     pub organization_id: Uuid,
     pub name: String,
     pub slug: String,
+    pub key: String,
     pub description: Option<String>,
     pub status: String,
     pub color: Option<String>,
+    pub enforce_dependencies: bool,
+    pub version: i32,
     pub created_by: Uuid,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -22,22 +25,32 @@ pub struct Project {
 pub struct CreateProjectRequest {
     pub name: String,
     pub slug: String,
+    /// Prefix of task keys; the server derives one from the name when `None`
+    pub key: Option<String>,
     pub description: Option<String>,
     pub status: Option<String>,
     pub color: Option<String>,
+    pub enforce_dependencies: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Merge patch: `None` leaves a field alone, `Some(None)` clears it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateProjectRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
-    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_dependencies: Option<bool>,
 }
 
 pub async fn get_organization_projects(org_id: &str) -> Result<Vec<Project>, String> {
     let endpoint = format!("/api/organizations/{}/projects", org_id);
-    api::get::<Vec<Project>>(&endpoint)
+    api::get_all_pages::<Project>(&endpoint)
         .await
         .map_err(|e| e.to_string())
 }
@@ -58,7 +71,7 @@ pub async fn get_project(project_id: &str) -> Result<Project, String> {
 
 pub async fn update_project(project_id: &str, request: UpdateProjectRequest) -> Result<Project, String> {
     let endpoint = format!("/api/projects/{}", project_id);
-    api::put::<UpdateProjectRequest, Project>(&endpoint, &request)
+    api::patch::<UpdateProjectRequest, Project>(&endpoint, &request)
         .await
         .map_err(|e| e.to_string())
 }
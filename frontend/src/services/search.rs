@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::api::{self, Page};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub kind: String,
+    pub id: Uuid,
+    pub title: String,
+    pub snippet: String,
+    pub rank: f32,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub task_id: Option<Uuid>,
+}
+
+/// A piece of a snippet, flagged when it is a matched term
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+/// Split a snippet on the `<mark>` tags added by the server so it can be
+/// rendered as plain text
+pub fn snippet_parts(snippet: &str) -> Vec<SnippetPart> {
+    let mut parts = Vec::new();
+    let mut rest = snippet;
+
+    while let Some(start) = rest.find("<mark>") {
+        if start > 0 {
+            parts.push(SnippetPart { text: rest[..start].to_string(), highlighted: false });
+        }
+        rest = &rest[start + "<mark>".len()..];
+        let end = rest.find("</mark>").unwrap_or(rest.len());
+        parts.push(SnippetPart { text: rest[..end].to_string(), highlighted: true });
+        rest = rest.get(end + "</mark>".len()..).unwrap_or("");
+    }
+    if !rest.is_empty() {
+        parts.push(SnippetPart { text: rest.to_string(), highlighted: false });
+    }
+
+    parts
+}
+
+pub async fn search(org_id: &str, q: &str, cursor: Option<&str>) -> Result<Page<SearchResult>, String> {
+    let q = String::from(js_sys::encode_uri_component(q));
+    let endpoint = match cursor {
+        Some(cursor) => format!("/api/organizations/{}/search?q={}&cursor={}", org_id, q, cursor),
+        None => format!("/api/organizations/{}/search?q={}", org_id, q),
+    };
+    api::get::<Page<SearchResult>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
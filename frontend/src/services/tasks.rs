@@ -1,26 +1,52 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use super::api;
+use super::labels::Label;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: Uuid,
+    /// Human-readable key such as `WEB-123`
+    pub key: String,
     pub project_id: Uuid,
     pub title: String,
     pub description: Option<String>,
     pub status: String,
     pub priority: String,
+    /// The primary assignee, also listed first in `assignees`
     pub assigned_to: Option<Uuid>,
+    pub assignees: Vec<Uuid>,
+    pub watchers: Vec<Uuid>,
     pub created_by: Uuid,
     pub due_date: Option<DateTime<Utc>>,
+    pub original_estimate_seconds: Option<i32>,
+    pub remaining_estimate_seconds: Option<i32>,
+    /// Sum of the finished time logs
+    pub time_spent_seconds: i64,
+    /// Absent when the task has no checklist
+    pub checklist_progress: Option<ChecklistProgress>,
     pub completed_at: Option<DateTime<Utc>>,
-    pub position: i32,
+    pub rank: String,
+    pub number: i32,
+    pub version: i32,
+    pub is_blocked: bool,
+    pub labels: Vec<Label>,
+    pub custom_fields: Vec<CustomFieldValue>,
+    /// RRULE of the series this task is the newest occurrence of
+    pub recurrence: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ChecklistProgress {
+    pub checked: i64,
+    pub total: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateTaskRequest {
     pub title: String,
@@ -29,17 +55,57 @@ pub struct CreateTaskRequest {
     pub priority: Option<String>,
     pub assigned_to: Option<Uuid>,
     pub due_date: Option<DateTime<Utc>>,
+    pub original_estimate_seconds: Option<i32>,
+    pub custom_fields: Option<HashMap<Uuid, serde_json::Value>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Merge patch: `None` leaves a field alone, `Some(None)` clears it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UpdateTaskRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
-    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<String>,
-    pub assigned_to: Option<Uuid>,
-    pub due_date: Option<DateTime<Utc>>,
-    pub position: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<Option<Uuid>>,
+    /// Replaces all assignees, the first one primary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_ids: Option<Vec<Uuid>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Option<DateTime<Utc>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_estimate_seconds: Option<Option<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_estimate_seconds: Option<Option<i32>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Watcher {
+    pub user_id: Uuid,
+    pub user_email: String,
+    pub user_first_name: Option<String>,
+    pub user_last_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Where to drop a task: between two neighbors of the target column
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveTaskRequest {
+    pub status: Option<String>,
+    pub before_id: Option<Uuid>,
+    pub after_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldValue {
+    pub field_id: Uuid,
+    pub name: String,
+    pub field_type: String,
+    pub value: serde_json::Value,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -57,9 +123,39 @@ pub struct CreateCommentRequest {
     pub content: String,
 }
 
-pub async fn get_project_tasks(project_id: &str) -> Result<Vec<Task>, String> {
-    let endpoint = format!("/api/projects/{}/tasks", project_id);
-    api::get::<Vec<Task>>(&endpoint)
+/// Filters and ordering for a project's task listing
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskListParams {
+    pub label_id: Option<String>,
+    /// Task query language expression
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+}
+
+impl TaskListParams {
+    fn query_string(&self) -> String {
+        let tz_offset = chrono::Local::now().offset().local_minus_utc() / 60;
+        let mut params = vec![format!("tz_offset={}", tz_offset)];
+        if let Some(ref label_id) = self.label_id {
+            params.push(format!("labels={}", label_id));
+        }
+        if let Some(ref filter) = self.filter {
+            params.push(format!("filter={}", String::from(js_sys::encode_uri_component(filter))));
+        }
+        if let Some(ref sort) = self.sort {
+            params.push(format!("sort={}", sort));
+        }
+        if let Some(ref order) = self.order {
+            params.push(format!("order={}", order));
+        }
+        params.join("&")
+    }
+}
+
+pub async fn get_project_tasks(project_id: &str, params: &TaskListParams) -> Result<Vec<Task>, String> {
+    let endpoint = format!("/api/projects/{}/tasks?{}", project_id, params.query_string());
+    api::get_all_pages::<Task>(&endpoint)
         .await
         .map_err(|e| e.to_string())
 }
@@ -80,7 +176,52 @@ pub async fn get_task(task_id: &str) -> Result<Task, String> {
 
 pub async fn update_task(task_id: &str, request: UpdateTaskRequest) -> Result<Task, String> {
     let endpoint = format!("/api/tasks/{}", task_id);
-    api::put::<UpdateTaskRequest, Task>(&endpoint, &request)
+    api::patch::<UpdateTaskRequest, Task>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn move_task(task_id: &str, request: MoveTaskRequest) -> Result<Task, String> {
+    let endpoint = format!("/api/tasks/{}/move", task_id);
+    api::post::<MoveTaskRequest, Task>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Result of saving an edit made against a known version of a task
+pub enum SaveOutcome {
+    Saved(Task),
+    /// Someone else changed the task first; this is their copy
+    Conflict(Task),
+}
+
+/// Update the task only if it is still at `version`
+pub async fn update_task_if_unchanged(
+    task_id: &str,
+    version: i32,
+    request: UpdateTaskRequest,
+) -> Result<SaveOutcome, String> {
+    let endpoint = format!("/api/tasks/{}", task_id);
+    let etag = format!("\"{}\"", version);
+    match api::patch_if_match::<UpdateTaskRequest, Task>(&endpoint, &request, &etag)
+        .await
+        .map_err(|e| e.to_string())?
+    {
+        api::Conditional::Done(task) => Ok(SaveOutcome::Saved(task)),
+        api::Conditional::PreconditionFailed => get_task(task_id).await.map(SaveOutcome::Conflict),
+    }
+}
+
+/// Target of a move to another project of the same organization
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransferTaskRequest {
+    pub project_id: Uuid,
+    pub status: Option<String>,
+}
+
+pub async fn transfer_task(task_id: Uuid, request: TransferTaskRequest) -> Result<Task, String> {
+    let endpoint = format!("/api/tasks/{}/transfer", task_id);
+    api::post::<TransferTaskRequest, Task>(&endpoint, &request)
         .await
         .map_err(|e| e.to_string())
 }
@@ -92,7 +233,7 @@ pub async fn delete_task(task_id: &str) -> Result<(), String> {
 
 pub async fn get_task_comments(task_id: &str) -> Result<Vec<Comment>, String> {
     let endpoint = format!("/api/tasks/{}/comments", task_id);
-    api::get::<Vec<Comment>>(&endpoint)
+    api::get_all_pages::<Comment>(&endpoint)
         .await
         .map_err(|e| e.to_string())
 }
@@ -103,3 +244,206 @@ pub async fn create_comment(task_id: &str, request: CreateCommentRequest) -> Res
         .await
         .map_err(|e| e.to_string())
 }
+
+pub async fn delete_comment(comment_id: Uuid) -> Result<(), String> {
+    let endpoint = format!("/api/comments/{}", comment_id);
+    api::delete(&endpoint).await
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedTask {
+    pub id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+    pub purge_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashedComment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub task_title: String,
+    pub user_id: Uuid,
+    pub content: String,
+    pub deleted_at: DateTime<Utc>,
+    pub deleted_by: Option<Uuid>,
+    pub purge_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Trash {
+    pub tasks: Vec<TrashedTask>,
+    pub comments: Vec<TrashedComment>,
+}
+
+pub async fn get_project_trash(project_id: &str) -> Result<Trash, String> {
+    let endpoint = format!("/api/projects/{}/trash", project_id);
+    api::get::<Trash>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn restore_task(task_id: Uuid) -> Result<Task, String> {
+    let endpoint = format!("/api/tasks/{}/restore", task_id);
+    api::post::<(), Task>(&endpoint, &())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn restore_comment(comment_id: Uuid) -> Result<Comment, String> {
+    let endpoint = format!("/api/comments/{}/restore", comment_id);
+    api::post::<(), Comment>(&endpoint, &())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// One field change recorded on a task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub field: String,
+    pub old_value: Option<serde_json::Value>,
+    pub new_value: Option<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub async fn get_task_history(task_id: &str) -> Result<Vec<HistoryEntry>, String> {
+    let endpoint = format!("/api/tasks/{}/history", task_id);
+    api::get_all_pages::<HistoryEntry>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Series a recurring task belongs to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recurrence {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub rule: String,
+    pub timezone: String,
+    pub starts_at: DateTime<Utc>,
+    pub occurrence_index: i32,
+    pub next_due_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRecurrenceRequest {
+    pub rule: String,
+    pub timezone: String,
+}
+
+pub async fn get_task_recurrence(task_id: &str) -> Result<Recurrence, String> {
+    let endpoint = format!("/api/tasks/{}/recurrence", task_id);
+    api::get::<Recurrence>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn set_task_recurrence(task_id: &str, request: SetRecurrenceRequest) -> Result<Recurrence, String> {
+    let endpoint = format!("/api/tasks/{}/recurrence", task_id);
+    api::put::<SetRecurrenceRequest, Recurrence>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn delete_task_recurrence(task_id: &str) -> Result<(), String> {
+    let endpoint = format!("/api/tasks/{}/recurrence", task_id);
+    api::delete(&endpoint).await
+}
+
+/// Changes applied to every selected task; unset fields are left alone
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkTaskChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    /// `Some(None)` unassigns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<Option<Uuid>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_label_ids: Vec<Uuid>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove_label_ids: Vec<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<Uuid>,
+    #[serde(default)]
+    pub delete: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTaskRequest {
+    pub task_ids: Vec<Uuid>,
+    pub changes: BulkTaskChanges,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTaskResult {
+    pub task_id: Uuid,
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkTaskResponse {
+    pub applied: bool,
+    pub results: Vec<BulkTaskResult>,
+}
+
+pub async fn bulk_update_tasks(request: BulkTaskRequest) -> Result<BulkTaskResponse, String> {
+    api::post::<BulkTaskRequest, BulkTaskResponse>("/api/tasks/bulk", &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MyTask {
+    #[serde(flatten)]
+    pub task: Task,
+    pub project_name: String,
+    pub organization_id: Uuid,
+    pub organization_name: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MyTasks {
+    pub overdue: Vec<MyTask>,
+    pub today: Vec<MyTask>,
+    pub this_week: Vec<MyTask>,
+    pub later: Vec<MyTask>,
+    pub no_due_date: Vec<MyTask>,
+}
+
+pub async fn get_my_tasks(org_id: Option<&str>) -> Result<MyTasks, String> {
+    let tz_offset = chrono::Local::now().offset().local_minus_utc() / 60;
+    let endpoint = match org_id {
+        Some(org_id) => format!("/api/me/tasks?tz_offset={}&org_id={}", tz_offset, org_id),
+        None => format!("/api/me/tasks?tz_offset={}", tz_offset),
+    };
+    api::get::<MyTasks>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn get_task_watchers(task_id: &str) -> Result<Vec<Watcher>, String> {
+    let endpoint = format!("/api/tasks/{}/watchers", task_id);
+    api::get::<Vec<Watcher>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn watch_task(task_id: &str) -> Result<Vec<Watcher>, String> {
+    let endpoint = format!("/api/tasks/{}/watch", task_id);
+    api::post::<(), Vec<Watcher>>(&endpoint, &())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn unwatch_task(task_id: &str) -> Result<(), String> {
+    let endpoint = format!("/api/tasks/{}/watch", task_id);
+    api::delete(&endpoint).await
+}
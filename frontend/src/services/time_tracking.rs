@@ -0,0 +1,146 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::api;
+
+/// Time a user spent on a task; no `ended_at` means the timer is running
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeLog {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_seconds: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartTimerRequest {
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTimeLogRequest {
+    pub started_at: DateTime<Utc>,
+    pub duration_seconds: i32,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserTimeTotal {
+    pub user_id: Uuid,
+    pub time_spent_seconds: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTimeSummary {
+    pub project_id: Uuid,
+    pub original_estimate_seconds: i64,
+    pub remaining_estimate_seconds: i64,
+    pub time_spent_seconds: i64,
+    pub users: Vec<UserTimeTotal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimesheetEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub task_key: String,
+    pub task_title: String,
+    pub project_id: Uuid,
+    pub project_name: String,
+    pub description: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub duration_seconds: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timesheet {
+    pub user_id: Uuid,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+    pub total_seconds: i64,
+    pub entries: Vec<TimesheetEntry>,
+}
+
+/// `1h 30m` style, as shown next to estimates
+pub fn format_duration(seconds: i64) -> String {
+    let minutes = seconds / 60;
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
+pub async fn start_timer(task_id: Uuid, request: StartTimerRequest) -> Result<TimeLog, String> {
+    let endpoint = format!("/api/tasks/{}/timer", task_id);
+    api::post::<StartTimerRequest, TimeLog>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn get_running_timer() -> Result<Option<TimeLog>, String> {
+    api::get::<Option<TimeLog>>("/api/timer")
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn stop_timer() -> Result<TimeLog, String> {
+    api::post::<(), TimeLog>("/api/timer/stop", &())
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn create_time_log(task_id: Uuid, request: CreateTimeLogRequest) -> Result<TimeLog, String> {
+    let endpoint = format!("/api/tasks/{}/time-logs", task_id);
+    api::post::<CreateTimeLogRequest, TimeLog>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn get_task_time_logs(task_id: &str) -> Result<Vec<TimeLog>, String> {
+    let endpoint = format!("/api/tasks/{}/time-logs", task_id);
+    api::get::<Vec<TimeLog>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn delete_time_log(log_id: Uuid) -> Result<(), String> {
+    let endpoint = format!("/api/time-logs/{}", log_id);
+    api::delete(&endpoint).await
+}
+
+pub async fn get_project_time_summary(project_id: &str) -> Result<ProjectTimeSummary, String> {
+    let endpoint = format!("/api/projects/{}/time-summary", project_id);
+    api::get::<ProjectTimeSummary>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+fn timesheet_endpoint(from: NaiveDate, to: NaiveDate, timezone: &str, format: &str) -> String {
+    format!(
+        "/api/timesheet?from={}&to={}&timezone={}&format={}",
+        from,
+        to,
+        js_sys::encode_uri_component(timezone),
+        format
+    )
+}
+
+pub async fn get_timesheet(from: NaiveDate, to: NaiveDate, timezone: &str) -> Result<Timesheet, String> {
+    api::get::<Timesheet>(&timesheet_endpoint(from, to, timezone, "json"))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn get_timesheet_csv(from: NaiveDate, to: NaiveDate, timezone: &str) -> Result<String, String> {
+    api::get_text(&timesheet_endpoint(from, to, timezone, "csv"))
+        .await
+        .map_err(|e| e.to_string())
+}
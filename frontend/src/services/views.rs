@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::api;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedView {
+    pub id: Uuid,
+    pub project_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: String,
+    pub group_by: Option<String>,
+    pub layout: String,
+    pub is_shared: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSavedViewRequest {
+    pub name: String,
+    pub filter: Option<String>,
+    pub sort: Option<String>,
+    pub sort_order: Option<String>,
+    pub group_by: Option<String>,
+    pub layout: Option<String>,
+    pub is_shared: Option<bool>,
+}
+
+pub async fn get_project_views(project_id: &str) -> Result<Vec<SavedView>, String> {
+    let endpoint = format!("/api/projects/{}/views", project_id);
+    api::get::<Vec<SavedView>>(&endpoint)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn create_view(project_id: &str, request: CreateSavedViewRequest) -> Result<SavedView, String> {
+    let endpoint = format!("/api/projects/{}/views", project_id);
+    api::post::<CreateSavedViewRequest, SavedView>(&endpoint, &request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn delete_view(view_id: &str) -> Result<(), String> {
+    let endpoint = format!("/api/views/{}", view_id);
+    api::delete(&endpoint).await
+}